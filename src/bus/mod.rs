@@ -1,2 +1,5 @@
 pub mod model;
 pub mod usecase;
+
+#[cfg(test)]
+mod mod_test;
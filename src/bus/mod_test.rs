@@ -2,19 +2,20 @@
 mod tests {
     use super::*;
     use crate::bus::model::Bus;
+    use crate::interfaces::bus::IBus;
 
     #[test]
     fn test_bus_read_write() {
         let mut bus = Bus::new();
-        
+
         // Test writing and reading a byte
         bus.write(0x0000, 0x42);
         assert_eq!(bus.read(0x0000), 0x42);
-        
+
         // Test writing to a different address
         bus.write(0x1234, 0xAB);
         assert_eq!(bus.read(0x1234), 0xAB);
-        
+
         // Ensure other memory locations are unchanged
         assert_eq!(bus.read(0x0000), 0x42);
     }
@@ -22,14 +23,14 @@ mod tests {
     #[test]
     fn test_bus_read_write_word() {
         let mut bus = Bus::new();
-        
+
         // Test writing and reading a 16-bit word (little-endian)
         bus.write_word(0x1000, 0x1234);
-        
+
         // Check that the bytes are stored in little-endian order
-        assert_eq!(bus.read(0x1000), 0x34);  // Low byte
-        assert_eq!(bus.read(0x1001), 0x12);  // High byte
-        
+        assert_eq!(bus.read(0x1000), 0x34); // Low byte
+        assert_eq!(bus.read(0x1001), 0x12); // High byte
+
         // Test reading the word back
         assert_eq!(bus.read_word(0x1000), 0x1234);
     }
@@ -37,19 +38,19 @@ mod tests {
     #[test]
     fn test_bus_mirroring() {
         let mut bus = Bus::new();
-        
+
         // Test RAM mirroring (0x0000-0x07FF is mirrored every 0x0800 bytes up to 0x1FFF)
         bus.write(0x0000, 0x12);
         bus.write(0x0800, 0x34);
         bus.write(0x1000, 0x56);
         bus.write(0x1800, 0x78);
-        
+
         // All these should read the same value (last write wins)
         assert_eq!(bus.read(0x0000), 0x78);
         assert_eq!(bus.read(0x0800), 0x78);
         assert_eq!(bus.read(0x1000), 0x78);
         assert_eq!(bus.read(0x1800), 0x78);
-        
+
         // Test that writing to mirrored locations works
         bus.write(0x0001, 0xAB);
         assert_eq!(bus.read(0x0801), 0xAB);
@@ -60,15 +61,56 @@ mod tests {
     #[test]
     fn test_bus_ppu_registers() {
         let mut bus = Bus::new();
-        
+
         // Test PPU register mirroring (0x2000-0x2007 is mirrored every 8 bytes up to 0x3FFF)
         bus.write(0x2000, 0x12);
-        bus.write(0x2008, 0x34);  // Should mirror to 0x2000
-        bus.write(0x3FF8, 0x56);  // Should mirror to 0x2000
-        
+        bus.write(0x2008, 0x34); // Should mirror to 0x2000
+        bus.write(0x3FF8, 0x56); // Should mirror to 0x2000
+
         // All these should read the same value (last write wins)
         assert_eq!(bus.read(0x2000), 0x56);
         assert_eq!(bus.read(0x2008), 0x56);
         assert_eq!(bus.read(0x3FF8), 0x56);
     }
+
+    #[test]
+    fn test_bus_cartridge_windows() {
+        let mut bus = Bus::new();
+
+        // SRAM is battery backed RAM
+        bus.write(&0x6000, 0x12);
+        bus.write(&0x7FFF, 0x34);
+        assert_eq!(bus.read(&0x6000), 0x12);
+        assert_eq!(bus.read(&0x7FFF), 0x34);
+
+        // Expansion ROM and PRG-ROM ignore writes
+        bus.write(&0x4020, 0x56);
+        bus.write(&0x8000, 0x78);
+        assert_eq!(bus.read(&0x4020), 0x00);
+        assert_eq!(bus.read(&0x8000), 0x00);
+
+        // APU and IO registers are not mirrored
+        bus.write(&0x4016, 0x01);
+        assert_eq!(bus.read(&0x4016), 0x01);
+        assert_eq!(bus.read(&0x4036), 0x00);
+    }
+
+    #[test]
+    fn test_bus_pointer_fetches() {
+        let mut bus = Bus::flat();
+        bus.write(&0x00FF, 0x34);
+        bus.write(&0x0000, 0x12);
+        bus.write(&0x0100, 0x56);
+        bus.write(&0x10FF, 0x78);
+        bus.write(&0x1000, 0x9A);
+        bus.write(&0x1100, 0xBC);
+
+        // Page zero pointer wraps from $FF to $00
+        assert_eq!(bus.read_word_zp(0xFF), 0x1234);
+        assert_eq!(bus.read_word(&0x00FF), 0x5634);
+
+        // NMOS JMP ($10FF) takes its high byte from $1000
+        assert_eq!(bus.read_word_bug(&0x10FF), 0x9A78);
+        assert_eq!(bus.read_word(&0x10FF), 0xBC78);
+    }
 }
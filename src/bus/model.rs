@@ -33,8 +33,32 @@
 // ______________________________________________________________
 //
 
-use crate::ram::model::Memory;
+use std::cell::RefCell;
 
+use crate::interfaces::device::Device;
+
+/*
+   Devices are looked up from the last attached : a device overlays the ones attached before it.
+   Addresses nobody answers read $00 and writes are dropped.
+*/
 pub struct Bus {
-    pub memory: Memory,
+    pub mappings: Vec<Mapping>,
+    /* Ranges holding data only, fetching an op code there is a bus fault */
+    pub data_only: Vec<(u16, u16)>,
+}
+
+/* Device answering $start-$end, mirrored every mask + 1 bytes */
+pub struct Mapping {
+    pub start: u16,
+    pub end: u16,
+    pub mask: u16,
+    pub device: RefCell<Box<dyn Device>>,
+}
+
+/*
+   24-bit address bus for the 65C816 : 256 banks of 64K.
+   16-bit accesses go to bank $00.
+*/
+pub struct LongBus {
+    pub memory: Vec<u8>,
 }
@@ -1,21 +1,290 @@
-use crate::bus::model::Bus;
-use crate::interfaces::bus::IBus;
-use crate::ram::model::Memory;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::bus::model::{Bus, LongBus, Mapping};
+use crate::interfaces::bus::{BusAddress, IBus};
+use crate::interfaces::device::Device;
+use crate::interfaces::mapper::Mapper;
+use crate::mapper::model::Slot;
+use crate::ram::model::{Ram, Rom};
 
 impl Bus {
+    /*
+       NES CPU memory map, see ram/model.rs.
+       The PPU and APU / IO windows hold plain registers until the application attaches the
+       real chips over them, the cartridge is attached over PRG-ROM the same way.
+    */
     pub fn new() -> Self {
+        let mut bus = Bus::empty();
+        // 2K of RAM mirrored up to $1FFF
+        bus.attach(0x0000, 0x1FFF, 0x07FF, Ram::new(0x0800));
+        // PPU registers $2000-$2007 mirrored up to $3FFF
+        bus.attach(0x2000, 0x3FFF, 0x0007, Ram::new(0x0008));
+        // APU and IO registers
+        bus.attach(0x4000, 0x401F, 0x001F, Ram::new(0x0020));
+        // Expansion ROM, SRAM
+        bus.attach(0x4020, 0x5FFF, 0x1FFF, Rom::new(vec![0x00; 0x1FE0]));
+        bus.attach(0x6000, 0x7FFF, 0x1FFF, Ram::new(0x2000));
+        // Lower and upper PRG-ROM banks
+        bus.attach(0x8000, 0xFFFF, 0x7FFF, Rom::new(vec![0x00; 0x8000]));
+        bus
+    }
+
+    /* 64K of RAM over the whole address space */
+    pub fn flat() -> Self {
+        let mut bus = Bus::empty();
+        bus.attach(0x0000, 0xFFFF, 0xFFFF, Ram::new(0x10000));
+        bus
+    }
+
+    /* Nothing mapped, every device is attached by the application */
+    pub fn empty() -> Self {
         Bus {
-            memory: Memory::new(),
+            mappings: Vec::new(),
+            data_only: Vec::new(),
         }
     }
+
+    /*
+       Map a device over $start-$end.
+       The device sees (address - start) & mask : a 2K RAM over $0000-$1FFF uses the mask $07FF.
+    */
+    pub fn attach<D: Device + 'static>(&mut self, start: u16, end: u16, mask: u16, device: D) {
+        self.mappings.push(Mapping {
+            start,
+            end,
+            mask,
+            device: RefCell::new(Box::new(device)),
+        });
+    }
+
+    /*
+       Plug a mapper over $start-$end, once : it is clocked through this slot.
+       Keep a clone of the Rc to reach the CHR side or inspect the bank layout.
+    */
+    pub fn attach_mapper(&mut self, start: u16, end: u16, mapper: Rc<RefCell<dyn Mapper>>) {
+        self.attach(start, end, 0xFFFF, Slot { mapper, start });
+    }
+
+    /* Report op code fetches from $start-$end, e.g. the stack page or a frame buffer */
+    pub fn mark_data_only(&mut self, start: u16, end: u16) {
+        self.data_only.push((start, end));
+    }
+
+    /* Device answering the address and the address it sees */
+    fn lookup(&self, address: u16) -> Option<(&Mapping, u16)> {
+        self.mappings
+            .iter()
+            .rev()
+            .find(|mapping| mapping.start <= address && address <= mapping.end)
+            .map(|mapping| (mapping, (address - mapping.start) & mapping.mask))
+    }
+}
+
+impl Default for Bus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/* Byte and word access by value or by reference, e.g. bus.read(0x0000) */
+impl Bus {
+    pub fn read<A: BusAddress>(&self, address: A) -> u8 {
+        IBus::read(self, &address.address())
+    }
+
+    pub fn write<A: BusAddress>(&mut self, address: A, data: u8) {
+        IBus::write(self, &address.address(), data);
+    }
+
+    pub fn read_word<A: BusAddress>(&self, address: A) -> u16 {
+        IBus::read_word(self, &address.address())
+    }
+
+    pub fn write_word<A: BusAddress>(&mut self, address: A, data: u16) {
+        IBus::write_word(self, &address.address(), data);
+    }
+}
+
+impl BusAddress for u16 {
+    fn address(self) -> u16 {
+        self
+    }
+}
+
+impl BusAddress for &u16 {
+    fn address(self) -> u16 {
+        *self
+    }
 }
 
 impl IBus for Bus {
     fn read(&self, address: &u16) -> u8 {
-        self.memory.read(address)
+        match self.lookup(*address) {
+            Some((mapping, offset)) => mapping.device.borrow_mut().read(&offset),
+            None => 0x00,
+        }
+    }
+
+    fn write(&mut self, address: &u16, data: u8) {
+        if let Some((mapping, offset)) = self.lookup(*address) {
+            mapping.device.borrow_mut().write(&offset, data);
+        }
+    }
+
+    fn peek(&self, address: &u16) -> u8 {
+        match self.lookup(*address) {
+            Some((mapping, offset)) => mapping.device.borrow().peek(&offset),
+            None => 0x00,
+        }
+    }
+
+    fn poke(&mut self, address: &u16, data: u8) {
+        if let Some((mapping, offset)) = self.lookup(*address) {
+            mapping.device.borrow_mut().poke(&offset, data);
+        }
+    }
+
+    fn writable(&self, address: &u16) -> bool {
+        match self.lookup(*address) {
+            Some((mapping, offset)) => mapping.device.borrow().writable(&offset),
+            None => true,
+        }
+    }
+
+    fn executable(&self, address: &u16) -> bool {
+        !self
+            .data_only
+            .iter()
+            .any(|(start, end)| start <= address && address <= end)
+    }
+
+    fn tick(&mut self, cycles: u64) {
+        for mapping in self.mappings.iter_mut() {
+            mapping.device.get_mut().tick(cycles);
+        }
+    }
+
+    fn irq(&self) -> bool {
+        self.mappings
+            .iter()
+            .any(|mapping| mapping.device.borrow().irq())
+    }
+}
+
+impl LongBus {
+    pub fn new() -> Self {
+        LongBus {
+            memory: vec![0x00; 0x100_0000],
+        }
+    }
+}
+
+impl Default for LongBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IBus for LongBus {
+    fn read(&self, address: &u16) -> u8 {
+        self.read_long(*address as u32)
+    }
+
+    fn write(&mut self, address: &u16, data: u8) {
+        self.write_long(*address as u32, data);
+    }
+
+    fn read_long(&self, address: u32) -> u8 {
+        self.memory[(address & 0x00FF_FFFF) as usize]
+    }
+
+    fn write_long(&mut self, address: u32, data: u8) {
+        self.memory[(address & 0x00FF_FFFF) as usize] = data;
+    }
+
+    fn peek_long(&self, address: u32) -> u8 {
+        self.memory[(address & 0x00FF_FFFF) as usize]
+    }
+}
+
+/*
+   Shared bus : the processor and other chips (video, sound ...) hold clones of
+   the same Rc and see each other's writes.
+*/
+impl<T: IBus> IBus for Rc<RefCell<T>> {
+    fn read(&self, address: &u16) -> u8 {
+        self.borrow().read(address)
+    }
+
+    fn write(&mut self, address: &u16, data: u8) {
+        self.borrow_mut().write(address, data);
+    }
+
+    fn read_long(&self, address: u32) -> u8 {
+        self.borrow().read_long(address)
+    }
+
+    fn write_long(&mut self, address: u32, data: u8) {
+        self.borrow_mut().write_long(address, data);
+    }
+
+    fn peek_long(&self, address: u32) -> u8 {
+        self.borrow().peek_long(address)
+    }
+
+    fn peek(&self, address: &u16) -> u8 {
+        self.borrow().peek(address)
+    }
+
+    fn poke(&mut self, address: &u16, data: u8) {
+        self.borrow_mut().poke(address, data);
+    }
+
+    fn writable(&self, address: &u16) -> bool {
+        self.borrow().writable(address)
+    }
+
+    fn executable(&self, address: &u16) -> bool {
+        self.borrow().executable(address)
+    }
+
+    fn tick(&mut self, cycles: u64) {
+        self.borrow_mut().tick(cycles);
+    }
+
+    fn irq(&self) -> bool {
+        self.borrow().irq()
+    }
+}
+
+/* Shared device : the application keeps a clone to inspect or drive it */
+impl<T: Device> Device for Rc<RefCell<T>> {
+    fn read(&mut self, address: &u16) -> u8 {
+        self.borrow_mut().read(address)
     }
 
     fn write(&mut self, address: &u16, data: u8) {
-        self.memory.write(address, data);
+        self.borrow_mut().write(address, data);
+    }
+
+    fn peek(&self, address: &u16) -> u8 {
+        self.borrow().peek(address)
+    }
+
+    fn poke(&mut self, address: &u16, data: u8) {
+        self.borrow_mut().poke(address, data);
+    }
+
+    fn writable(&self, address: &u16) -> bool {
+        self.borrow().writable(address)
+    }
+
+    fn tick(&mut self, cycles: u64) {
+        self.borrow_mut().tick(cycles);
+    }
+
+    fn irq(&self) -> bool {
+        self.borrow().irq()
     }
 }
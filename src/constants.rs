@@ -1 +1,13 @@
 pub const START_STACK_POINTER: &'static u16 = &0x0100;
+
+/* Interrupt and reset vectors : low byte first, high byte at the next address */
+pub const NMI_VECTOR: u16 = 0xFFFA;
+pub const RESET_VECTOR: u16 = 0xFFFC;
+pub const IRQ_VECTOR: u16 = 0xFFFE;
+
+/* 65C816 vectors : emulation mode uses the 6502 vectors above plus COP */
+pub const EMULATION_COP_VECTOR: u16 = 0xFFF4;
+pub const NATIVE_COP_VECTOR: u16 = 0xFFE4;
+pub const NATIVE_BRK_VECTOR: u16 = 0xFFE6;
+pub const NATIVE_NMI_VECTOR: u16 = 0xFFEA;
+pub const NATIVE_IRQ_VECTOR: u16 = 0xFFEE;
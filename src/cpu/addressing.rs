@@ -0,0 +1,313 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+/** Addressing */
+impl<B: IBus> CPU<B> {
+    /*
+       Shared operand resolver used by every op code.
+       Consumes the operand bytes after the op code, leaves PC on the next instruction and
+       stores the effective address in the address register.
+       Returns true when the indexed address (or branch target) lands on another page.
+    */
+    pub fn resolve_address(&mut self, mode: &AddressingMode) -> bool {
+        match mode {
+            AddressingMode::Implied | AddressingMode::Accumulator => {
+                // PC + 1, the byte after the op code is read and discarded
+                self.pc = self.pc.wrapping_add(1);
+                self.dummy_read(self.pc);
+                false
+            }
+            AddressingMode::Immediate => {
+                // Operand is the byte after the op code
+                self.pc = self.pc.wrapping_add(1);
+                self.address_register = self.pc;
+                self.address = self.address_register;
+                // PC + 2
+                self.pc = self.pc.wrapping_add(1);
+                false
+            }
+            AddressingMode::ZeroPage => {
+                // Fetch page zero Effective Address
+                // ADL
+                let adl = self.fetch_operand_byte();
+                self.address_register = adl as u16 & 0x00FF;
+                self.address = self.address_register;
+                false
+            }
+            AddressingMode::ZeroPageX => {
+                // Fetch page zero Base Address, add X without carry
+                // BAL
+                let bal = self.fetch_operand_byte();
+                self.dummy_read(bal as u16);
+                self.address_register = bal.wrapping_add(self.x_register) as u16 & 0x00FF;
+                self.address = self.address_register;
+                false
+            }
+            AddressingMode::ZeroPageY => {
+                // Fetch page zero Base Address, add Y without carry
+                // BAL
+                let bal = self.fetch_operand_byte();
+                self.dummy_read(bal as u16);
+                self.address_register = bal.wrapping_add(self.y_register) as u16 & 0x00FF;
+                self.address = self.address_register;
+                false
+            }
+            AddressingMode::Absolute => {
+                // Fetch Effective Address
+                // ADL, ADH
+                self.address_register = self.fetch_operand_word();
+                self.address = self.address_register;
+                false
+            }
+            AddressingMode::AbsoluteX => {
+                // Fetch Base Address and add X
+                // BAL, BAH
+                let base = self.fetch_operand_word();
+                self.index_address(base, self.x_register)
+            }
+            AddressingMode::AbsoluteY => {
+                // Fetch Base Address and add Y
+                // BAL, BAH
+                let base = self.fetch_operand_word();
+                self.index_address(base, self.y_register)
+            }
+            AddressingMode::Indirect => {
+                // Fetch Indirect Address
+                // IAL, IAH
+                let indirect = self.fetch_operand_word();
+
+                // Fetch Effective Address from the Indirect Address
+                // ADL, ADH
+                // NMOS does not carry into the high byte : JMP ($10FF) reads ADH from $1000.
+                // The CMOS parts fix this and spend one more cycle (in the op code table).
+                self.address_register = if self.is_cmos() {
+                    self.dummy_read(self.pc.wrapping_sub(1));
+                    self.read_word(&indirect)
+                } else {
+                    self.read_word_bug(&indirect)
+                };
+                self.address = self.address_register;
+                false
+            }
+            AddressingMode::IndirectX => {
+                // Fetch page zero Base Address, add X without carry
+                // BAL
+                let bal = self.fetch_operand_byte();
+                self.dummy_read(bal as u16);
+                let pointer = bal.wrapping_add(self.x_register);
+
+                // Fetch Effective Address from page zero, the pointer wraps from $FF to $00
+                // ADL, ADH
+                self.address_register = self.read_word_zp(pointer);
+                self.address = self.address_register;
+                false
+            }
+            AddressingMode::IndirectY => {
+                // Fetch page zero Indirect Address
+                // IAL
+                let ial = self.fetch_operand_byte();
+
+                // Fetch Base Address from page zero and add Y, the pointer wraps from $FF to $00
+                // BAL, BAH
+                let base = self.read_word_zp(ial);
+                self.index_address(base, self.y_register)
+            }
+            AddressingMode::ZeroPageIndirect => {
+                // Fetch page zero Indirect Address
+                // IAL
+                let ial = self.fetch_operand_byte();
+
+                // Fetch Effective Address from page zero
+                // ADL, ADH
+                self.address_register = self.read_word_zp(ial);
+                self.address = self.address_register;
+                false
+            }
+            AddressingMode::AbsoluteIndexedIndirect => {
+                // Fetch Base Address and add X
+                // BAL, BAH
+                let pointer = self
+                    .fetch_operand_word()
+                    .wrapping_add(self.x_register as u16);
+                self.dummy_read(self.pc.wrapping_sub(1));
+
+                // Fetch Effective Address from the indexed pointer
+                // ADL, ADH
+                self.address_register = self.read_word(&pointer);
+                self.address = self.address_register;
+                false
+            }
+            AddressingMode::ZeroPageRelative => {
+                // Fetch page zero Address to test
+                // ADL
+                let adl = self.fetch_operand_byte();
+
+                // Fetch Branch Offset
+                self.address = self.pc;
+                let offset = self.read(&self.address) as i8 as u16;
+                // PC + 3
+                self.pc = self.pc.wrapping_add(1);
+
+                // Offset is relative to the next instruction
+                self.address_register = self.pc.wrapping_add(offset);
+                self.address = adl as u16 & 0x00FF;
+                self.page_cross(self.pc, self.address_register)
+            }
+            AddressingMode::Relative => {
+                // Fetch Branch Offset
+                let offset = self.fetch_operand_byte() as i8 as u16;
+
+                // Offset is relative to the next instruction
+                self.address_register = self.pc.wrapping_add(offset);
+                self.address = self.address_register;
+                self.page_cross(self.pc, self.address)
+            }
+        }
+    }
+
+    /*
+       Operand for read instructions (LDA, ADC, CMP ...).
+       Loads the data into the data register, charging one cycle when a page is crossed.
+    */
+    pub fn fetch_operand(&mut self, mode: &AddressingMode) {
+        if self.resolve_address(mode) {
+            // Page crossed : add one cycle
+            self.cycles += 1;
+            self.index_dummy_read(true);
+        }
+        self.load_operand(mode);
+    }
+
+    /*
+       Operand for read-modify-write instructions (ASL, INC ...).
+       These always spend the indexing cycle, so a page cross costs nothing extra.
+       The 65C02 shifts and rotates on $nnnn,X only spend it on a page cross.
+    */
+    pub fn fetch_modify_operand(&mut self, mode: &AddressingMode) {
+        let page_cross = self.resolve_address(mode);
+        if Self::is_indexed(mode) {
+            if !self.cmos_indexed_shift() {
+                self.index_dummy_read(page_cross);
+            } else if page_cross {
+                // Page crossed : add one cycle
+                self.cycles += 1;
+                self.index_dummy_read(true);
+            }
+        }
+        self.load_operand(mode);
+    }
+
+    /*
+       Effective address for store instructions (STA, STX ...).
+       Stores always spend the indexing cycle, the page cross flag is only used by SHA and friends.
+    */
+    pub fn resolve_write_address(&mut self, mode: &AddressingMode) -> bool {
+        let page_cross = self.resolve_address(mode);
+        if Self::is_indexed(mode) {
+            self.index_dummy_read(page_cross);
+        }
+        page_cross
+    }
+
+    /*
+       Write back the result of a read-modify-write instruction.
+       The result is kept in the data register for the combined undocumented op codes.
+       While the result is computed NMOS writes the unmodified data back, CMOS reads the address again.
+    */
+    pub fn store_modified(&mut self, mode: &AddressingMode, data: u8) {
+        if *mode == AddressingMode::Accumulator {
+            self.accumulator = data;
+        } else {
+            let addr = self.address;
+            if self.is_cmos() {
+                self.dummy_read(addr);
+            } else {
+                let unmodified = self.data;
+                self.write(&addr, unmodified);
+            }
+            self.write(&addr, data);
+        }
+        self.data = data;
+    }
+
+    /* Bus access of a cycle that has nothing to fetch, the data is discarded */
+    pub fn dummy_read(&mut self, address: u16) {
+        self.read(&address);
+    }
+
+    fn load_operand(&mut self, mode: &AddressingMode) {
+        if *mode == AddressingMode::Accumulator {
+            self.data = self.accumulator;
+        } else {
+            self.data = self.read(&self.address);
+        }
+    }
+
+    /* ASL, ROL, LSR, ROR $nnnn,X on the 65C02 */
+    fn cmos_indexed_shift(&self) -> bool {
+        self.is_cmos()
+            && matches!(
+                self.instruction_reg.map(|instruction| instruction.code),
+                Some(0x1E | 0x3E | 0x5E | 0x7E)
+            )
+    }
+
+    fn is_indexed(mode: &AddressingMode) -> bool {
+        matches!(
+            mode,
+            AddressingMode::AbsoluteX | AddressingMode::AbsoluteY | AddressingMode::IndirectY
+        )
+    }
+
+    /*
+       Cycle spent adding the index to the base address.
+       NMOS reads the address before the carry reaches the high byte, CMOS reads the last operand byte again.
+    */
+    fn index_dummy_read(&mut self, page_cross: bool) {
+        let address = if self.is_cmos() {
+            self.pc.wrapping_sub(1)
+        } else if page_cross {
+            self.address.wrapping_sub(0x0100)
+        } else {
+            self.address
+        };
+        self.dummy_read(address);
+    }
+
+    /* Fetch the byte after the op code. PC is left on the next instruction. */
+    fn fetch_operand_byte(&mut self) -> u8 {
+        // PC + 1
+        self.pc = self.pc.wrapping_add(1);
+        self.address = self.pc;
+        self.data = self.read(&self.address);
+        // PC + 2
+        self.pc = self.pc.wrapping_add(1);
+        self.data
+    }
+
+    /* Fetch the two bytes after the op code, low order byte first */
+    fn fetch_operand_word(&mut self) -> u16 {
+        // PC + 1
+        self.pc = self.pc.wrapping_add(1);
+        self.address = self.pc;
+        let low = self.read(&self.address);
+
+        // PC + 2
+        self.pc = self.pc.wrapping_add(1);
+        self.address = self.pc;
+        let high = self.read(&self.address);
+
+        // PC + 3
+        self.pc = self.pc.wrapping_add(1);
+        ((high as u16) << 8) & 0xFF00 | low as u16 & 0x00FF
+    }
+
+    fn index_address(&mut self, base: u16, index: u8) -> bool {
+        self.address_register = base.wrapping_add(index as u16);
+        self.address = self.address_register;
+
+        // Calculate Page Cross
+        self.page_cross(base, self.address)
+    }
+}
@@ -1,21 +1,65 @@
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+use AddressingMode::*;
+
+/**
+ * Ref : http://www.6502.org/tutorials/6502opcodes.html
+ * How the operand bytes after the op code are turned into an effective address.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressingMode {
+    /* No operand : CLC, TAX, RTS */
+    Implied,
+    /* Operates on the accumulator : ASL A */
+    Accumulator,
+    /* Operand is the byte after the op code : LDA #$44 */
+    Immediate,
+    /* Address $00nn : LDA $44 */
+    ZeroPage,
+    /* Address $00nn + X, wraps inside the zero page : LDA $44,X */
+    ZeroPageX,
+    /* Address $00nn + Y, wraps inside the zero page : LDX $44,Y */
+    ZeroPageY,
+    /* Address $nnnn : LDA $4400 */
+    Absolute,
+    /* Address $nnnn + X : LDA $4400,X */
+    AbsoluteX,
+    /* Address $nnnn + Y : LDA $4400,Y */
+    AbsoluteY,
+    /* Address read from $nnnn : JMP ($4400) */
+    Indirect,
+    /* Address read from zero page $nn + X : LDA ($44,X) */
+    IndirectX,
+    /* Address read from zero page $nn, then + Y : LDA ($44),Y */
+    IndirectY,
+    /* Signed offset from the next instruction : BNE $nn */
+    Relative,
+    /* Address read from zero page $nn, 65C02 only : LDA ($44) */
+    ZeroPageIndirect,
+    /* Address read from $nnnn + X, 65C02 only : JMP ($4400,X) */
+    AbsoluteIndexedIndirect,
+    /* Zero page address to test, then a branch offset, W65C02S only : BBR0 $44,$nn */
+    ZeroPageRelative,
+}
+
 #[derive(Debug, Clone)]
 pub struct Instruction {
     pub code: u8,
     pub name: &'static str,
     pub length: u8,
     pub cycle: u8,
+    pub mode: AddressingMode,
 }
 
 impl Instruction {
-    pub fn new(code: u8, name: &'static str, length: u8, cycle: u8) -> Self {
+    pub fn new(code: u8, name: &'static str, length: u8, cycle: u8, mode: AddressingMode) -> Self {
         Instruction {
             code,
             name,
             length,
             cycle,
+            mode,
         }
     }
 }
@@ -41,14 +85,14 @@ lazy_static! {
             // In decimal mode, addition is carried out on the assumption that the values involved
             // are packed BCD (Binary Coded Decimal).
             // There is no way to add without carry.
-            Instruction::new(0x69, "ADC", 2, 2),
-            Instruction::new(0x65, "ADC", 2, 3),
-            Instruction::new(0x75, "ADC", 2, 4),
-            Instruction::new(0x6D, "ADC", 3, 4),
-            Instruction::new(0x7D, "ADC", 3, 4),
-            Instruction::new(0x79, "ADC", 3, 4),
-            Instruction::new(0x61, "ADC", 2, 6),
-            Instruction::new(0x71, "ADC", 2, 5),
+            Instruction::new(0x69, "ADC", 2, 2, Immediate),
+            Instruction::new(0x65, "ADC", 2, 3, ZeroPage),
+            Instruction::new(0x75, "ADC", 2, 4, ZeroPageX),
+            Instruction::new(0x6D, "ADC", 3, 4, Absolute),
+            Instruction::new(0x7D, "ADC", 3, 4, AbsoluteX),
+            Instruction::new(0x79, "ADC", 3, 4, AbsoluteY),
+            Instruction::new(0x61, "ADC", 2, 6, IndirectX),
+            Instruction::new(0x71, "ADC", 2, 5, IndirectY),
 
             // AND (bitwise AND with accumulator)
             // Affects Flags: N Z
@@ -62,14 +106,14 @@ lazy_static! {
             // Indirect,X    AND ($44,X)   $21  2   6
             // Indirect,Y    AND ($44),Y   $31  2   5+
             // + add 1 cycle if page boundary crossed
-            Instruction::new(0x29, "AND", 2, 2),
-            Instruction::new(0x25, "AND", 2, 3),
-            Instruction::new(0x35, "AND", 2, 4),
-            Instruction::new(0x2D, "AND", 3, 4),
-            Instruction::new(0x3D, "AND", 3, 4),
-            Instruction::new(0x39, "AND", 3, 4),
-            Instruction::new(0x21, "AND", 2, 6),
-            Instruction::new(0x31, "AND", 2, 5),
+            Instruction::new(0x29, "AND", 2, 2, Immediate),
+            Instruction::new(0x25, "AND", 2, 3, ZeroPage),
+            Instruction::new(0x35, "AND", 2, 4, ZeroPageX),
+            Instruction::new(0x2D, "AND", 3, 4, Absolute),
+            Instruction::new(0x3D, "AND", 3, 4, AbsoluteX),
+            Instruction::new(0x39, "AND", 3, 4, AbsoluteY),
+            Instruction::new(0x21, "AND", 2, 6, IndirectX),
+            Instruction::new(0x31, "AND", 2, 5, IndirectY),
             // ASL (Arithmetic Shift Left)
             // Affects Flags: N Z C
             // MODE           SYNTAX       HEX LEN TIM
@@ -80,11 +124,11 @@ lazy_static! {
             // Absolute,X    ASL $4400,X   $1E  3   7
             // ASL shifts all bits left one position.
             // 0 is shifted into bit 0 and the original bit 7 is shifted into the Carry.
-            Instruction::new(0x0A, "ASL", 1, 2),
-            Instruction::new(0x06, "ASL", 2, 5),
-            Instruction::new(0x16, "ASL", 2, 6),
-            Instruction::new(0x0E, "ASL", 3, 6),
-            Instruction::new(0x1E, "ASL", 3, 7),
+            Instruction::new(0x0A, "ASL", 1, 2, Accumulator),
+            Instruction::new(0x06, "ASL", 2, 5, ZeroPage),
+            Instruction::new(0x16, "ASL", 2, 6, ZeroPageX),
+            Instruction::new(0x0E, "ASL", 3, 6, Absolute),
+            Instruction::new(0x1E, "ASL", 3, 7, AbsoluteX),
             // BIT (test BITs)
             // Affects Flags: N V Z
             // MODE           SYNTAX       HEX LEN TIM
@@ -102,8 +146,8 @@ lazy_static! {
             //        STA ICCOM,X upon arrival here.
             // Beware: a BIT instruction used in this way as a NOP does have effects: the flags may be modified,
             // and the read of the absolute address, if it happens to access an I/O device, may cause an unwanted action.
-            Instruction::new(0x24, "BIT", 2, 3),
-            Instruction::new(0x2C, "BIT", 3, 4),
+            Instruction::new(0x24, "BIT", 2, 3, ZeroPage),
+            Instruction::new(0x2C, "BIT", 3, 4, Absolute),
             // Branch Instructions
             // Affect Flags: none
             // All branches are relative mode and have a length of two bytes.
@@ -135,14 +179,14 @@ lazy_static! {
             //         BVC LABEL
             //   LABEL NOP
             // the BVC instruction will take 3 cycles no matter what address it is located at.
-            Instruction::new(0x10, "BPL", 2, 2),
-            Instruction::new(0x30, "BMI", 2, 2),
-            Instruction::new(0x50, "BVC", 2, 2),
-            Instruction::new(0x70, "BVS", 2, 2),
-            Instruction::new(0x90, "BCC", 2, 2),
-            Instruction::new(0xB0, "BCS", 2, 2),
-            Instruction::new(0xD0, "BNE", 2, 2),
-            Instruction::new(0xF0, "BEQ", 2, 2),
+            Instruction::new(0x10, "BPL", 2, 2, Relative),
+            Instruction::new(0x30, "BMI", 2, 2, Relative),
+            Instruction::new(0x50, "BVC", 2, 2, Relative),
+            Instruction::new(0x70, "BVS", 2, 2, Relative),
+            Instruction::new(0x90, "BCC", 2, 2, Relative),
+            Instruction::new(0xB0, "BCS", 2, 2, Relative),
+            Instruction::new(0xD0, "BNE", 2, 2, Relative),
+            Instruction::new(0xF0, "BEQ", 2, 2, Relative),
             // BRK (BReaK)
             // Affects Flags: B
             // MODE           SYNTAX       HEX LEN TIM
@@ -150,7 +194,7 @@ lazy_static! {
             // BRK causes a non-maskable interrupt and increments the program counter by one.
             // Therefore an RTI will go to the address of the BRK +2 so that BRK may be used
             // to replace a two-byte instruction for debugging and the subsequent RTI will be correct.
-            Instruction::new(0x00, "BRK", 1, 7),
+            Instruction::new(0x00, "BRK", 1, 7, Implied),
             // CMP (CoMPare accumulator)
             // Affects Flags: N Z C
             // MODE           SYNTAX       HEX LEN TIM
@@ -167,14 +211,14 @@ lazy_static! {
             // If the value in the accumulator is equal or greater than the compared value,
             // the Carry will be set. The equal (Z) and negative (N) flags will be set based on equality or
             // lack thereof and the sign (i.e. A>=$80) of the accumulator.
-            Instruction::new(0xC9, "CMP", 2, 2),
-            Instruction::new(0xC5, "CMP", 2, 3),
-            Instruction::new(0xD5, "CMP", 2, 4),
-            Instruction::new(0xCD, "CMP", 3, 4),
-            Instruction::new(0xDD, "CMP", 3, 4),
-            Instruction::new(0xD9, "CMP", 3, 4),
-            Instruction::new(0xC1, "CMP", 2, 6),
-            Instruction::new(0xD1, "CMP", 2, 5),
+            Instruction::new(0xC9, "CMP", 2, 2, Immediate),
+            Instruction::new(0xC5, "CMP", 2, 3, ZeroPage),
+            Instruction::new(0xD5, "CMP", 2, 4, ZeroPageX),
+            Instruction::new(0xCD, "CMP", 3, 4, Absolute),
+            Instruction::new(0xDD, "CMP", 3, 4, AbsoluteX),
+            Instruction::new(0xD9, "CMP", 3, 4, AbsoluteY),
+            Instruction::new(0xC1, "CMP", 2, 6, IndirectX),
+            Instruction::new(0xD1, "CMP", 2, 5, IndirectY),
             // CPX (ComPare X register)
             // Affects Flags: N Z C
             // MODE           SYNTAX       HEX LEN TIM
@@ -182,9 +226,9 @@ lazy_static! {
             // Zero Page     CPX $44       $E4  2   3
             // Absolute      CPX $4400     $EC  3   4
             // Operation and flag results are identical to equivalent mode accumulator CMP ops.
-            Instruction::new(0xE0, "CPX", 2, 2),
-            Instruction::new(0xE4, "CPX", 2, 3),
-            Instruction::new(0xEC, "CPX", 3, 4),
+            Instruction::new(0xE0, "CPX", 2, 2, Immediate),
+            Instruction::new(0xE4, "CPX", 2, 3, ZeroPage),
+            Instruction::new(0xEC, "CPX", 3, 4, Absolute),
             // CPY (ComPare Y register)
             // Affects Flags: N Z C
             // MODE           SYNTAX       HEX LEN TIM
@@ -192,9 +236,9 @@ lazy_static! {
             // Zero Page     CPY $44       $C4  2   3
             // Absolute      CPY $4400     $CC  3   4
             // Operation and flag results are identical to equivalent mode accumulator CMP ops.
-            Instruction::new(0xC0, "CPY", 2, 2),
-            Instruction::new(0xC4, "CPY", 2, 3),
-            Instruction::new(0xCC, "CPY", 3, 4),
+            Instruction::new(0xC0, "CPY", 2, 2, Immediate),
+            Instruction::new(0xC4, "CPY", 2, 3, ZeroPage),
+            Instruction::new(0xCC, "CPY", 3, 4, Absolute),
             // DEC (DECrement memory)
             // Affects Flags: N Z
             // MODE           SYNTAX       HEX LEN TIM
@@ -202,10 +246,10 @@ lazy_static! {
             // Zero Page,X   DEC $44,X     $D6  2   6
             // Absolute      DEC $4400     $CE  3   6
             // Absolute,X    DEC $4400,X   $DE  3   7
-            Instruction::new(0xC6, "DEC", 2, 5),
-            Instruction::new(0xD6, "DEC", 2, 6),
-            Instruction::new(0xCE, "DEC", 3, 6),
-            Instruction::new(0xDE, "DEC", 3, 7),
+            Instruction::new(0xC6, "DEC", 2, 5, ZeroPage),
+            Instruction::new(0xD6, "DEC", 2, 6, ZeroPageX),
+            Instruction::new(0xCE, "DEC", 3, 6, Absolute),
+            Instruction::new(0xDE, "DEC", 3, 7, AbsoluteX),
             // EOR (bitwise Exclusive OR)
             // Affects Flags: N Z
             // MODE           SYNTAX       HEX LEN TIM
@@ -218,14 +262,14 @@ lazy_static! {
             // Indirect,X    EOR ($44,X)   $41  2   6
             // Indirect,Y    EOR ($44),Y   $51  2   5+
             // + add 1 cycle if page boundary crossed
-            Instruction::new(0x49, "EOR", 2, 2),
-            Instruction::new(0x45, "EOR", 2, 3),
-            Instruction::new(0x55, "EOR", 2, 4),
-            Instruction::new(0x4D, "EOR", 3, 4),
-            Instruction::new(0x5D, "EOR", 3, 4),
-            Instruction::new(0x59, "EOR", 3, 4),
-            Instruction::new(0x41, "EOR", 2, 6),
-            Instruction::new(0x51, "EOR", 2, 5),
+            Instruction::new(0x49, "EOR", 2, 2, Immediate),
+            Instruction::new(0x45, "EOR", 2, 3, ZeroPage),
+            Instruction::new(0x55, "EOR", 2, 4, ZeroPageX),
+            Instruction::new(0x4D, "EOR", 3, 4, Absolute),
+            Instruction::new(0x5D, "EOR", 3, 4, AbsoluteX),
+            Instruction::new(0x59, "EOR", 3, 4, AbsoluteY),
+            Instruction::new(0x41, "EOR", 2, 6, IndirectX),
+            Instruction::new(0x51, "EOR", 2, 5, IndirectY),
             // Flag (Processor Status) Instructions
             // Affect Flags: as noted
             // These instructions are implied mode, have a length of one byte and require two machine cycles.
@@ -260,13 +304,13 @@ lazy_static! {
             // the overflow flag is 0 (-127 + -1 = -128). The overflow flag is not affected by increments, decrements,
             // shifts and logical operations i.e. only ADC, BIT, CLV, PLP, RTI and SBC affect it.
             // There is no op code to set the overflow but a BIT test on an RTS instruction will do the trick.
-            Instruction::new(0x18, "CLC", 1, 2),
-            Instruction::new(0x38, "SEC", 1, 2),
-            Instruction::new(0x58, "CLI", 1, 2),
-            Instruction::new(0x78, "SEI", 1, 2),
-            Instruction::new(0xB8, "CLV", 1, 2),
-            Instruction::new(0xD8, "CLD", 1, 2),
-            Instruction::new(0xF8, "SED", 1, 2),
+            Instruction::new(0x18, "CLC", 1, 2, Implied),
+            Instruction::new(0x38, "SEC", 1, 2, Implied),
+            Instruction::new(0x58, "CLI", 1, 2, Implied),
+            Instruction::new(0x78, "SEI", 1, 2, Implied),
+            Instruction::new(0xB8, "CLV", 1, 2, Implied),
+            Instruction::new(0xD8, "CLD", 1, 2, Implied),
+            Instruction::new(0xF8, "SED", 1, 2, Implied),
             // INC (INCrement memory)
             // Affects Flags: N Z
             // MODE           SYNTAX       HEX LEN TIM
@@ -274,10 +318,10 @@ lazy_static! {
             // Zero Page,X   INC $44,X     $F6  2   6
             // Absolute      INC $4400     $EE  3   6
             // Absolute,X    INC $4400,X   $FE  3   7
-            Instruction::new(0xE6, "INC", 2, 5),
-            Instruction::new(0xF6, "INC", 2, 6),
-            Instruction::new(0xEE, "INC", 3, 6),
-            Instruction::new(0xFE, "INC", 3, 7),
+            Instruction::new(0xE6, "INC", 2, 5, ZeroPage),
+            Instruction::new(0xF6, "INC", 2, 6, ZeroPageX),
+            Instruction::new(0xEE, "INC", 3, 6, Absolute),
+            Instruction::new(0xFE, "INC", 3, 7, AbsoluteX),
             // JMP (JuMP)
             // Affects Flags: none
             // MODE           SYNTAX       HEX LEN TIM
@@ -292,8 +336,8 @@ lazy_static! {
             // For example if address $3000 contains $40, $30FF contains $80, and $3100 contains $50,
             // the result of JMP ($30FF) will be a transfer of control to $4080 rather than $5080 as you intended i.e.
             // the 6502 took the low byte of the address from $30FF and the high byte from $3000.
-            Instruction::new(0x4C, "JMP", 3, 3),
-            Instruction::new(0x6C, "JMP", 3, 5),
+            Instruction::new(0x4C, "JMP", 3, 3, Absolute),
+            Instruction::new(0x6C, "JMP", 3, 5, Indirect),
             // JSR (Jump to SubRoutine)
             // Affects Flags: none
             // MODE           SYNTAX       HEX LEN TIM
@@ -301,7 +345,7 @@ lazy_static! {
             // JSR pushes the address-1 of the next operation on to
             // the stack before transferring program control to the following address.
             // Subroutines are normally terminated by a RTS op code.
-            Instruction::new(0x20, "JSR", 3, 6),
+            Instruction::new(0x20, "JSR", 3, 6, Absolute),
             // LDA (LoaD Accumulator)
             // Affects Flags: N Z
             // MODE           SYNTAX       HEX LEN TIM
@@ -314,14 +358,14 @@ lazy_static! {
             // Indirect,X    LDA ($44,X)   $A1  2   6
             // Indirect,Y    LDA ($44),Y   $B1  2   5+
             // + add 1 cycle if page boundary crossed
-            Instruction::new(0xA9, "LDA", 2, 2),
-            Instruction::new(0xA5, "LDA", 2, 3),
-            Instruction::new(0xB5, "LDA", 2, 4),
-            Instruction::new(0xAD, "LDA", 3, 4),
-            Instruction::new(0xBD, "LDA", 3, 4),
-            Instruction::new(0xB9, "LDA", 3, 4),
-            Instruction::new(0xA1, "LDA", 2, 6),
-            Instruction::new(0xB1, "LDA", 2, 5),
+            Instruction::new(0xA9, "LDA", 2, 2, Immediate),
+            Instruction::new(0xA5, "LDA", 2, 3, ZeroPage),
+            Instruction::new(0xB5, "LDA", 2, 4, ZeroPageX),
+            Instruction::new(0xAD, "LDA", 3, 4, Absolute),
+            Instruction::new(0xBD, "LDA", 3, 4, AbsoluteX),
+            Instruction::new(0xB9, "LDA", 3, 4, AbsoluteY),
+            Instruction::new(0xA1, "LDA", 2, 6, IndirectX),
+            Instruction::new(0xB1, "LDA", 2, 5, IndirectY),
             // LDX (LoaD X register)
             // Affects Flags: N Z
             // MODE           SYNTAX       HEX LEN TIM
@@ -331,11 +375,11 @@ lazy_static! {
             // Absolute      LDX $4400     $AE  3   4
             // Absolute,Y    LDX $4400,Y   $BE  3   4+
             // + add 1 cycle if page boundary crossed
-            Instruction::new(0xA2, "LDX", 2, 2),
-            Instruction::new(0xA6, "LDX", 2, 3),
-            Instruction::new(0xB6, "LDX", 2, 4),
-            Instruction::new(0xAE, "LDX", 3, 4),
-            Instruction::new(0xBE, "LDX", 3, 4),
+            Instruction::new(0xA2, "LDX", 2, 2, Immediate),
+            Instruction::new(0xA6, "LDX", 2, 3, ZeroPage),
+            Instruction::new(0xB6, "LDX", 2, 4, ZeroPageY),
+            Instruction::new(0xAE, "LDX", 3, 4, Absolute),
+            Instruction::new(0xBE, "LDX", 3, 4, AbsoluteY),
             // LDY (LoaD Y register)
             // Affects Flags: N Z
             // MODE           SYNTAX       HEX LEN TIM
@@ -345,11 +389,11 @@ lazy_static! {
             // Absolute      LDY $4400     $AC  3   4
             // Absolute,X    LDY $4400,X   $BC  3   4+
             // + add 1 cycle if page boundary crossed
-            Instruction::new(0xA0, "LDY", 2, 2),
-            Instruction::new(0xA4, "LDY", 2, 3),
-            Instruction::new(0xB4, "LDY", 2, 4),
-            Instruction::new(0xAC, "LDY", 3, 4),
-            Instruction::new(0xBC, "LDY", 3, 4),
+            Instruction::new(0xA0, "LDY", 2, 2, Immediate),
+            Instruction::new(0xA4, "LDY", 2, 3, ZeroPage),
+            Instruction::new(0xB4, "LDY", 2, 4, ZeroPageX),
+            Instruction::new(0xAC, "LDY", 3, 4, Absolute),
+            Instruction::new(0xBC, "LDY", 3, 4, AbsoluteX),
             // LSR (Logical Shift Right)
             // Affects Flags: N Z C
             // MODE           SYNTAX       HEX LEN TIM
@@ -359,17 +403,17 @@ lazy_static! {
             // Absolute      LSR $4400     $4E  3   6
             // Absolute,X    LSR $4400,X   $5E  3   7
             // LSR shifts all bits right one position. 0 is shifted into bit 7 and the original bit 0 is shifted into the Carry.
-            Instruction::new(0x4A, "LSR", 1, 2),
-            Instruction::new(0x46, "LSR", 2, 5),
-            Instruction::new(0x56, "LSR", 2, 6),
-            Instruction::new(0x4E, "LSR", 3, 6),
-            Instruction::new(0x5E, "LSR", 3, 7),
+            Instruction::new(0x4A, "LSR", 1, 2, Accumulator),
+            Instruction::new(0x46, "LSR", 2, 5, ZeroPage),
+            Instruction::new(0x56, "LSR", 2, 6, ZeroPageX),
+            Instruction::new(0x4E, "LSR", 3, 6, Absolute),
+            Instruction::new(0x5E, "LSR", 3, 7, AbsoluteX),
             // NOP (No OPeration)
             // Affects Flags: none
             // MODE           SYNTAX       HEX LEN TIM
             // Implied       NOP           $EA  1   2
             // NOP is used to reserve space for future modifications or effectively REM out existing code.
-            Instruction::new(0xEA, "NOP", 1, 2),
+            Instruction::new(0xEA, "NOP", 1, 2, Implied),
             // ORA (bitwise OR with Accumulator)
             // Affects Flags: N Z
             // MODE           SYNTAX       HEX LEN TIM
@@ -382,14 +426,14 @@ lazy_static! {
             // Indirect,X    ORA ($44,X)   $01  2   6
             // Indirect,Y    ORA ($44),Y   $11  2   5+
             // + add 1 cycle if page boundary crossed
-            Instruction::new(0x09, "ORA", 2, 2),
-            Instruction::new(0x05, "ORA", 2, 3),
-            Instruction::new(0x15, "ORA", 2, 4),
-            Instruction::new(0x0D, "ORA", 3, 4),
-            Instruction::new(0x1D, "ORA", 3, 4),
-            Instruction::new(0x19, "ORA", 3, 4),
-            Instruction::new(0x01, "ORA", 2, 6),
-            Instruction::new(0x11, "ORA", 2, 5),
+            Instruction::new(0x09, "ORA", 2, 2, Immediate),
+            Instruction::new(0x05, "ORA", 2, 3, ZeroPage),
+            Instruction::new(0x15, "ORA", 2, 4, ZeroPageX),
+            Instruction::new(0x0D, "ORA", 3, 4, Absolute),
+            Instruction::new(0x1D, "ORA", 3, 4, AbsoluteX),
+            Instruction::new(0x19, "ORA", 3, 4, AbsoluteY),
+            Instruction::new(0x01, "ORA", 2, 6, IndirectX),
+            Instruction::new(0x11, "ORA", 2, 5, IndirectY),
             // Register Instructions
             // Affect Flags: N Z
             // These instructions are implied mode, have a length of one byte and require two machine cycles.
@@ -402,14 +446,14 @@ lazy_static! {
             // TYA (Transfer Y to A)    $98
             // DEY (DEcrement Y)        $88
             // INY (INcrement Y)        $C8
-            Instruction::new(0xAA, "TAX", 1, 2),
-            Instruction::new(0x8A, "TXA", 1, 2),
-            Instruction::new(0xCA, "DEX", 1, 2),
-            Instruction::new(0xE8, "INX", 1, 2),
-            Instruction::new(0xA8, "TAY", 1, 2),
-            Instruction::new(0x98, "TYA", 1, 2),
-            Instruction::new(0x88, "DEY", 1, 2),
-            Instruction::new(0xC8, "INY", 1, 2),
+            Instruction::new(0xAA, "TAX", 1, 2, Implied),
+            Instruction::new(0x8A, "TXA", 1, 2, Implied),
+            Instruction::new(0xCA, "DEX", 1, 2, Implied),
+            Instruction::new(0xE8, "INX", 1, 2, Implied),
+            Instruction::new(0xA8, "TAY", 1, 2, Implied),
+            Instruction::new(0x98, "TYA", 1, 2, Implied),
+            Instruction::new(0x88, "DEY", 1, 2, Implied),
+            Instruction::new(0xC8, "INY", 1, 2, Implied),
             // ROL (ROtate Left)
             // Affects Flags: N Z C
             // MODE           SYNTAX       HEX LEN TIM
@@ -420,11 +464,11 @@ lazy_static! {
             // Absolute,X    ROL $4400,X   $3E  3   7
             // ROL shifts all bits left one position. The Carry is shifted into bit 0
             // and the original bit 7 is shifted into the Carry.
-            Instruction::new(0x2A, "ROL", 1, 2),
-            Instruction::new(0x26, "ROL", 2, 5),
-            Instruction::new(0x36, "ROL", 2, 6),
-            Instruction::new(0x2E, "ROL", 3, 6),
-            Instruction::new(0x3E, "ROL", 3, 7),
+            Instruction::new(0x2A, "ROL", 1, 2, Accumulator),
+            Instruction::new(0x26, "ROL", 2, 5, ZeroPage),
+            Instruction::new(0x36, "ROL", 2, 6, ZeroPageX),
+            Instruction::new(0x2E, "ROL", 3, 6, Absolute),
+            Instruction::new(0x3E, "ROL", 3, 7, AbsoluteX),
             // ROR (ROtate Right)
             // Affects Flags: N Z C
             // MODE           SYNTAX       HEX LEN TIM
@@ -435,11 +479,11 @@ lazy_static! {
             // Absolute,X    ROR $4400,X   $7E  3   7
             // ROR shifts all bits right one position. The Carry is shifted into bit 7
             // and the original bit 0 is shifted into the Carry.
-            Instruction::new(0x6A, "ROR", 1, 2),
-            Instruction::new(0x66, "ROR", 2, 5),
-            Instruction::new(0x76, "ROR", 2, 6),
-            Instruction::new(0x6E, "ROR", 3, 6),
-            Instruction::new(0x7E, "ROR", 3, 7),
+            Instruction::new(0x6A, "ROR", 1, 2, Accumulator),
+            Instruction::new(0x66, "ROR", 2, 5, ZeroPage),
+            Instruction::new(0x76, "ROR", 2, 6, ZeroPageX),
+            Instruction::new(0x6E, "ROR", 3, 6, Absolute),
+            Instruction::new(0x7E, "ROR", 3, 7, AbsoluteX),
             // RTI (ReTurn from Interrupt)
             // Affects Flags: all
             // MODE           SYNTAX       HEX LEN TIM
@@ -447,7 +491,7 @@ lazy_static! {
             // RTI retrieves the Processor Status Word (flags) and the Program Counter
             // from the stack in that order (interrupts push the PC first and then the PSW).
             // Note that unlike RTS, the return address on the stack is the actual address rather than the address-1.
-            Instruction::new(0x40, "RTI", 1, 6),
+            Instruction::new(0x40, "RTI", 1, 6, Implied),
             // RTS (ReTurn from Subroutine)
             // Affects Flags: none
             // MODE           SYNTAX       HEX LEN TIM
@@ -471,7 +515,7 @@ lazy_static! {
             //  LDA LOBYTE,X
             //  PHA
             //  RTS
-            Instruction::new(0x60, "RTS", 1, 6),
+            Instruction::new(0x60, "RTS", 1, 6, Implied),
             // SBC (SuBtract with Carry)
             // Affects Flags: N V Z C
             // MODE           SYNTAX       HEX LEN TIM
@@ -490,14 +534,14 @@ lazy_static! {
             // There is no way to subtract without the carry which works as an inverse borrow. i.e,
             // to subtract you set the carry before the operation. If the carry is cleared by the operation,
             // it indicates a borrow occurred.
-            Instruction::new(0xE9, "SBC", 2, 2),
-            Instruction::new(0xE5, "SBC", 2, 3),
-            Instruction::new(0xF5, "SBC", 2, 4),
-            Instruction::new(0xED, "SBC", 3, 4),
-            Instruction::new(0xFD, "SBC", 3, 4),
-            Instruction::new(0xF9, "SBC", 3, 4),
-            Instruction::new(0xE1, "SBC", 2, 6),
-            Instruction::new(0xF1, "SBC", 2, 5),
+            Instruction::new(0xE9, "SBC", 2, 2, Immediate),
+            Instruction::new(0xE5, "SBC", 2, 3, ZeroPage),
+            Instruction::new(0xF5, "SBC", 2, 4, ZeroPageX),
+            Instruction::new(0xED, "SBC", 3, 4, Absolute),
+            Instruction::new(0xFD, "SBC", 3, 4, AbsoluteX),
+            Instruction::new(0xF9, "SBC", 3, 4, AbsoluteY),
+            Instruction::new(0xE1, "SBC", 2, 6, IndirectX),
+            Instruction::new(0xF1, "SBC", 2, 5, IndirectY),
             // STA (STore Accumulator)
             // Affects Flags: none
             // MODE           SYNTAX       HEX LEN TIM
@@ -508,13 +552,13 @@ lazy_static! {
             // Absolute,Y    STA $4400,Y   $99  3   5
             // Indirect,X    STA ($44,X)   $81  2   6
             // Indirect,Y    STA ($44),Y   $91  2   6
-            Instruction::new(0x85, "STA", 2, 3),
-            Instruction::new(0x95, "STA", 2, 4),
-            Instruction::new(0x8D, "STA", 3, 4),
-            Instruction::new(0x9D, "STA", 3, 5),
-            Instruction::new(0x99, "STA", 3, 5),
-            Instruction::new(0x81, "STA", 2, 6),
-            Instruction::new(0x91, "STA", 2, 6),
+            Instruction::new(0x85, "STA", 2, 3, ZeroPage),
+            Instruction::new(0x95, "STA", 2, 4, ZeroPageX),
+            Instruction::new(0x8D, "STA", 3, 4, Absolute),
+            Instruction::new(0x9D, "STA", 3, 5, AbsoluteX),
+            Instruction::new(0x99, "STA", 3, 5, AbsoluteY),
+            Instruction::new(0x81, "STA", 2, 6, IndirectX),
+            Instruction::new(0x91, "STA", 2, 6, IndirectY),
             // Stack Instructions
             // These instructions are implied mode, have a length of one byte and require machine cycles as indicated.
             // The "PuLl" operations are known as "POP" on most other microprocessors. With the 6502,
@@ -526,36 +570,521 @@ lazy_static! {
             // PLA (PuLl Accumulator)          $68  4
             // PHP (PusH Processor status)     $08  3
             // PLP (PuLl Processor status)     $28  4
-            Instruction::new(0x9A, "TXS", 1, 2),
-            Instruction::new(0xBA, "TSX", 1, 2),
-            Instruction::new(0x48, "PHA", 1, 3),
-            Instruction::new(0x68, "PLA", 1, 4),
-            Instruction::new(0x08, "PHP", 1, 3),
-            Instruction::new(0x28, "PLP", 1, 4),
+            Instruction::new(0x9A, "TXS", 1, 2, Implied),
+            Instruction::new(0xBA, "TSX", 1, 2, Implied),
+            Instruction::new(0x48, "PHA", 1, 3, Implied),
+            Instruction::new(0x68, "PLA", 1, 4, Implied),
+            Instruction::new(0x08, "PHP", 1, 3, Implied),
+            Instruction::new(0x28, "PLP", 1, 4, Implied),
             // STX (STore X register)
             // Affects Flags: none
             // MODE           SYNTAX       HEX LEN TIM
             // Zero Page     STX $44       $86  2   3
             // Zero Page,Y   STX $44,Y     $96  2   4
             // Absolute      STX $4400     $8E  3   4
-            Instruction::new(0x86, "STX", 2, 3),
-            Instruction::new(0x96, "STX", 2, 4),
-            Instruction::new(0x8E, "STX", 3, 4),
+            Instruction::new(0x86, "STX", 2, 3, ZeroPage),
+            Instruction::new(0x96, "STX", 2, 4, ZeroPageY),
+            Instruction::new(0x8E, "STX", 3, 4, Absolute),
             // STY (STore Y register)
             // Affects Flags: none
             // MODE           SYNTAX       HEX LEN TIM
             // Zero Page     STY $44       $84  2   3
             // Zero Page,X   STY $44,X     $94  2   4
             // Absolute      STY $4400     $8C  3   4
-            Instruction::new(0x84, "STY", 2, 3),
-            Instruction::new(0x94, "STY", 2, 4),
-            Instruction::new(0x8C, "STY", 3, 4),
+            Instruction::new(0x84, "STY", 2, 3, ZeroPage),
+            Instruction::new(0x94, "STY", 2, 4, ZeroPageX),
+            Instruction::new(0x8C, "STY", 3, 4, Absolute),
+        ];
+    /**
+     * Ref : https://www.masswerk.at/6502/6502_instruction_set.html#illegals
+     * Undocumented NMOS op codes. Many NES and C64 programs rely on the stable ones.
+     */
+    pub static ref CPU_6502_UNDOCUMENTED_OPERATION_CODES: Vec<Instruction> = vec![
+            // SLO (Shift Left then OR)
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     SLO $44       $07  2   5
+            // Zero Page,X   SLO $44,X     $17  2   6
+            // Absolute      SLO $4400     $0F  3   6
+            // Absolute,X    SLO $4400,X   $1F  3   7
+            // Absolute,Y    SLO $4400,Y   $1B  3   7
+            // Indirect,X    SLO ($44,X)   $03  2   8
+            // Indirect,Y    SLO ($44),Y   $13  2   8
+            // ASL memory, then ORA the result into the accumulator.
+            Instruction::new(0x07, "SLO", 2, 5, ZeroPage),
+            Instruction::new(0x17, "SLO", 2, 6, ZeroPageX),
+            Instruction::new(0x0F, "SLO", 3, 6, Absolute),
+            Instruction::new(0x1F, "SLO", 3, 7, AbsoluteX),
+            Instruction::new(0x1B, "SLO", 3, 7, AbsoluteY),
+            Instruction::new(0x03, "SLO", 2, 8, IndirectX),
+            Instruction::new(0x13, "SLO", 2, 8, IndirectY),
+            // RLA (Rotate Left then AND)
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     RLA $44       $27  2   5
+            // Zero Page,X   RLA $44,X     $37  2   6
+            // Absolute      RLA $4400     $2F  3   6
+            // Absolute,X    RLA $4400,X   $3F  3   7
+            // Absolute,Y    RLA $4400,Y   $3B  3   7
+            // Indirect,X    RLA ($44,X)   $23  2   8
+            // Indirect,Y    RLA ($44),Y   $33  2   8
+            // ROL memory, then AND the result into the accumulator.
+            Instruction::new(0x27, "RLA", 2, 5, ZeroPage),
+            Instruction::new(0x37, "RLA", 2, 6, ZeroPageX),
+            Instruction::new(0x2F, "RLA", 3, 6, Absolute),
+            Instruction::new(0x3F, "RLA", 3, 7, AbsoluteX),
+            Instruction::new(0x3B, "RLA", 3, 7, AbsoluteY),
+            Instruction::new(0x23, "RLA", 2, 8, IndirectX),
+            Instruction::new(0x33, "RLA", 2, 8, IndirectY),
+            // SRE (Shift Right then EOR)
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     SRE $44       $47  2   5
+            // Zero Page,X   SRE $44,X     $57  2   6
+            // Absolute      SRE $4400     $4F  3   6
+            // Absolute,X    SRE $4400,X   $5F  3   7
+            // Absolute,Y    SRE $4400,Y   $5B  3   7
+            // Indirect,X    SRE ($44,X)   $43  2   8
+            // Indirect,Y    SRE ($44),Y   $53  2   8
+            // LSR memory, then EOR the result into the accumulator.
+            Instruction::new(0x47, "SRE", 2, 5, ZeroPage),
+            Instruction::new(0x57, "SRE", 2, 6, ZeroPageX),
+            Instruction::new(0x4F, "SRE", 3, 6, Absolute),
+            Instruction::new(0x5F, "SRE", 3, 7, AbsoluteX),
+            Instruction::new(0x5B, "SRE", 3, 7, AbsoluteY),
+            Instruction::new(0x43, "SRE", 2, 8, IndirectX),
+            Instruction::new(0x53, "SRE", 2, 8, IndirectY),
+            // RRA (Rotate Right then ADC)
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     RRA $44       $67  2   5
+            // Zero Page,X   RRA $44,X     $77  2   6
+            // Absolute      RRA $4400     $6F  3   6
+            // Absolute,X    RRA $4400,X   $7F  3   7
+            // Absolute,Y    RRA $4400,Y   $7B  3   7
+            // Indirect,X    RRA ($44,X)   $63  2   8
+            // Indirect,Y    RRA ($44),Y   $73  2   8
+            // ROR memory, then ADC the result to the accumulator.
+            Instruction::new(0x67, "RRA", 2, 5, ZeroPage),
+            Instruction::new(0x77, "RRA", 2, 6, ZeroPageX),
+            Instruction::new(0x6F, "RRA", 3, 6, Absolute),
+            Instruction::new(0x7F, "RRA", 3, 7, AbsoluteX),
+            Instruction::new(0x7B, "RRA", 3, 7, AbsoluteY),
+            Instruction::new(0x63, "RRA", 2, 8, IndirectX),
+            Instruction::new(0x73, "RRA", 2, 8, IndirectY),
+            // DCP (DeCrement then ComPare)
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     DCP $44       $C7  2   5
+            // Zero Page,X   DCP $44,X     $D7  2   6
+            // Absolute      DCP $4400     $CF  3   6
+            // Absolute,X    DCP $4400,X   $DF  3   7
+            // Absolute,Y    DCP $4400,Y   $DB  3   7
+            // Indirect,X    DCP ($44,X)   $C3  2   8
+            // Indirect,Y    DCP ($44),Y   $D3  2   8
+            // DEC memory, then CMP the result with the accumulator.
+            Instruction::new(0xC7, "DCP", 2, 5, ZeroPage),
+            Instruction::new(0xD7, "DCP", 2, 6, ZeroPageX),
+            Instruction::new(0xCF, "DCP", 3, 6, Absolute),
+            Instruction::new(0xDF, "DCP", 3, 7, AbsoluteX),
+            Instruction::new(0xDB, "DCP", 3, 7, AbsoluteY),
+            Instruction::new(0xC3, "DCP", 2, 8, IndirectX),
+            Instruction::new(0xD3, "DCP", 2, 8, IndirectY),
+            // ISC (Increment then SuBtract)
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     ISC $44       $E7  2   5
+            // Zero Page,X   ISC $44,X     $F7  2   6
+            // Absolute      ISC $4400     $EF  3   6
+            // Absolute,X    ISC $4400,X   $FF  3   7
+            // Absolute,Y    ISC $4400,Y   $FB  3   7
+            // Indirect,X    ISC ($44,X)   $E3  2   8
+            // Indirect,Y    ISC ($44),Y   $F3  2   8
+            // INC memory, then SBC the result from the accumulator.
+            Instruction::new(0xE7, "ISC", 2, 5, ZeroPage),
+            Instruction::new(0xF7, "ISC", 2, 6, ZeroPageX),
+            Instruction::new(0xEF, "ISC", 3, 6, Absolute),
+            Instruction::new(0xFF, "ISC", 3, 7, AbsoluteX),
+            Instruction::new(0xFB, "ISC", 3, 7, AbsoluteY),
+            Instruction::new(0xE3, "ISC", 2, 8, IndirectX),
+            Instruction::new(0xF3, "ISC", 2, 8, IndirectY),
+            // SAX (Store A AND X)
+            // Affects Flags: none
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     SAX $44       $87  2   3
+            // Zero Page,Y   SAX $44,Y     $97  2   4
+            // Absolute      SAX $4400     $8F  3   4
+            // Indirect,X    SAX ($44,X)   $83  2   6
+            Instruction::new(0x87, "SAX", 2, 3, ZeroPage),
+            Instruction::new(0x97, "SAX", 2, 4, ZeroPageY),
+            Instruction::new(0x8F, "SAX", 3, 4, Absolute),
+            Instruction::new(0x83, "SAX", 2, 6, IndirectX),
+            // LAX (Load A and X)
+            // Affects Flags: N Z
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     LAX $44       $A7  2   3
+            // Zero Page,Y   LAX $44,Y     $B7  2   4
+            // Absolute      LAX $4400     $AF  3   4
+            // Absolute,Y    LAX $4400,Y   $BF  3   4+
+            // Indirect,X    LAX ($44,X)   $A3  2   6
+            // Indirect,Y    LAX ($44),Y   $B3  2   5+
+            // + add 1 cycle if page boundary crossed
+            Instruction::new(0xA7, "LAX", 2, 3, ZeroPage),
+            Instruction::new(0xB7, "LAX", 2, 4, ZeroPageY),
+            Instruction::new(0xAF, "LAX", 3, 4, Absolute),
+            Instruction::new(0xBF, "LAX", 3, 4, AbsoluteY),
+            Instruction::new(0xA3, "LAX", 2, 6, IndirectX),
+            Instruction::new(0xB3, "LAX", 2, 5, IndirectY),
+            // Immediate combined instructions
+            // MNEMONIC                                  HEX LEN TIM
+            // ANC #$44 (AND, then copy N to C)          $0B  2   2
+            // ANC #$44                                  $2B  2   2
+            // ALR #$44 (AND, then LSR A)                $4B  2   2
+            // ARR #$44 (AND, then ROR A)                $6B  2   2
+            // SBX #$44 ((A AND X) - #$44 to X)          $CB  2   2
+            // USBC #$44 (same as SBC #$44)              $EB  2   2
+            // XAA #$44 ((A OR CONST) AND X AND #$44)    $8B  2   2   unstable
+            // LXA #$44 ((A OR CONST) AND #$44 to A, X)  $AB  2   2   unstable
+            Instruction::new(0x0B, "ANC", 2, 2, Immediate),
+            Instruction::new(0x2B, "ANC", 2, 2, Immediate),
+            Instruction::new(0x4B, "ALR", 2, 2, Immediate),
+            Instruction::new(0x6B, "ARR", 2, 2, Immediate),
+            Instruction::new(0xCB, "SBX", 2, 2, Immediate),
+            Instruction::new(0xEB, "USBC", 2, 2, Immediate),
+            Instruction::new(0x8B, "XAA", 2, 2, Immediate),
+            Instruction::new(0xAB, "LXA", 2, 2, Immediate),
+            // High byte stores and LAS
+            // MODE           SYNTAX        HEX LEN TIM
+            // Absolute,Y    LAS $4400,Y   $BB  3   4+
+            // Absolute,Y    SHA $4400,Y   $9F  3   5   unstable
+            // Indirect,Y    SHA ($44),Y   $93  2   6   unstable
+            // Absolute,Y    SHX $4400,Y   $9E  3   5   unstable
+            // Absolute,X    SHY $4400,X   $9C  3   5   unstable
+            // Absolute,Y    TAS $4400,Y   $9B  3   5   unstable
+            // The stores write (register AND high byte of the base address + 1).
+            Instruction::new(0xBB, "LAS", 3, 4, AbsoluteY),
+            Instruction::new(0x9F, "SHA", 3, 5, AbsoluteY),
+            Instruction::new(0x93, "SHA", 2, 6, IndirectY),
+            Instruction::new(0x9E, "SHX", 3, 5, AbsoluteY),
+            Instruction::new(0x9C, "SHY", 3, 5, AbsoluteX),
+            Instruction::new(0x9B, "TAS", 3, 5, AbsoluteY),
+            // NOP (No OPeration), undocumented variants
+            // MODE           SYNTAX        HEX                          LEN TIM
+            // Implied       NOP           $1A $3A $5A $7A $DA $FA       1   2
+            // Immediate     NOP #$44      $80 $82 $89 $C2 $E2           2   2
+            // Zero Page     NOP $44       $04 $44 $64                   2   3
+            // Zero Page,X   NOP $44,X     $14 $34 $54 $74 $D4 $F4       2   4
+            // Absolute      NOP $4400     $0C                           3   4
+            // Absolute,X    NOP $4400,X   $1C $3C $5C $7C $DC $FC       3   4+
+            // The operand is read and discarded.
+            Instruction::new(0x1A, "NOP", 1, 2, Implied),
+            Instruction::new(0x3A, "NOP", 1, 2, Implied),
+            Instruction::new(0x5A, "NOP", 1, 2, Implied),
+            Instruction::new(0x7A, "NOP", 1, 2, Implied),
+            Instruction::new(0xDA, "NOP", 1, 2, Implied),
+            Instruction::new(0xFA, "NOP", 1, 2, Implied),
+            Instruction::new(0x80, "NOP", 2, 2, Immediate),
+            Instruction::new(0x82, "NOP", 2, 2, Immediate),
+            Instruction::new(0x89, "NOP", 2, 2, Immediate),
+            Instruction::new(0xC2, "NOP", 2, 2, Immediate),
+            Instruction::new(0xE2, "NOP", 2, 2, Immediate),
+            Instruction::new(0x04, "NOP", 2, 3, ZeroPage),
+            Instruction::new(0x44, "NOP", 2, 3, ZeroPage),
+            Instruction::new(0x64, "NOP", 2, 3, ZeroPage),
+            Instruction::new(0x14, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0x34, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0x54, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0x74, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0xD4, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0xF4, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0x0C, "NOP", 3, 4, Absolute),
+            Instruction::new(0x1C, "NOP", 3, 4, AbsoluteX),
+            Instruction::new(0x3C, "NOP", 3, 4, AbsoluteX),
+            Instruction::new(0x5C, "NOP", 3, 4, AbsoluteX),
+            Instruction::new(0x7C, "NOP", 3, 4, AbsoluteX),
+            Instruction::new(0xDC, "NOP", 3, 4, AbsoluteX),
+            Instruction::new(0xFC, "NOP", 3, 4, AbsoluteX),
+            // JAM (also KIL, HLT)
+            // HEX : $02 $12 $22 $32 $42 $52 $62 $72 $92 $B2 $D2 $F2
+            // Freezes the processor with $FF on the data bus. Only RESET recovers.
+            Instruction::new(0x02, "JAM", 1, 2, Implied),
+            Instruction::new(0x12, "JAM", 1, 2, Implied),
+            Instruction::new(0x22, "JAM", 1, 2, Implied),
+            Instruction::new(0x32, "JAM", 1, 2, Implied),
+            Instruction::new(0x42, "JAM", 1, 2, Implied),
+            Instruction::new(0x52, "JAM", 1, 2, Implied),
+            Instruction::new(0x62, "JAM", 1, 2, Implied),
+            Instruction::new(0x72, "JAM", 1, 2, Implied),
+            Instruction::new(0x92, "JAM", 1, 2, Implied),
+            Instruction::new(0xB2, "JAM", 1, 2, Implied),
+            Instruction::new(0xD2, "JAM", 1, 2, Implied),
+            Instruction::new(0xF2, "JAM", 1, 2, Implied),
+        ];
+    /**
+     * Ref : http://www.6502.org/tutorials/65c02opcodes.html
+     * 65C02 op codes that are new or replace an entry of the NMOS table.
+     */
+    pub static ref CPU_65C02_OPERATION_CODES: Vec<Instruction> = vec![
+            // BRA (BRanch Always)
+            // Affects Flags: none
+            // MODE           SYNTAX       HEX LEN TIM
+            // Relative      BRA $nn       $80  2   3+
+            // + add 1 cycle if page boundary crossed
+            Instruction::new(0x80, "BRA", 2, 2, Relative),
+            // Stack Instructions for the index registers
+            // MNEMONIC                        HEX TIM
+            // PHX (PusH X register)           $DA  3
+            // PHY (PusH Y register)           $5A  3
+            // PLX (PuLl X register)           $FA  4
+            // PLY (PuLl Y register)           $7A  4
+            Instruction::new(0xDA, "PHX", 1, 3, Implied),
+            Instruction::new(0x5A, "PHY", 1, 3, Implied),
+            Instruction::new(0xFA, "PLX", 1, 4, Implied),
+            Instruction::new(0x7A, "PLY", 1, 4, Implied),
+            // STZ (STore Zero)
+            // Affects Flags: none
+            // MODE           SYNTAX       HEX LEN TIM
+            // Zero Page     STZ $44       $64  2   3
+            // Zero Page,X   STZ $44,X     $74  2   4
+            // Absolute      STZ $4400     $9C  3   4
+            // Absolute,X    STZ $4400,X   $9E  3   5
+            Instruction::new(0x64, "STZ", 2, 3, ZeroPage),
+            Instruction::new(0x74, "STZ", 2, 4, ZeroPageX),
+            Instruction::new(0x9C, "STZ", 3, 4, Absolute),
+            Instruction::new(0x9E, "STZ", 3, 5, AbsoluteX),
+            // TRB (Test and Reset Bits) / TSB (Test and Set Bits)
+            // Affects Flags: Z
+            // MODE           SYNTAX       HEX LEN TIM
+            // Zero Page     TRB $44       $14  2   5
+            // Absolute      TRB $4400     $1C  3   6
+            // Zero Page     TSB $44       $04  2   5
+            // Absolute      TSB $4400     $0C  3   6
+            Instruction::new(0x14, "TRB", 2, 5, ZeroPage),
+            Instruction::new(0x1C, "TRB", 3, 6, Absolute),
+            Instruction::new(0x04, "TSB", 2, 5, ZeroPage),
+            Instruction::new(0x0C, "TSB", 3, 6, Absolute),
+            // INC / DEC on the accumulator
+            // Affects Flags: N Z
+            // MODE           SYNTAX       HEX LEN TIM
+            // Accumulator   INC A         $1A  1   2
+            // Accumulator   DEC A         $3A  1   2
+            Instruction::new(0x1A, "INC", 1, 2, Accumulator),
+            Instruction::new(0x3A, "DEC", 1, 2, Accumulator),
+            // Zero Page Indirect
+            // MODE              SYNTAX        HEX LEN TIM
+            // (Zero Page)      ORA ($44)     $12  2   5
+            // (Zero Page)      AND ($44)     $32  2   5
+            // (Zero Page)      EOR ($44)     $52  2   5
+            // (Zero Page)      ADC ($44)     $72  2   5
+            // (Zero Page)      STA ($44)     $92  2   5
+            // (Zero Page)      LDA ($44)     $B2  2   5
+            // (Zero Page)      CMP ($44)     $D2  2   5
+            // (Zero Page)      SBC ($44)     $F2  2   5
+            Instruction::new(0x12, "ORA", 2, 5, ZeroPageIndirect),
+            Instruction::new(0x32, "AND", 2, 5, ZeroPageIndirect),
+            Instruction::new(0x52, "EOR", 2, 5, ZeroPageIndirect),
+            Instruction::new(0x72, "ADC", 2, 5, ZeroPageIndirect),
+            Instruction::new(0x92, "STA", 2, 5, ZeroPageIndirect),
+            Instruction::new(0xB2, "LDA", 2, 5, ZeroPageIndirect),
+            Instruction::new(0xD2, "CMP", 2, 5, ZeroPageIndirect),
+            Instruction::new(0xF2, "SBC", 2, 5, ZeroPageIndirect),
+            // BIT, new addressing modes
+            // MODE           SYNTAX       HEX LEN TIM
+            // Immediate     BIT #$44      $89  2   2
+            // Zero Page,X   BIT $44,X     $34  2   4
+            // Absolute,X    BIT $4400,X   $3C  3   4+
+            // + add 1 cycle if page boundary crossed
+            // BIT immediate only affects the Z flag.
+            Instruction::new(0x89, "BIT", 2, 2, Immediate),
+            Instruction::new(0x34, "BIT", 2, 4, ZeroPageX),
+            Instruction::new(0x3C, "BIT", 3, 4, AbsoluteX),
+            // JMP, fixed indirect and new indexed indirect
+            // MODE                SYNTAX          HEX LEN TIM
+            // Indirect           JMP ($4400)     $6C  3   6
+            // Indexed Indirect   JMP ($4400,X)   $7C  3   6
+            // JMP ($xxFF) reads the high byte from the next page, costing one more cycle than NMOS.
+            Instruction::new(0x6C, "JMP", 3, 6, Indirect),
+            Instruction::new(0x7C, "JMP", 3, 6, AbsoluteIndexedIndirect),
+            // Shifts and rotates, absolute indexed
+            // MODE           SYNTAX       HEX LEN TIM
+            // Absolute,X    ASL $4400,X   $1E  3   6+
+            // Absolute,X    ROL $4400,X   $3E  3   6+
+            // Absolute,X    LSR $4400,X   $5E  3   6+
+            // Absolute,X    ROR $4400,X   $7E  3   6+
+            // + add 1 cycle if page boundary crossed. INC / DEC $4400,X keep 7 cycles.
+            Instruction::new(0x1E, "ASL", 3, 6, AbsoluteX),
+            Instruction::new(0x3E, "ROL", 3, 6, AbsoluteX),
+            Instruction::new(0x5E, "LSR", 3, 6, AbsoluteX),
+            Instruction::new(0x7E, "ROR", 3, 6, AbsoluteX),
+            // NOP, unused op codes
+            // MODE           HEX                                  LEN TIM
+            // Implied       $x3 $x7 $xB $xF                        1   1
+            // Immediate     $02 $22 $42 $62 $82 $C2 $E2            2   2
+            // Zero Page     $44                                    2   3
+            // Zero Page,X   $54 $D4 $F4                            2   4
+            // Absolute      $5C                                    3   8
+            // Absolute      $DC $FC                                3   4
+            Instruction::new(0x03, "NOP", 1, 1, Implied),
+            Instruction::new(0x07, "NOP", 1, 1, Implied),
+            Instruction::new(0x0B, "NOP", 1, 1, Implied),
+            Instruction::new(0x0F, "NOP", 1, 1, Implied),
+            Instruction::new(0x13, "NOP", 1, 1, Implied),
+            Instruction::new(0x17, "NOP", 1, 1, Implied),
+            Instruction::new(0x1B, "NOP", 1, 1, Implied),
+            Instruction::new(0x1F, "NOP", 1, 1, Implied),
+            Instruction::new(0x23, "NOP", 1, 1, Implied),
+            Instruction::new(0x27, "NOP", 1, 1, Implied),
+            Instruction::new(0x2B, "NOP", 1, 1, Implied),
+            Instruction::new(0x2F, "NOP", 1, 1, Implied),
+            Instruction::new(0x33, "NOP", 1, 1, Implied),
+            Instruction::new(0x37, "NOP", 1, 1, Implied),
+            Instruction::new(0x3B, "NOP", 1, 1, Implied),
+            Instruction::new(0x3F, "NOP", 1, 1, Implied),
+            Instruction::new(0x43, "NOP", 1, 1, Implied),
+            Instruction::new(0x47, "NOP", 1, 1, Implied),
+            Instruction::new(0x4B, "NOP", 1, 1, Implied),
+            Instruction::new(0x4F, "NOP", 1, 1, Implied),
+            Instruction::new(0x53, "NOP", 1, 1, Implied),
+            Instruction::new(0x57, "NOP", 1, 1, Implied),
+            Instruction::new(0x5B, "NOP", 1, 1, Implied),
+            Instruction::new(0x5F, "NOP", 1, 1, Implied),
+            Instruction::new(0x63, "NOP", 1, 1, Implied),
+            Instruction::new(0x67, "NOP", 1, 1, Implied),
+            Instruction::new(0x6B, "NOP", 1, 1, Implied),
+            Instruction::new(0x6F, "NOP", 1, 1, Implied),
+            Instruction::new(0x73, "NOP", 1, 1, Implied),
+            Instruction::new(0x77, "NOP", 1, 1, Implied),
+            Instruction::new(0x7B, "NOP", 1, 1, Implied),
+            Instruction::new(0x7F, "NOP", 1, 1, Implied),
+            Instruction::new(0x83, "NOP", 1, 1, Implied),
+            Instruction::new(0x87, "NOP", 1, 1, Implied),
+            Instruction::new(0x8B, "NOP", 1, 1, Implied),
+            Instruction::new(0x8F, "NOP", 1, 1, Implied),
+            Instruction::new(0x93, "NOP", 1, 1, Implied),
+            Instruction::new(0x97, "NOP", 1, 1, Implied),
+            Instruction::new(0x9B, "NOP", 1, 1, Implied),
+            Instruction::new(0x9F, "NOP", 1, 1, Implied),
+            Instruction::new(0xA3, "NOP", 1, 1, Implied),
+            Instruction::new(0xA7, "NOP", 1, 1, Implied),
+            Instruction::new(0xAB, "NOP", 1, 1, Implied),
+            Instruction::new(0xAF, "NOP", 1, 1, Implied),
+            Instruction::new(0xB3, "NOP", 1, 1, Implied),
+            Instruction::new(0xB7, "NOP", 1, 1, Implied),
+            Instruction::new(0xBB, "NOP", 1, 1, Implied),
+            Instruction::new(0xBF, "NOP", 1, 1, Implied),
+            Instruction::new(0xC3, "NOP", 1, 1, Implied),
+            Instruction::new(0xC7, "NOP", 1, 1, Implied),
+            Instruction::new(0xCB, "NOP", 1, 1, Implied),
+            Instruction::new(0xCF, "NOP", 1, 1, Implied),
+            Instruction::new(0xD3, "NOP", 1, 1, Implied),
+            Instruction::new(0xD7, "NOP", 1, 1, Implied),
+            Instruction::new(0xDB, "NOP", 1, 1, Implied),
+            Instruction::new(0xDF, "NOP", 1, 1, Implied),
+            Instruction::new(0xE3, "NOP", 1, 1, Implied),
+            Instruction::new(0xE7, "NOP", 1, 1, Implied),
+            Instruction::new(0xEB, "NOP", 1, 1, Implied),
+            Instruction::new(0xEF, "NOP", 1, 1, Implied),
+            Instruction::new(0xF3, "NOP", 1, 1, Implied),
+            Instruction::new(0xF7, "NOP", 1, 1, Implied),
+            Instruction::new(0xFB, "NOP", 1, 1, Implied),
+            Instruction::new(0xFF, "NOP", 1, 1, Implied),
+            Instruction::new(0x02, "NOP", 2, 2, Immediate),
+            Instruction::new(0x22, "NOP", 2, 2, Immediate),
+            Instruction::new(0x42, "NOP", 2, 2, Immediate),
+            Instruction::new(0x62, "NOP", 2, 2, Immediate),
+            Instruction::new(0x82, "NOP", 2, 2, Immediate),
+            Instruction::new(0xC2, "NOP", 2, 2, Immediate),
+            Instruction::new(0xE2, "NOP", 2, 2, Immediate),
+            Instruction::new(0x44, "NOP", 2, 3, ZeroPage),
+            Instruction::new(0x54, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0xD4, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0xF4, "NOP", 2, 4, ZeroPageX),
+            Instruction::new(0x5C, "NOP", 3, 8, Absolute),
+            Instruction::new(0xDC, "NOP", 3, 4, Absolute),
+            Instruction::new(0xFC, "NOP", 3, 4, Absolute),
+        ];
+    /**
+     * Ref : https://www.westerndesigncenter.com/wdc/documentation/w65c02s.pdf
+     * W65C02S op codes added on top of the 65C02 table.
+     */
+    pub static ref CPU_W65C02S_OPERATION_CODES: Vec<Instruction> = vec![
+            // RMB / SMB (Reset / Set Memory Bit)
+            // Affects Flags: none
+            // MODE           SYNTAX        HEX LEN TIM
+            // Zero Page     RMB0 $44      $07  2   5
+            // Zero Page     SMB0 $44      $87  2   5
+            // The bit number is bits 4-6 of the op code : RMB0-7 $07-$77, SMB0-7 $87-$F7.
+            Instruction::new(0x07, "RMB0", 2, 5, ZeroPage),
+            Instruction::new(0x17, "RMB1", 2, 5, ZeroPage),
+            Instruction::new(0x27, "RMB2", 2, 5, ZeroPage),
+            Instruction::new(0x37, "RMB3", 2, 5, ZeroPage),
+            Instruction::new(0x47, "RMB4", 2, 5, ZeroPage),
+            Instruction::new(0x57, "RMB5", 2, 5, ZeroPage),
+            Instruction::new(0x67, "RMB6", 2, 5, ZeroPage),
+            Instruction::new(0x77, "RMB7", 2, 5, ZeroPage),
+            Instruction::new(0x87, "SMB0", 2, 5, ZeroPage),
+            Instruction::new(0x97, "SMB1", 2, 5, ZeroPage),
+            Instruction::new(0xA7, "SMB2", 2, 5, ZeroPage),
+            Instruction::new(0xB7, "SMB3", 2, 5, ZeroPage),
+            Instruction::new(0xC7, "SMB4", 2, 5, ZeroPage),
+            Instruction::new(0xD7, "SMB5", 2, 5, ZeroPage),
+            Instruction::new(0xE7, "SMB6", 2, 5, ZeroPage),
+            Instruction::new(0xF7, "SMB7", 2, 5, ZeroPage),
+            // BBR / BBS (Branch on Bit Reset / Set)
+            // Affects Flags: none
+            // MODE                SYNTAX             HEX LEN TIM
+            // Zero Page,Relative BBR0 $44,$nn       $0F  3   5+
+            // Zero Page,Relative BBS0 $44,$nn       $8F  3   5+
+            // + add 1 cycle if branch is taken, 1 more if it crosses a page
+            // The bit number is bits 4-6 of the op code : BBR0-7 $0F-$7F, BBS0-7 $8F-$FF.
+            Instruction::new(0x0F, "BBR0", 3, 5, ZeroPageRelative),
+            Instruction::new(0x1F, "BBR1", 3, 5, ZeroPageRelative),
+            Instruction::new(0x2F, "BBR2", 3, 5, ZeroPageRelative),
+            Instruction::new(0x3F, "BBR3", 3, 5, ZeroPageRelative),
+            Instruction::new(0x4F, "BBR4", 3, 5, ZeroPageRelative),
+            Instruction::new(0x5F, "BBR5", 3, 5, ZeroPageRelative),
+            Instruction::new(0x6F, "BBR6", 3, 5, ZeroPageRelative),
+            Instruction::new(0x7F, "BBR7", 3, 5, ZeroPageRelative),
+            Instruction::new(0x8F, "BBS0", 3, 5, ZeroPageRelative),
+            Instruction::new(0x9F, "BBS1", 3, 5, ZeroPageRelative),
+            Instruction::new(0xAF, "BBS2", 3, 5, ZeroPageRelative),
+            Instruction::new(0xBF, "BBS3", 3, 5, ZeroPageRelative),
+            Instruction::new(0xCF, "BBS4", 3, 5, ZeroPageRelative),
+            Instruction::new(0xDF, "BBS5", 3, 5, ZeroPageRelative),
+            Instruction::new(0xEF, "BBS6", 3, 5, ZeroPageRelative),
+            Instruction::new(0xFF, "BBS7", 3, 5, ZeroPageRelative),
+            // WAI (WAit for Interrupt) / STP (SToP the clock)
+            // MNEMONIC    HEX LEN TIM
+            // WAI         $CB  1   3
+            // STP         $DB  1   3
+            Instruction::new(0xCB, "WAI", 1, 3, Implied),
+            Instruction::new(0xDB, "STP", 1, 3, Implied),
         ];
     pub static ref CPU_6502_OPERATION_CODES_MAP: HashMap<u8, &'static Instruction> = {
         let mut map = HashMap::new();
-        for cpu_6502_operation_code in &*CPU_6502_OPERATION_CODES {
+        for cpu_6502_operation_code in CPU_6502_OPERATION_CODES
+            .iter()
+            .chain(CPU_6502_UNDOCUMENTED_OPERATION_CODES.iter())
+        {
             map.insert(cpu_6502_operation_code.code, cpu_6502_operation_code);
         }
         map
     };
+    pub static ref CPU_65C02_OPERATION_CODES_MAP: HashMap<u8, &'static Instruction> = {
+        let mut map = HashMap::new();
+        // Official NMOS op codes first, the 65C02 entries override them
+        for cpu_65c02_operation_code in CPU_6502_OPERATION_CODES
+            .iter()
+            .chain(CPU_65C02_OPERATION_CODES.iter())
+        {
+            map.insert(cpu_65c02_operation_code.code, cpu_65c02_operation_code);
+        }
+        map
+    };
+    pub static ref CPU_W65C02S_OPERATION_CODES_MAP: HashMap<u8, &'static Instruction> = {
+        let mut map = HashMap::new();
+        // 65C02 table first, the W65C02S entries override its NOPs
+        for cpu_w65c02s_operation_code in CPU_6502_OPERATION_CODES
+            .iter()
+            .chain(CPU_65C02_OPERATION_CODES.iter())
+            .chain(CPU_W65C02S_OPERATION_CODES.iter())
+        {
+            map.insert(cpu_w65c02s_operation_code.code, cpu_w65c02s_operation_code);
+        }
+        map
+    };
 }
@@ -1,5 +1,10 @@
+pub mod addressing;
 pub mod instruction;
 pub mod model;
 pub mod operations;
 pub mod processor_status;
 pub mod usecase;
+pub mod w65c816;
+
+#[cfg(test)]
+mod mod_test;
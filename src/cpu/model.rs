@@ -33,18 +33,177 @@
 //                          |_____________________|
 //                                   Memory
 //
+use std::cell::RefCell;
+
 use crate::bus::model::Bus;
 use crate::cpu::instruction::Instruction;
 use crate::cpu::processor_status::ProcessorStatus;
+use crate::interfaces::bus::IBus;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum State {
     Fetch,
     Decode,
     Execute,
     Exit,
+    /* Halted by a JAM op code, only RESET recovers */
+    Jam,
+    /* Sleeping after WAI until an IRQ or NMI is asserted */
+    Wait,
+    /* Stopped by STP, only RESET recovers */
+    Stop,
+}
+
+/*
+   Kind of RESET applied to the processor.
+   Cold : power-on, registers cleared to zero.
+   ColdRandom : power-on, registers filled with seeded garbage like real silicon.
+   Warm : RESET line pulled while running, registers keep their values.
+*/
+pub enum ResetKind {
+    Cold,
+    ColdRandom(u64),
+    Warm,
+}
+
+/*
+   Processor family being emulated.
+   Nmos : original MOS 6502, including the undocumented op codes.
+   Cmos : 65C02, new instructions, unused op codes are NOPs and the NMOS quirks are fixed.
+   Wdc : W65C02S, the 65C02 plus RMB/SMB/BBR/BBS and WAI/STP.
+   Ricoh2A03 : NES CPU, an NMOS core whose decimal mode is disconnected.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Nmos,
+    Cmos,
+    Wdc,
+    Ricoh2A03,
+}
+
+/*
+   Conditions the processor reports to the host instead of panicking.
+   pc is the address of the op code that raised the condition.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuError {
+    /* Op code missing from the table of the selected processor, with the bytes at PC */
+    UnknownOpcode { pc: u16, bytes: [u8; 3] },
+    /* JAM op code executed, the decoder is locked until RESET */
+    Jam { pc: u16, opcode: u8 },
+    /* Push past $0100 or pull past $01FF, the stack pointer wrapped inside page one */
+    StackWrap { pc: u16, sp: u8 },
+    /* The bus could not serve an access at this address : write to ROM, op code fetch from data */
+    BusFault { pc: u16, address: u16 },
+}
+
+/*
+   What the processor does when a condition is raised.
+   Halt : stop the processor and return the error, only RESET recovers.
+   Nop : ignore the condition, step over the op code and keep running.
+   Trap : return the error to the host with the processor ready to resume.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    Halt,
+    Nop,
+    Trap,
+}
+
+/* Policy for each kind of CpuError */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorPolicies {
+    pub unknown_opcode: ErrorPolicy,
+    pub jam: ErrorPolicy,
+    pub stack_wrap: ErrorPolicy,
+    pub bus_fault: ErrorPolicy,
+}
+
+/*
+   Outcome of a single step : one instruction, or one interrupt sequence.
+   Addresses are 24-bit so the 65C816 can share the type, the 6502 leaves the bank at zero.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepResult {
+    /* Address of the op code, or PC when the interrupt was entered */
+    pub pc: u32,
+    pub opcode: u8,
+    /* "NMI" or "IRQ" for an interrupt sequence, "???" for an unknown op code */
+    pub mnemonic: &'static str,
+    /* Operand bytes after the op code, low order byte first */
+    pub operand: u32,
+    /* Instruction length including the op code */
+    pub length: u8,
+    /* Effective address of the operand, None for implied and accumulator modes */
+    pub effective_address: Option<u32>,
+    pub cycles: u64,
+    pub branch_taken: bool,
+    pub interrupt: bool,
+    /* Processor state after the step : Wait, Stop or Jam when it is halted */
+    pub state: State,
+}
+
+/* Why a bounded run returned. The processor can be resumed by running again. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /* Cycle budget used up, with the cycles actually spent : the last instruction may overshoot */
+    CyclesElapsed(u64),
+    /* The caller's condition became true */
+    Condition,
+    /* Processor sleeping or halted : Wait, Stop, Jam or Exit */
+    Halted(State),
+}
+
+/* One bus access issued in cycle-stepped mode */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusCycle {
+    Read { address: u16, data: u8 },
+    Write { address: u16, data: u8 },
+}
+
+/* Registers an instruction starts from, restored before every replay of a cycle-stepped instruction */
+#[derive(Clone, Copy)]
+pub struct Registers {
+    pub pc: u16,
+    pub address: u16,
+    pub address_register: u16,
+    pub data: u8,
+    pub x_register: u8,
+    pub y_register: u8,
+    pub accumulator: u8,
+    pub instruction_reg: Option<&'static Instruction>,
+    pub status: u8,
+    pub cycles: u64,
+    pub state: State,
+    pub sp: u8,
+    pub irq_line: bool,
+    pub nmi_pending: bool,
+    pub instruction_pc: u16,
+    pub fault: Option<CpuError>,
 }
 
-pub struct CPU {
+/*
+   Cycle-stepped execution.
+   Each tick replays the current instruction from its starting registers : accesses issued by
+   earlier ticks are served from the log, the next one goes to the bus and the later ones are
+   skipped. The instruction is complete once a replay needs no access beyond the log.
+*/
+pub struct TickState {
+    pub accesses: Vec<BusCycle>,
+    /* Index of the next access of the running replay */
+    pub position: usize,
+    /* The access of this tick has been issued */
+    pub issued: bool,
+    /* A replay is running, CPU::read and CPU::write go through the log */
+    pub active: bool,
+    pub start: Option<Registers>,
+}
+
+/*
+   The bus is any IBus implementation : the application supplies its own memory map
+   and can share it with other chips. CPU::new uses Bus::flat(), 64K of RAM.
+*/
+pub struct CPU<B: IBus = Bus> {
     /*
        Program Counter (PC) - holds the address for
        the next machine language instruction to be executed.
@@ -81,9 +240,38 @@ pub struct CPU {
        if the result of an operation is 0, and is unset/erased (0) otherwise)
     */
     pub status: ProcessorStatus,
-    pub cycles: u16,
+    /*
+       Total machine cycles executed since power-on. Each instruction adds its
+       base cost plus the page-cross and branch-taken penalties.
+    */
+    pub cycles: u64,
     pub state: State,
-    pub bus: Bus,
+    pub bus: B,
     /* Stack Pointer */
     pub sp: u8,
+    /*
+       Interrupt Request (IRQ) - level triggered input, serviced between
+       instructions for as long as it is held and the I flag is clear.
+    */
+    pub irq_line: bool,
+    /*
+       Non-Maskable Interrupt (NMI) - edge triggered input. A low to high
+       transition latches a pending NMI which is serviced once.
+    */
+    pub nmi_line: bool,
+    pub nmi_pending: bool,
+    /*
+       "Magic" constant ORed into the accumulator by the unstable XAA and LXA op codes.
+       Real chips use values such as $00, $EE, $EF or $FF.
+    */
+    pub magic_constant: u8,
+    /* Processor family, selects the op code table and the variant specific behaviour */
+    pub variant: Variant,
+    /* Address of the op code being executed */
+    pub instruction_pc: u16,
+    /* Condition raised by the current instruction, handled by the run loop */
+    pub fault: Option<CpuError>,
+    pub policies: ErrorPolicies,
+    /* Bus log of the instruction being executed by tick */
+    pub tick: RefCell<TickState>,
 }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * ADC - Add Memory to Accumulator with Carry
        Operation: A + M + C → A, C
@@ -13,6 +15,14 @@ impl CPU {
 
        In decimal mode, the N, V and Z flags are not consistent with the decimal result.
 
+       Note on the 65C02:
+
+       In decimal mode, the N and Z flags are valid and the instruction takes one more cycle.
+
+       Note on the Ricoh 2A03:
+
+       The decimal flag can be set but is ignored, the addition is always binary.
+
        Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Immediate	                    ADC #$nn	          $69	    2	        2
        Absolute	                    ADC $nnnn	          $6D	    3	        4
@@ -22,6 +32,7 @@ impl CPU {
        X-Indexed Zero Page	            ADC $nn,X	          $75	    2	        4
        X-Indexed Zero Page Indirect	ADC ($nn,X)	          $61	    2	        6
        Zero Page Indirect Y-Indexed	ADC ($nn),Y	          $71	    2	        5+p
+       Zero Page Indirect	            ADC ($nn)	          $72	    2	        5    (65C02 only)
        p: =1 if page is crossed.
 
        Processor Status register changes
@@ -31,292 +42,136 @@ impl CPU {
        Zero flag	Set if the result is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the result.
     */
-    pub fn adc(&mut self, code: &u8) {
-        match *code {
-            /* Immediate */
-            0x69 => {
-                self.adc_immediate();
-                self.adc_run();
-            }
-            /* Absolute */
-            0x6D => {
-                self.adc_absolute();
-                self.adc_run();
-            }
-            /* X-Indexed Absolute */
-            0x7D => {
-                let page_cross = self.adc_absolute_x();
-                self.adc_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            /* Y-Indexed Absolute */
-            0x79 => {
-                let page_cross = self.adc_absolute_y();
-                self.adc_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            /* Zero Page */
-            0x65 => {
-                self.adc_zero_page();
-                self.adc_run();
-            }
-            /* X-Indexed Zero Page */
-            0x75 => {
-                self.adc_zero_page_x();
-                self.adc_run();
-            }
-            /* X-Indexed Zero Page Indirect */
-            0x61 => {
-                self.adc_indirect_x();
-                self.adc_run();
-            }
-            /* Zero Page Indirect Y-Indexed */
-            0x71 => {
-                let page_cross = self.adc_indirect_y();
-                self.adc_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            _ => {
-                self.state = State::Fetch;
-            }
-        }
-    }
-
-    fn adc_immediate(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2 : Next Instruction
-        self.pc += 1;
-    }
-
-    fn adc_zero_page(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Effective Address
-        self.data = self.read(&self.address);
-        let adl = self.data;
-        self.address_register = 0x0000 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
-    }
-
-    fn adc_absolute(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : ADL
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : ADH
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
+    pub fn adc(&mut self, mode: &AddressingMode) {
+        // Fetch Data : one more cycle if the index crosses a page
+        self.fetch_operand(mode);
+        self.adc_run();
     }
 
-    fn adc_absolute_x(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : BAH
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.x_register.clone() as u16 & 0x00FF;
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-
-        // Calculate Page Cross
-        return self.page_cross(current_addr, current_addr + new_addr);
-    }
-
-    fn adc_absolute_y(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : BAH
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.y_register.clone() as u16 & 0x00FF;
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-
-        // Calculate Page Cross
-        return self.page_cross(current_addr, current_addr + new_addr);
+    pub(crate) fn adc_run(&mut self) {
+        if self.decimal_enabled() {
+            self.adc_decimal();
+        } else {
+            self.adc_binary();
+        }
     }
 
-    fn adc_zero_page_x(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Base Address
-        // BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-        self.address_register = 0x0000 + bal as u16 & 0x00FF + self.x_register as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
-    }
+    fn adc_binary(&mut self) {
+        let sum = self.accumulator as u16 + self.data as u16 + self.status.get_carry() as u16;
+        let temp = (sum & 0x00FF) as u8;
 
-    fn adc_indirect_x(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Base Address
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
+        // Set if the result includes a carry bit, otherwise cleared.
+        if sum > 0x00FF {
+            self.status.set_carry();
+        } else {
+            self.status.unset_carry();
+        }
 
-        // Fetch low order byte of Effective Address
-        self.address_register = 0x0000 + bal as u16 & 0x00FF + self.x_register as u16 & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-        let adl = self.data;
+        // Set if the result is zero, otherwise cleared.
+        if temp == 0x00 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
 
-        // Fetch hi order byte of Effective Address
-        self.address += 1;
-        self.data = self.read(&self.address);
-        let adh = self.data;
+        // Updated to the value of bit #7 of the result.
+        if temp & 0x80 > 0 {
+            self.status.set_negative();
+        } else {
+            self.status.unset_negative();
+        }
 
-        // Fetch Data
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | (adl as u16) & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
+        // Set if bit #7 of the result changed in a way that indicates overflow when adding signed byte values, otherwise cleared.
+        // http://www.righto.com/2012/12/the-6502-overflow-flag-explained.html
+        if (self.data ^ temp) & (self.accumulator ^ temp) & 0x80 != 0 {
+            self.status.set_overflow();
+        } else {
+            self.status.unset_overflow();
+        }
 
-        // PC + 2
-        self.pc += 1;
+        self.accumulator = temp;
     }
 
-    fn adc_indirect_y(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Indirect Address
-        // Store : IAL
-        self.data = self.read(&self.address);
-        let ial = self.data;
-
-        // Fetch low order byte of Base Address
-        // Store : BAL
-        self.address_register = 0x0000 + ial as u16 & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // Fetch hi order byte of Base Address
-        // Store : BAH
-        self.address += 1;
-        self.data = self.read(&self.address);
-        let bah = self.data;
+    // NMOS decimal addition : http://www.6502.org/tutorials/decimal_mode.html#A
+    // The accumulator and carry hold the BCD result, Z comes from the binary sum and
+    // N / V come from the intermediate result before the high nibble is adjusted.
+    fn adc_decimal(&mut self) {
+        let a = self.accumulator as u16;
+        let b = self.data as u16;
+        let c = self.status.get_carry() as u16;
+
+        // Add low nibbles and adjust to a decimal digit
+        let mut al = (a & 0x0F) + (b & 0x0F) + c;
+        if al >= 0x0A {
+            al = ((al + 0x06) & 0x0F) + 0x10;
+        }
 
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.y_register.clone() as u16 & 0x00FF;
+        // Add high nibbles
+        let mut sum = (a & 0xF0) + (b & 0xF0) + al;
 
-        // Fetch Data
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
+        // Zero flag : binary result
+        if (a + b + c) & 0x00FF == 0x0000 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
 
-        // PC + 2
-        self.pc += 1;
+        // Negative flag : bit #7 of the intermediate result
+        if sum & 0x0080 > 0 {
+            self.status.set_negative();
+        } else {
+            self.status.unset_negative();
+        }
 
-        return self.page_cross(current_addr, current_addr + new_addr);
-    }
+        // Overflow flag : intermediate result outside -128..127 as signed addition
+        let signed = (a & 0xF0) as u8 as i8 as i16 + (b & 0xF0) as u8 as i8 as i16 + al as i16;
+        if !(-128..=127).contains(&signed) {
+            self.status.set_overflow();
+        } else {
+            self.status.unset_overflow();
+        }
 
-    fn adc_run(&mut self) {
-        let temp: u8 = self.accumulator + self.data + self.status.get_carry();
+        // Adjust high nibble to a decimal digit
+        if sum >= 0x00A0 {
+            sum += 0x0060;
+        }
 
-        // Set if the result includes a carry bit, otherwise cleared.
-        if temp as u16 > 255 {
+        // Carry flag : decimal sum exceeds 99
+        if sum >= 0x0100 {
             self.status.set_carry();
         } else {
             self.status.unset_carry();
         }
 
+        self.accumulator = (sum & 0x00FF) as u8;
+
+        // 65C02 : N and Z are valid for the decimal result, at the cost of one more cycle
+        if self.is_cmos() {
+            self.decimal_flags();
+        }
+    }
+
+    /*
+       65C02 decimal mode : N and Z follow the corrected accumulator and
+       the decimal adjust costs one extra cycle.
+    */
+    pub(crate) fn decimal_flags(&mut self) {
         // Set if the result is zero, otherwise cleared.
-        if temp & 0xFF == 0x00 {
+        if self.accumulator == 0x00 {
             self.status.set_zero();
         } else {
             self.status.unset_zero();
         }
 
         // Updated to the value of bit #7 of the result.
-        if temp & 0x80 > 0 {
+        if self.accumulator & 0x80 > 0 {
             self.status.set_negative();
         } else {
             self.status.unset_negative();
         }
 
-        // Set if bit #7 of the result changed in a way that indicates overflow when adding signed byte values, otherwise cleared.
-        // http://www.righto.com/2012/12/the-6502-overflow-flag-explained.html
-        if (self.data ^ temp) & (self.accumulator ^ temp) & 0x80 != 0 {
-            self.status.set_overflow();
-        } else {
-            self.status.unset_overflow();
-        }
-
-        self.accumulator = temp & 0x00FF;
+        // The extra cycle reads the operand again
+        self.cycles += 1;
+        self.dummy_read(self.address);
     }
 }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * ASL - Arithmetic Shift Left
        Operation: C ← /M7...M0/ ← 0
@@ -23,163 +25,13 @@ impl CPU {
        Carry flag	The old value of bit #7 is stored here.
     */
 
-    pub fn asl(&mut self, code: &u8) {
-        match *code {
-            /* Accumulator */
-            0x0A => {
-                self.asl_accumulator();
-                self.asl_accumulator_run();
-            }
-            /* Absolute */
-            0x0E => {
-                self.asl_absolute();
-                self.asl_run();
-            }
-            /* X-Indexed Absolute */
-            0x1E => {
-                let page_cross = self.asl_absolute_x();
-                self.asl_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            /* Zero Page */
-            0x06 => {
-                self.asl_zero_page();
-                self.asl_run();
-            }
-            /* X-Indexed Zero Page */
-            0x16 => {
-                self.asl_zero_page_x();
-                self.asl_run();
-            }
-            _ => {
-                self.state = State::Fetch;
-            }
-        }
-    }
-
-    fn asl_accumulator(&mut self) {
-        self.data = self.accumulator.clone();
-        self.pc += 1;
-    }
-
-    fn asl_accumulator_run(&mut self) {
-        let temp = (self.data as u16) << 1;
-        // Set if the shifted byte is zero, otherwise cleared.
-        if temp & 0x00FF == 0 {
-            self.status.set_zero();
-        } else {
-            self.status.unset_zero();
-        }
-
-        // Set to the value of bit #7 in the shifted byte.
-        if temp & 0x80 > 0 {
-            self.status.set_negative();
-        } else {
-            self.status.unset_negative();
-        }
-
-        // The old value of bit #7 is stored here.
-        if temp & 0xFF00 > 0 {
-            self.status.set_carry();
-        } else {
-            self.status.unset_carry();
-        }
-
-        self.accumulator = (temp & 0x00FF) as u8;
-    }
-
-    fn asl_zero_page(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Effective Address
-        self.data = self.read(&self.address);
-        let adl = self.data;
-        self.address_register = 0x0000 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
-    }
-
-    fn asl_absolute(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : ADL
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : ADH
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-    }
-
-    fn asl_absolute_x(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : BAH
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.x_register.clone() as u16 & 0x00FF;
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-
-        // Calculate Page Cross
-        return self.page_cross(current_addr, current_addr + new_addr);
-    }
-
-    fn asl_zero_page_x(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Base Address
-        // BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-        self.address_register = 0x0000 + bal as u16 & 0x00FF + self.x_register as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
+    pub fn asl(&mut self, mode: &AddressingMode) {
+        // Fetch Data from memory or accumulator
+        self.fetch_modify_operand(mode);
+        self.asl_run(mode);
     }
 
-    fn asl_run(&mut self) {
+    pub(crate) fn asl_run(&mut self, mode: &AddressingMode) {
         let temp = (self.data as u16) << 1;
         // Set if the shifted byte is zero, otherwise cleared.
         if temp & 0x00FF == 0 {
@@ -202,7 +54,6 @@ impl CPU {
             self.status.unset_carry();
         }
 
-        let addr = self.address.clone();
-        self.write(&addr, (temp & 0x00FF) as u8);
+        self.store_modified(mode, (temp & 0x00FF) as u8);
     }
 }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * BIT - Test Bits in Memory with Accumulator
        Operation: A ∧ M, M7 → N, M6 → V
@@ -13,72 +15,26 @@ impl CPU {
        Absolute	        BIT $nnnn	         $2C	    3	        4
        Zero Page	        BIT $nn	             $24	    2	        3
 
+       65C02 only
+       Immediate	        BIT #$nn	         $89	    2	        2
+       X-Indexed Absolute	BIT $nnnn,X	         $3C	    3	        4+p
+       X-Indexed Zero Page	BIT $nn,X	         $34	    2	        4
+       p: =1 if page is crossed.
+       BIT immediate has no memory byte to test, so it only updates the Z flag.
+
        Processor Status register changes
        Flag	Effect
        Zero flag	Set if the result of the AND operation is zero (none of the bits tested were set in both bytes), otherwise cleared.
        Overflow flag	Updated to equal bit #6 of the specified memory byte.
        Negative flag	Updated to equal bit #7 of the specified memory byte.
     */
-    pub fn bit(&mut self, code: &u8) {
-        match *code {
-            /* Absolute */
-            0x2C => {
-                self.bit_absolute();
-                self.bit_run();
-            }
-            /* Zero Page */
-            0x24 => {
-                self.bit_zero_page();
-                self.bit_run();
-            }
-            _ => {
-                self.state = State::Fetch;
-            }
-        }
-    }
-
-    fn bit_zero_page(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Effective Address
-        self.data = self.read(&self.address);
-        let adl = self.data;
-        self.address_register = 0x0000 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
+    pub fn bit(&mut self, mode: &AddressingMode) {
+        // Fetch Data : one more cycle if the index crosses a page
+        self.fetch_operand(mode);
+        self.bit_run(mode);
     }
 
-    fn bit_absolute(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : ADL
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : ADH
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-    }
-
-    fn bit_run(&mut self) {
+    fn bit_run(&mut self, mode: &AddressingMode) {
         let temp = self.data & self.accumulator;
 
         // Set if the result of the AND operation is zero (none of the bits tested were set in both bytes), otherwise cleared.
@@ -88,15 +44,20 @@ impl CPU {
             self.status.unset_zero();
         }
 
+        // BIT immediate (65C02) only updates the Z flag
+        if *mode == AddressingMode::Immediate {
+            return;
+        }
+
         // Updated to equal bit #6 of the specified memory byte.
-        if temp & 0b0100_0000 > 0 {
+        if self.data & 0b0100_0000 > 0 {
             self.status.set_overflow();
         } else {
             self.status.unset_overflow();
         }
 
         // Updated to equal bit #7 of the specified memory byte.
-        if temp & 0x80 > 0 {
+        if self.data & 0x80 > 0 {
             self.status.set_negative();
         } else {
             self.status.unset_negative();
@@ -10,3 +10,5 @@ pub mod ora;
 pub mod rol;
 pub mod ror;
 pub mod sbc;
+pub mod trb;
+pub mod tsb;
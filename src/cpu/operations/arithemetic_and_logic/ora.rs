@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * ORA - "OR" Memory with Accumulator
        Operation: A ∨ M → A
@@ -18,6 +20,7 @@ impl CPU {
        X-Indexed Zero Page	            ORA $nn,X	          $15	    2	        4
        X-Indexed Zero Page Indirect	ORA ($nn,X)	          $01	    2	        6
        Zero Page Indirect Y-Indexed	ORA ($nn),Y	          $11	    2	        5+p
+       Zero Page Indirect	            ORA ($nn)	          $12	    2	        5    (65C02 only)
        p: =1 if page is crossed.
 
        Processor Status register changes
@@ -25,261 +28,13 @@ impl CPU {
        Zero flag	Set if the result is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the result.
     */
-    pub fn ora(&mut self, code: &u8) {
-        match *code {
-            /* Immediate */
-            0x09 => {
-                self.ora_immediate();
-                self.ora_run();
-            }
-            /* Absolute */
-            0x0D => {
-                self.ora_absolute();
-                self.ora_run();
-            }
-            /* X-Indexed Absolute */
-            0x1D => {
-                let page_cross = self.ora_absolute_x();
-                self.ora_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            /* Y-Indexed Absolute */
-            0x19 => {
-                let page_cross = self.ora_absolute_y();
-                self.ora_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            /* Zero Page */
-            0x05 => {
-                self.ora_zero_page();
-                self.ora_run();
-            }
-            /* X-Indexed Zero Page */
-            0x15 => {
-                self.ora_zero_page_x();
-                self.ora_run();
-            }
-            /* X-Indexed Zero Page Indirect */
-            0x01 => {
-                self.ora_indirect_x();
-                self.ora_run();
-            }
-            /* Zero Page Indirect Y-Indexed */
-            0x11 => {
-                let page_cross = self.ora_indirect_y();
-                self.ora_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            _ => {
-                self.state = State::Fetch;
-            }
-        }
-    }
-
-    fn ora_immediate(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2 : Next Instruction
-        self.pc += 1;
-    }
-
-    fn ora_absolute(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : ADL
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : ADH
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-    }
-
-    fn ora_absolute_x(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : BAH
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.x_register.clone() as u16 & 0x00FF;
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-
-        // Calculate Page Cross
-        return self.page_cross(current_addr, current_addr + new_addr);
-    }
-
-    fn ora_absolute_y(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : BAH
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.y_register.clone() as u16 & 0x00FF;
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-
-        // Calculate Page Cross
-        return self.page_cross(current_addr, current_addr + new_addr);
-    }
-
-    fn ora_zero_page(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Effective Address
-        self.data = self.read(&self.address);
-        let adl = self.data;
-        self.address_register = 0x0000 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
-    }
-
-    fn ora_zero_page_x(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Base Address
-        // BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-        self.address_register = 0x0000 + bal as u16 & 0x00FF + self.x_register as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
-    }
-
-    fn ora_indirect_x(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Base Address
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // Fetch low order byte of Effective Address
-        self.address_register = 0x0000 + bal as u16 & 0x00FF + self.x_register as u16 & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // Fetch hi order byte of Effective Address
-        self.address += 1;
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        // Fetch Data
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | (adl as u16) & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-
-        // PC + 2
-        self.pc += 1;
-    }
-
-    fn ora_indirect_y(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Indirect Address
-        // Store : IAL
-        self.data = self.read(&self.address);
-        let ial = self.data;
-
-        // Fetch low order byte of Base Address
-        // Store : BAL
-        self.address_register = 0x0000 + ial as u16 & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // Fetch hi order byte of Base Address
-        // Store : BAH
-        self.address += 1;
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.y_register.clone() as u16 & 0x00FF;
-
-        // Fetch Data
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-
-        // PC + 2
-        self.pc += 1;
-
-        return self.page_cross(current_addr, current_addr + new_addr);
+    pub fn ora(&mut self, mode: &AddressingMode) {
+        // Fetch Data : one more cycle if the index crosses a page
+        self.fetch_operand(mode);
+        self.ora_run();
     }
 
-    fn ora_run(&mut self) {
+    pub(crate) fn ora_run(&mut self) {
         self.accumulator |= self.data;
 
         // Set if the result is zero, otherwise cleared.
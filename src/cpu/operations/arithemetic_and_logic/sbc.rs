@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * SBC - Subtract Memory from Accumulator with Borrow
        Operation: A - M - ~C → A
@@ -13,6 +15,14 @@ impl CPU {
 
        In decimal mode, the N, V and Z flags are not consistent with the decimal result.
 
+       Note on the 65C02:
+
+       In decimal mode, the N and Z flags are valid and the instruction takes one more cycle.
+
+       Note on the Ricoh 2A03:
+
+       The decimal flag can be set but is ignored, the subtraction is always binary.
+
 
        Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Immediate	                    SBC #$nn	         $E9	    2	        2
@@ -23,6 +33,7 @@ impl CPU {
        X-Indexed Zero Page	            SBC $nn,X	         $F5	    2	        4
        X-Indexed Zero Page Indirect	SBC ($nn,X)	         $E1	    2	        6
        Zero Page Indirect Y-Indexed	SBC ($nn),Y	         $F1	    2	        5+p
+       Zero Page Indirect	            SBC ($nn)	          $F2	    2	        5    (65C02 only)
        p: =1 if page is crossed.
 
        Processor Status register changes
@@ -32,264 +43,31 @@ impl CPU {
        Zero flag	Set if the result is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the result.
     */
-    pub fn sbc(&mut self, code: &u8) {
-        match *code {
-            /* Immediate */
-            0xE9 => {
-                self.sbc_immediate();
-                self.sbc_run();
-            }
-            /* Absolute */
-            0xED => {
-                self.sbc_absolute();
-                self.sbc_run();
-            }
-            /* X-Indexed Absolute */
-            0xFD => {
-                let page_cross = self.sbc_absolute_x();
-                self.sbc_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            /* Y-Indexed Absolute */
-            0xF9 => {
-                let page_cross = self.sbc_absolute_y();
-                self.sbc_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            /* Zero Page */
-            0xE5 => {
-                self.sbc_zero_page();
-                self.sbc_run();
-            }
-            /* X-Indexed Zero Page	 */
-            0xF5 => {
-                self.sbc_zero_page_x();
-                self.sbc_run();
-            }
-            /* X-Indexed Zero Page Indirect */
-            0xE1 => {
-                self.sbc_indirect_x();
-                self.sbc_run();
-            }
-            /* Zero Page Indirect Y-Indexed */
-            0xF1 => {
-                let page_cross = self.sbc_indirect_y();
-                self.sbc_run();
+    pub fn sbc(&mut self, mode: &AddressingMode) {
+        // Fetch Data : one more cycle if the index crosses a page
+        self.fetch_operand(mode);
+        self.sbc_run();
+    }
 
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            _ => {
-                self.state = State::Fetch;
+    pub(crate) fn sbc_run(&mut self) {
+        let a = self.accumulator;
+        let carry = self.status.get_carry();
+        self.sbc_binary();
+
+        // Decimal mode : NMOS keeps the binary flags, the 65C02 fixes N and Z
+        if self.decimal_enabled() {
+            if self.is_cmos() {
+                self.sbc_decimal_65c02(a, carry);
+                self.decimal_flags();
+            } else {
+                self.sbc_decimal(a, carry);
             }
         }
     }
 
-    fn sbc_immediate(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2 : Next Instruction
-        self.pc += 1;
-    }
-
-    fn sbc_absolute(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : ADL
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : ADH
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-    }
-
-    fn sbc_absolute_x(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : BAH
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.x_register.clone() as u16 & 0x00FF;
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-
-        // Calculate Page Cross
-        return self.page_cross(current_addr, current_addr + new_addr);
-    }
-
-    fn sbc_absolute_y(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch high order Effective Address byte
-        // Store : BAH
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.y_register.clone() as u16 & 0x00FF;
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-
-        // Calculate Page Cross
-        return self.page_cross(current_addr, current_addr + new_addr);
-    }
-
-    fn sbc_zero_page(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Effective Address
-        self.data = self.read(&self.address);
-        let adl = self.data;
-        self.address_register = 0x0000 | adl as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
-    }
-
-    fn sbc_zero_page_x(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Base Address
-        // BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-        self.address_register = 0x0000 + bal as u16 & 0x00FF + self.x_register as u16 & 0x00FF;
-        self.address = self.address_register;
-        // Fetch Data
-        self.data = self.read(&self.address);
-        // PC + 2
-        self.pc += 1;
-    }
-
-    fn sbc_indirect_x(&mut self) {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Base Address
-        // Store : BAL
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // Fetch low order byte of Effective Address
-        self.address_register = 0x0000 + bal as u16 & 0x00FF + self.x_register as u16 & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // Fetch hi order byte of Effective Address
-        self.address += 1;
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        // Fetch Data
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | (adl as u16) & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-
-        // PC + 2
-        self.pc += 1;
-    }
-
-    fn sbc_indirect_y(&mut self) -> bool {
-        // PC + 1
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Page Zero Indirect Address
-        // Store : IAL
-        self.data = self.read(&self.address);
-        let ial = self.data;
-
-        // Fetch low order byte of Base Address
-        // Store : BAL
-        self.address_register = 0x0000 + ial as u16 & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // Fetch hi order byte of Base Address
-        // Store : BAH
-        self.address += 1;
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | bal as u16 & 0x00FF;
-        let new_addr = self.y_register.clone() as u16 & 0x00FF;
-
-        // Fetch Data
-        self.address_register = current_addr + new_addr;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-
-        // PC + 2
-        self.pc += 1;
-
-        return self.page_cross(current_addr, current_addr + new_addr);
-    }
-
-    fn sbc_run(&mut self) {
-        let value = (self.data ^ 0x00FF) as u16;
-        let temp =
-            (self.accumulator as u16) & 0x00FF + value + (self.status.get_carry() as u16) & 0x00FF;
+    fn sbc_binary(&mut self) {
+        let value = (self.data ^ 0xFF) as u16;
+        let temp = self.accumulator as u16 + value + self.status.get_carry() as u16;
 
         // Set if borrowing did not occur during the calculation, or cleared if borrowing did occur.
         if temp & 0xFF00 > 0 {
@@ -321,4 +99,49 @@ impl CPU {
 
         self.accumulator = (temp & 0x00FF) as u8;
     }
+
+    // NMOS decimal subtraction : http://www.6502.org/tutorials/decimal_mode.html#A
+    // Borrow is the inverted carry that was used by the binary subtraction.
+    fn sbc_decimal(&mut self, a: u8, carry: u8) {
+        let a = a as i16;
+        let b = self.data as i16;
+        let borrow = 1 - carry as i16;
+
+        // Subtract low nibbles and adjust to a decimal digit
+        let mut al = (a & 0x0F) - (b & 0x0F) - borrow;
+        if al < 0 {
+            al = ((al - 0x06) & 0x0F) - 0x10;
+        }
+
+        // Subtract high nibbles and adjust to a decimal digit
+        let mut result = (a & 0xF0) - (b & 0xF0) + al;
+        if result < 0 {
+            result -= 0x60;
+        }
+
+        self.accumulator = (result & 0x00FF) as u8;
+    }
+
+    // 65C02 decimal subtraction : http://www.6502.org/tutorials/decimal_mode.html#A
+    // The whole byte is subtracted first, then each digit is adjusted.
+    fn sbc_decimal_65c02(&mut self, a: u8, carry: u8) {
+        let a = a as i16;
+        let b = self.data as i16;
+        let borrow = 1 - carry as i16;
+
+        let al = (a & 0x0F) - (b & 0x0F) - borrow;
+        let mut result = a - b - borrow;
+
+        // Adjust the high digit
+        if result < 0 {
+            result -= 0x60;
+        }
+
+        // Adjust the low digit
+        if al < 0 {
+            result -= 0x06;
+        }
+
+        self.accumulator = (result & 0x00FF) as u8;
+    }
 }
@@ -0,0 +1,40 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * TRB - Test And Reset Memory Bits With Accumulator (65C02)
+       Operation: A ∧ M → Z, ~A ∧ M → M
+
+       This instruction tests the bits of memory against the accumulator like BIT, then clears in memory every bit that is set in the accumulator.
+
+       Only the Z flag is affected, it is set from the AND of the accumulator and the original memory value. The accumulator is not changed.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	        TRB $nnnn	         $1C	    3	        6
+       Zero Page	        TRB $nn	             $14	    2	        5
+
+       Processor Status register changes
+       Flag	Effect
+       Zero flag	Set if the accumulator AND the memory value is zero, otherwise cleared.
+    */
+    pub fn trb(&mut self, mode: &AddressingMode) {
+        // Fetch Data from memory
+        self.fetch_modify_operand(mode);
+        self.trb_run(mode);
+    }
+
+    fn trb_run(&mut self, mode: &AddressingMode) {
+        // Set if the accumulator AND the memory value is zero, otherwise cleared.
+        if self.data & self.accumulator == 0x00 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
+
+        // Write modified Data back to memory
+        let temp = self.data & !self.accumulator;
+        self.store_modified(mode, temp);
+    }
+}
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     *  CPX - Compare Index Register X To Memory
        Operation: X - M
@@ -28,98 +30,14 @@ impl CPU {
        Zero flag	Set if the value in the X register is equal to the operand byte, otherwise cleared.
        Negative flag Updated to the value of bit #7 of the result.
     */
-    pub fn cpx(&mut self, code: &u8) {
-        match *code {
-            /* Immediate */
-            0xE0 => {
-                self.cpx_immediate();
-                self.cpx_run();
-            }
-            /* Zero Page */
-            0xE4 => {
-                self.cpx_zero_page();
-                self.cpx_run();
-            }
-            /* Absolute */
-            0xEC => {
-                self.cpx_absolute();
-                self.cpx_run();
-            }
-            _ => {
-                self.state = State::Fetch;
-            }
-        }
-    }
-
-    fn cpx_immediate(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-
-        // Load PC to Address 2-Byte
-        self.address = self.pc.clone();
-
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // Next PC for next instruction
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
-    fn cpx_zero_page(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-
-        // Load PC to Address 2-Byte
-        self.address = self.pc.clone();
-
-        // Fetch effective address
-        // And store : ADL
-        self.data = self.read(&self.address);
-        let adl = self.data; // Lo-Byte
-        self.address_register = 0x0000 | (adl as u16) & 0x00FF;
-
-        self.address = self.address_register;
-
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // Next PC for next instruction
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
-    fn cpx_absolute(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-        // Load PC to Address 2-Byte
-        self.address = self.pc.clone();
-        // Fetch low order Effective Address byte
-        // And store : ADL
-        self.data = self.read(&self.address);
-        let adl = self.data; // Lo-Byte
-
-        // PC = PC + 1
-        self.pc += 1;
-        // Load PC to Address 2-Byte
-        self.address = self.pc.clone();
-        // Fetch hight order Effective Address byte
-        // And store : ADH
-        self.data = self.read(&self.address);
-        let adh = self.data; // Hi-Byte
-
-        // Fetch Data
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | (adl as u16) & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-
-        // Next PC for next instruction
-        // PC = PC + 1
-        self.pc += 1;
+    pub fn cpx(&mut self, mode: &AddressingMode) {
+        // Fetch Data : one more cycle if the index crosses a page
+        self.fetch_operand(mode);
+        self.cpx_run();
     }
 
     fn cpx_run(&mut self) {
-        let temp: u8 = self.x_register - self.data;
+        let temp: u8 = self.x_register.wrapping_sub(self.data);
 
         // Set if the value in the X register is greater than or equal to the operand byte, otherwise cleared.
         if self.x_register >= self.data {
@@ -1,6 +1,8 @@
+use crate::cpu::instruction::AddressingMode;
 use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * DEX - Decrement Index Register X By One
       Operation: X - 1 → X
@@ -19,18 +21,13 @@ impl CPU {
       Zero flag	    Set if the result is zero, otherwise cleared.
       Negative flag	Updated to the value of bit #7 of the result.
     */
-    pub fn dex(&mut self) {
-        self.dex_implied();
+    pub fn dex(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.dex_run();
     }
 
-    fn dex_implied(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
     fn dex_run(&mut self) {
-        self.x_register -= 1;
+        self.x_register = self.x_register.wrapping_sub(1);
 
         // Set if the result is zero, otherwise cleared.
         if self.x_register & 0xFF == 0x00 {
@@ -1,6 +1,8 @@
+use crate::cpu::instruction::AddressingMode;
 use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * DEY - Decrement Index Register Y By One
       Operation: Y - 1 → Y
@@ -22,18 +24,13 @@ impl CPU {
       Zero flag	Set if the result is zero, otherwise cleared.
       Negative flag	Updated to the value of bit #7 of the result.
     */
-    pub fn dey(&mut self) {
-        self.dey_implied();
+    pub fn dey(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.dey_run();
     }
 
-    fn dey_implied(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
     fn dey_run(&mut self) {
-        self.y_register -= 1;
+        self.y_register = self.y_register.wrapping_sub(1);
 
         // Set if the result is zero, otherwise cleared.
         if self.y_register & 0xFF == 0x00 {
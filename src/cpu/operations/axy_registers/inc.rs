@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * INC - Increment Memory By One
       Operation: M + 1 → M
@@ -22,132 +24,14 @@ impl CPU {
       Zero flag	    Set if the result is zero, otherwise cleared.
       Negative flag	Updated to the value of bit #7 of the result.
     */
-    pub fn inc(&mut self, code: &u8) {
-        match *code {
-            /* Absolute */
-            0xEE => {
-                self.inc_absolute();
-                self.inc_run();
-            }
-            /* X-Indexed Absolute */
-            0xFE => {
-                let page_cross = self.inc_absolute_x();
-                self.inc_run();
-
-                if page_cross {
-                    // TODO :: Tick
-                }
-            }
-            /* Zero Page */
-            0xE6 => {
-                self.inc_zero_page();
-                self.inc_run();
-            }
-            /* X-Indexed Zero Page */
-            0xF6 => {
-                self.inc_zero_page_x();
-                self.inc_run();
-            }
-            _ => {
-                self.state = State::Fetch;
-            }
-        }
-    }
-
-    fn inc_zero_page(&mut self) {
-        // PC + 1
-        // ADL : Fetch page zero Effective Address
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        self.address_register = 0x0000 | (adl & 0x00FF) as u16;
-        self.address = self.address_register;
-
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // Next Instruction
-        self.pc += 1;
-    }
-
-    fn inc_zero_page_x(&mut self) {
-        // PC + 1
-        // BAL : Fetch page zero Base Address
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        self.address_register = 0x0000 + (bal & 0x00FF) as u16 + (self.x_register & 0x00FF) as u16;
-        self.address = self.address_register;
-
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // Next Instruction
-        self.pc += 1;
-    }
-
-    fn inc_absolute(&mut self) {
-        // PC + 1
-        // ADL : Fetch low order byte of Effective Address
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // PC + 2
-        // ADH : Fetch hi order byte of Effective Address
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | (adl as u16) & 0x00FF;
-        self.address = self.address_register;
-
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-    }
-
-    fn inc_absolute_x(&mut self) -> bool {
-        // PC + 1
-        // BAL : Fetch low order byte of base address
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let bal = self.data;
-
-        // PC + 2
-        // BAH : Fetch hi order byte of base address
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let bah = self.data;
-
-        let current_addr = ((bah as u16) << 8) & 0xFF00 | (bal as u16) & 0x00FF;
-        let x_addr = (self.x_register.clone() & 0x00FF) as u16;
-
-        self.address_register = current_addr + x_addr;
-        self.address = self.address_register;
-
-        // Fetch Data
-        self.data = self.read(&self.address);
-
-        // PC + 3
-        self.pc += 1;
-
-        // Calculate Page cross
-        return self.page_cross(current_addr, current_addr + x_addr);
+    pub fn inc(&mut self, mode: &AddressingMode) {
+        // Fetch Data from memory or accumulator
+        self.fetch_modify_operand(mode);
+        self.inc_run(mode);
     }
 
-    fn inc_run(&mut self) {
-        let temp = self.data + 1;
+    pub(crate) fn inc_run(&mut self, mode: &AddressingMode) {
+        let temp = self.data.wrapping_add(1);
 
         // Set if the result is zero, otherwise cleared.
         if temp & 0xFF == 0x00 {
@@ -164,7 +48,6 @@ impl CPU {
         }
 
         // Write modified Data back to memory
-        let addr = self.address;
-        self.write(&addr, temp & 0x00FF);
+        self.store_modified(mode, temp);
     }
 }
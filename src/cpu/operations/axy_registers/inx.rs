@@ -1,6 +1,8 @@
+use crate::cpu::instruction::AddressingMode;
 use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     *  INX - Increment Index Register X By One
        Operation: X + 1 → X
@@ -22,18 +24,13 @@ impl CPU {
        Zero flag	Set if the result is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the result.
     */
-    pub fn inx(&mut self) {
-        self.inx_implied();
+    pub fn inx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.inx_run();
     }
 
-    fn inx_implied(&mut self) {
-        // PC + 1
-        self.pc += 1;
-    }
-
     fn inx_run(&mut self) {
-        self.x_register += 1;
+        self.x_register = self.x_register.wrapping_add(1);
 
         // Set if the result is zero, otherwise cleared.
         if self.x_register & 0xFF == 0x00 {
@@ -1,6 +1,8 @@
+use crate::cpu::instruction::AddressingMode;
 use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * INY - Increment Index Register Y By One
       Operation: Y + 1 → Y
@@ -19,18 +21,13 @@ impl CPU {
       Zero flag	    Set if the result is zero, otherwise cleared.
       Negative flag	Updated to the value of bit #7 of the result.
     */
-    pub fn iny(&mut self) {
-        self.iny_implied();
+    pub fn iny(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.iny_run();
     }
 
-    fn iny_implied(&mut self) {
-        // PC + 1
-        self.pc += 1;
-    }
-
     fn iny_run(&mut self) {
-        self.y_register += 1;
+        self.y_register = self.y_register.wrapping_add(1);
 
         // Set if the result is zero, otherwise cleared.
         if self.y_register & 0xFF == 0x00 {
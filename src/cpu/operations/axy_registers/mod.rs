@@ -11,6 +11,7 @@ pub mod ldy;
 pub mod sta;
 pub mod stx;
 pub mod sty;
+pub mod stz;
 pub mod tax;
 pub mod tay;
 pub mod tsx;
@@ -0,0 +1,33 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * STZ - Store Zero In Memory (65C02)
+      Operation: 0 → M
+
+      Transfers zero to the addressed memory location.
+
+      No flags or registers in the microprocessor are affected by the store operation.
+
+      Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+      Absolute	            STZ $nnnn	         $9C	    3	        4
+      X-Indexed Absolute	STZ $nnnn,X	         $9E	    3	        5
+      Zero Page	        STZ $nn	             $64	    2	        3
+      X-Indexed Zero Page	STZ $nn,X	         $74	    2	        4
+
+      Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn stz(&mut self, mode: &AddressingMode) {
+        // Resolve Effective Address, stores never take the page cross cycle
+        self.resolve_write_address(mode);
+        self.stz_run();
+    }
+
+    fn stz_run(&mut self) {
+        let addr = self.address;
+        self.write(&addr, 0x00);
+    }
+}
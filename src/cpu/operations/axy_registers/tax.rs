@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * TAX - Transfer Accumulator To Index X
       Operation: A → X
@@ -17,16 +19,11 @@ impl CPU {
        Zero flag	    Set if the copied byte is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the copied byte.
     */
-    pub fn tax(&mut self) {
-        self.tax_implied();
+    pub fn tax(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.tax_run();
     }
 
-    fn tax_implied(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
     fn tax_run(&mut self) {
         self.x_register = self.accumulator.clone();
 
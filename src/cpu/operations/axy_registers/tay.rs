@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * TAY - Transfer Accumula Tor To Index Y
       Operation: A → Y
@@ -17,16 +19,11 @@ impl CPU {
        Zero flag	    Set if the copied byte is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the copied byte.
     */
-    pub fn tay(&mut self) {
-        self.tay_implied();
+    pub fn tay(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.tay_run();
     }
 
-    fn tay_implied(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
     fn tay_run(&mut self) {
         self.y_register = self.accumulator.clone();
 
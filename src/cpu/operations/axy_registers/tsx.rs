@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * TSX - Transfer Stack Pointer To Index X
       Operation: S → X
@@ -17,16 +19,11 @@ impl CPU {
       Zero flag	    Set if the copied byte is zero, otherwise cleared.
       Negative flag	Updated to the value of bit #7 of the copied byte.
     */
-    pub fn tsx(&mut self) {
-        self.tsx_implied();
+    pub fn tsx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.tsx_run();
     }
 
-    fn tsx_implied(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
     fn tsx_run(&mut self) {
         self.x_register = self.sp.clone();
 
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * TXA - Transfer Index X To Accumulator
        Operation: X → A
@@ -17,16 +19,11 @@ impl CPU {
        Zero flag	    Set if the copied byte is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the copied byte.
     */
-    pub fn txa(&mut self) {
-        self.txa_implied();
+    pub fn txa(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.txa_run();
     }
 
-    fn txa_implied(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
     fn txa_run(&mut self) {
         self.accumulator = self.x_register.clone();
 
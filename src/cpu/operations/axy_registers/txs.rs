@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * TXS - Transfer Index X To Stack Pointer
        Operation: X → S
@@ -15,16 +17,11 @@ impl CPU {
        Processor Status register changes
        Does not update any flags.
     */
-    pub fn txs(&mut self) {
-        self.txs_implied();
+    pub fn txs(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.txs_run();
     }
 
-    fn txs_implied(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
     fn txs_run(&mut self) {
         self.sp = self.x_register.clone();
     }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * TYA - Transfer Index Y To Accumulator
        Operation: Y → A
@@ -17,16 +19,11 @@ impl CPU {
        Zero flag	Set if the copied byte is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the copied byte.
     */
-    pub fn tya(&mut self) {
-        self.tya_implied();
+    pub fn tya(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.tya_run();
     }
 
-    fn tya_implied(&mut self) {
-        // PC = PC + 1
-        self.pc += 1;
-    }
-
     fn tya_run(&mut self) {
         self.accumulator = self.y_register.clone();
 
@@ -0,0 +1,41 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * BBR - Branch on Bit Reset (W65C02S)
+       Operation: Branch on Mb = 0
+
+       This instruction tests one bit of a zero page memory location and takes the branch if the bit is clear. The bit number is encoded in the op code, BBR0 tests bit #0 up to BBR7 which tests bit #7. The second operand byte is a branch offset relative to the next instruction.
+
+       BBR does not affect any of the flags or registers other than the program counter.
+
+       Addressing Mode	    Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Zero Page, Relative	BBR0 $nn,$nnnn	     $0F	    3	        5+t+p
+       Zero Page, Relative	BBR1 $nn,$nnnn	     $1F	    3	        5+t+p
+       Zero Page, Relative	BBR2 $nn,$nnnn	     $2F	    3	        5+t+p
+       Zero Page, Relative	BBR3 $nn,$nnnn	     $3F	    3	        5+t+p
+       Zero Page, Relative	BBR4 $nn,$nnnn	     $4F	    3	        5+t+p
+       Zero Page, Relative	BBR5 $nn,$nnnn	     $5F	    3	        5+t+p
+       Zero Page, Relative	BBR6 $nn,$nnnn	     $6F	    3	        5+t+p
+       Zero Page, Relative	BBR7 $nn,$nnnn	     $7F	    3	        5+t+p
+       p: =1 if page is crossed.
+       t: =1 if branch is taken.
+    */
+    pub fn bbr(&mut self, mode: &AddressingMode, bit: u8) {
+        // Fetch zero page Address and Branch Offset
+        let page_cross = self.resolve_address(mode);
+        // Fetch Data to test
+        self.data = self.read(&self.address);
+        self.dummy_read(self.address);
+        self.bbr_run(bit, page_cross);
+    }
+
+    fn bbr_run(&mut self, bit: u8, page_cross: bool) {
+        if self.data & (1 << bit) == 0 {
+            // Offset Added to Program Counter
+            self.branch(page_cross);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * BBS - Branch on Bit Set (W65C02S)
+       Operation: Branch on Mb = 1
+
+       This instruction tests one bit of a zero page memory location and takes the branch if the bit is set. The bit number is encoded in the op code, BBS0 tests bit #0 up to BBS7 which tests bit #7. The second operand byte is a branch offset relative to the next instruction.
+
+       BBS does not affect any of the flags or registers other than the program counter.
+
+       Addressing Mode	    Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Zero Page, Relative	BBS0 $nn,$nnnn	     $8F	    3	        5+t+p
+       Zero Page, Relative	BBS1 $nn,$nnnn	     $9F	    3	        5+t+p
+       Zero Page, Relative	BBS2 $nn,$nnnn	     $AF	    3	        5+t+p
+       Zero Page, Relative	BBS3 $nn,$nnnn	     $BF	    3	        5+t+p
+       Zero Page, Relative	BBS4 $nn,$nnnn	     $CF	    3	        5+t+p
+       Zero Page, Relative	BBS5 $nn,$nnnn	     $DF	    3	        5+t+p
+       Zero Page, Relative	BBS6 $nn,$nnnn	     $EF	    3	        5+t+p
+       Zero Page, Relative	BBS7 $nn,$nnnn	     $FF	    3	        5+t+p
+       p: =1 if page is crossed.
+       t: =1 if branch is taken.
+    */
+    pub fn bbs(&mut self, mode: &AddressingMode, bit: u8) {
+        // Fetch zero page Address and Branch Offset
+        let page_cross = self.resolve_address(mode);
+        // Fetch Data to test
+        self.data = self.read(&self.address);
+        self.dummy_read(self.address);
+        self.bbs_run(bit, page_cross);
+    }
+
+    fn bbs_run(&mut self, bit: u8, page_cross: bool) {
+        if self.data & (1 << bit) != 0 {
+            // Offset Added to Program Counter
+            self.branch(page_cross);
+        }
+    }
+}
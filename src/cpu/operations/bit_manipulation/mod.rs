@@ -0,0 +1,4 @@
+pub mod bbr;
+pub mod bbs;
+pub mod rmb;
+pub mod smb;
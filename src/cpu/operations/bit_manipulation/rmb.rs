@@ -0,0 +1,38 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * RMB - Reset Memory Bit (W65C02S)
+       Operation: 0 → Mb
+
+       This instruction clears one bit of a zero page memory location. The bit number is encoded in the op code, RMB0 clears bit #0 up to RMB7 which clears bit #7.
+
+       It does not affect any register or flag.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Zero Page	        RMB0 $nn	         $07	    2	        5
+       Zero Page	        RMB1 $nn	         $17	    2	        5
+       Zero Page	        RMB2 $nn	         $27	    2	        5
+       Zero Page	        RMB3 $nn	         $37	    2	        5
+       Zero Page	        RMB4 $nn	         $47	    2	        5
+       Zero Page	        RMB5 $nn	         $57	    2	        5
+       Zero Page	        RMB6 $nn	         $67	    2	        5
+       Zero Page	        RMB7 $nn	         $77	    2	        5
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn rmb(&mut self, mode: &AddressingMode, bit: u8) {
+        // Fetch Data from memory
+        self.fetch_modify_operand(mode);
+        self.rmb_run(mode, bit);
+    }
+
+    fn rmb_run(&mut self, mode: &AddressingMode, bit: u8) {
+        // Write modified Data back to memory
+        let temp = self.data & !(1 << bit);
+        self.store_modified(mode, temp);
+    }
+}
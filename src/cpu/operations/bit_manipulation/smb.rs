@@ -0,0 +1,38 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * SMB - Set Memory Bit (W65C02S)
+       Operation: 1 → Mb
+
+       This instruction sets one bit of a zero page memory location. The bit number is encoded in the op code, SMB0 sets bit #0 up to SMB7 which sets bit #7.
+
+       It does not affect any register or flag.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Zero Page	        SMB0 $nn	         $87	    2	        5
+       Zero Page	        SMB1 $nn	         $97	    2	        5
+       Zero Page	        SMB2 $nn	         $A7	    2	        5
+       Zero Page	        SMB3 $nn	         $B7	    2	        5
+       Zero Page	        SMB4 $nn	         $C7	    2	        5
+       Zero Page	        SMB5 $nn	         $D7	    2	        5
+       Zero Page	        SMB6 $nn	         $E7	    2	        5
+       Zero Page	        SMB7 $nn	         $F7	    2	        5
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn smb(&mut self, mode: &AddressingMode, bit: u8) {
+        // Fetch Data from memory
+        self.fetch_modify_operand(mode);
+        self.smb_run(mode, bit);
+    }
+
+    fn smb_run(&mut self, mode: &AddressingMode, bit: u8) {
+        // Write modified Data back to memory
+        let temp = self.data | (1 << bit);
+        self.store_modified(mode, temp);
+    }
+}
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * BCS - Branch on Carry Set
        Operation: Branch on C = 1
@@ -14,33 +16,16 @@ impl CPU {
        p: =1 if page is crossed.
        t: =1 if branch is taken.
     */
-    pub fn bcs(&mut self) {
-        self.bcs_relative();
-        self.bcs_run();
+    pub fn bcs(&mut self, mode: &AddressingMode) {
+        // Fetch Branch Offset and compute the target address
+        let page_cross = self.resolve_address(mode);
+        self.bcs_run(page_cross);
     }
 
-    fn bcs_relative(&mut self) {
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Branch Offset
-        self.data = self.read(&self.address);
-    }
-
-    fn bcs_run(&mut self) {
+    fn bcs_run(&mut self, page_cross: bool) {
         if self.status.get_carry() == 1 {
-            let offset = self.data;
-
-            // TODO :: Tick
-
-            self.address_register = self.pc + offset as u16 & 0x00FF + 0x0001;
-            self.address = self.address_register;
-
-            if self.address & 0xFF00 != self.pc & 0xFF00 {
-                // TODO :: Tick
-            }
-
             // Offset Added to Program Counter
-            self.pc = self.address;
+            self.branch(page_cross);
         }
     }
 }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * BNE - Branch on Result Not Zero
        Operation: Branch on Z = 0
@@ -14,31 +16,16 @@ impl CPU {
        p: =1 if page is crossed.
        t: =1 if branch is taken.
     */
-    pub fn bne(&mut self) {
-        self.bne_relative();
-        self.bne_run();
+    pub fn bne(&mut self, mode: &AddressingMode) {
+        // Fetch Branch Offset and compute the target address
+        let page_cross = self.resolve_address(mode);
+        self.bne_run(page_cross);
     }
 
-    fn bne_relative(&mut self) {
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Branch Offset
-        self.data = self.read(&self.address);
-    }
-
-    fn bne_run(&mut self) {
+    fn bne_run(&mut self, page_cross: bool) {
         if self.status.get_zero() == 0 {
-            let offset = self.data;
-            // TODO :: Tick
-            self.address_register = self.pc + offset as u16 & 0x00FF + 0x0001;
-            self.address = self.address_register;
-
-            if self.address & 0xFF00 != self.pc & 0xFF00 {
-                // TODO :: Tick
-            }
-
             // Offset Added to Program Counter
-            self.pc = self.address;
+            self.branch(page_cross);
         }
     }
 }
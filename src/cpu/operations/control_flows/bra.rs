@@ -0,0 +1,28 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * BRA - Branch Always (65C02)
+       Operation: Branch always
+
+       This instruction always takes the branch. The offset is relative to the next instruction, exactly as the conditional branches.
+
+       BRA does not affect any of the flags or registers other than the program counter.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Relative	                BRA $nnnn	$80	        2	        3+p
+       p: =1 if page is crossed.
+    */
+    pub fn bra(&mut self, mode: &AddressingMode) {
+        // Fetch Branch Offset and compute the target address
+        let page_cross = self.resolve_address(mode);
+        self.bra_run(page_cross);
+    }
+
+    fn bra_run(&mut self, page_cross: bool) {
+        // Offset Added to Program Counter
+        self.branch(page_cross);
+    }
+}
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * BVS - Branch on Overflow Set
        Operation: Branch on V = 1
@@ -17,31 +19,16 @@ impl CPU {
        Processor Status register changes
        Does not update any flags.
     */
-    pub fn bvs(&mut self) {
-        self.bvs_relative();
-        self.bvs_run();
+    pub fn bvs(&mut self, mode: &AddressingMode) {
+        // Fetch Branch Offset and compute the target address
+        let page_cross = self.resolve_address(mode);
+        self.bvs_run(page_cross);
     }
 
-    fn bvs_relative(&mut self) {
-        self.pc += 1;
-        self.address = self.pc.clone();
-        // Fetch Branch Offset
-        self.data = self.read(&self.address);
-    }
-
-    fn bvs_run(&mut self) {
+    fn bvs_run(&mut self, page_cross: bool) {
         if self.status.get_overflow() > 0 {
-            let offset = self.data;
-            // TODO :: Tick
-            self.address_register = self.pc + offset as u16 & 0x00FF + 0x0001;
-            self.address = self.address_register;
-
-            if self.address & 0xFF00 != self.pc & 0xFF00 {
-                // TODO :: Tick
-            }
-
             // Offset Added to Program Counter
-            self.pc = self.address;
+            self.branch(page_cross);
         }
     }
 }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * JMP - JMP Indirect
        Operation: [PC + 1] → PCL, [PC + 2] → PCH
@@ -12,72 +14,16 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Absolute	                JMP $nnnn	 $4C	    3	        3
        Absolute Indirect	        JMP ($nnnn)	 $6C	    3	        5
-    */
-    pub fn jmp(&mut self, code: &u8) {
-        match *code {
-            /* Absolute */
-            0x4C => {
-                self.jmp_absolute();
-                self.jmp_run();
-            }
-            /* Absolute Indirect */
-            0x6C => {
-                self.jmp_jmp_absolute_indirect();
-                self.jmp_run();
-            }
-            _ => {
-                self.state = State::Fetch;
-            }
-        }
-    }
-
-    fn jmp_absolute(&mut self) {
-        // Fetch low order byte of Jump Address
-        // ADL
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let adl = self.data;
-
-        // Fetch hiht order byte of Jump Address
-        // ADH
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | adl as u16 & 0x00FF;
-    }
-
-    fn jmp_jmp_absolute_indirect(&mut self) {
-        // Fetch low order byte of Indirect Address
-        // IAL
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let idl = self.data;
-
-        // Fetch high order byte of Indirect Address
-        // IAH
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let idh = self.data;
 
-        // Fetch low order byte of Jump Address
-        // ADL
-        self.address_register = ((idh as u16) << 8) & 0xFF00 | idl as u16 & 0x00FF;
-        self.address = self.address_register;
-        self.data = self.read(&self.address);
-        let adl = self.data;
+       NMOS indirect jumps do not carry into the pointer high byte : JMP ($10FF) takes PCH from $1000.
 
-        // Fetch high order byte of Jump Address
-        // ADH
-        self.address += 1;
-        self.data = self.read(&self.address);
-        let adh = self.data;
-
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | adl as u16 & 0x00FF;
+       65C02 only (page-wrap fixed)
+       Absolute Indirect	        JMP ($nnnn)	 $6C	    3	        6
+       X-Indexed Absolute Indirect	JMP ($nnnn,X)	 $7C	    3	        6
+    */
+    pub fn jmp(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.jmp_run();
     }
 
     fn jmp_run(&mut self) {
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * JSR - Jump To Subroutine
        Operation: PC + 2↓, [PC + 1] → PCL, [PC + 2] → PCH
@@ -12,44 +14,34 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Absolute	        JSR $nnnn	          $20	    3	        6
     */
-    pub fn jsr(&mut self) {
-        self.jsr_absolute();
-        self.jsr_run();
+    pub fn jsr(&mut self, _mode: &AddressingMode) {
+        // Fetch ADL, PC is left on ADH : the last byte of the instruction
+        self.pc = self.pc.wrapping_add(1);
+        let adl = self.read(&self.pc);
+        self.pc = self.pc.wrapping_add(1);
+
+        self.stack_dummy_read();
+        self.jsr_run(adl);
     }
 
-    fn jsr_absolute(&mut self) {
-        // Fetch low order byte of Subroutine Address
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-    }
-
-    fn jsr_run(&mut self) {
-        // ADL
-        let adl = self.data;
+    fn jsr_run(&mut self, adl: u8) {
+        // Return address is the last byte of the JSR instruction
+        let pc = self.pc;
 
         // Push high order byte of program counter to Stack
         // PCH
-        let pch = ((self.pc >> 8) & 0x00FF) as u8;
-        let addr_stck = 0x0100 + self.sp as u16;
-        self.write(&addr_stck, pch);
+        self.push(((pc >> 8) & 0x00FF) as u8);
 
         // Push low order byte of program counter to Stack
         // PCL
-        self.sp -= 1;
-        let pcl = (self.pc & 0x00FF) as u8;
-        let addr_stck = 0x0100 + self.sp as u16;
-        self.write(&addr_stck, pcl);
-
-        // Fetch high order byte of Subroutine Address
-        // ADH
-        self.pc += 1;
-        self.address = self.pc.clone();
-        self.data = self.read(&self.address);
-        let adh = self.data;
+        self.push((pc & 0x00FF) as u8);
 
-        // Next Instruction
+        // ADH is fetched once the return address is on the stack
+        let adh = self.read(&pc);
         self.address_register = ((adh as u16) << 8) & 0xFF00 | adl as u16 & 0x00FF;
+        self.address = self.address_register;
+
+        // Next Instruction
         self.pc = self.address_register;
     }
 }
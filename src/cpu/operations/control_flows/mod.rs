@@ -4,6 +4,7 @@ pub mod beq;
 pub mod bmi;
 pub mod bne;
 pub mod bpl;
+pub mod bra;
 pub mod bvc;
 pub mod bvs;
 pub mod jmp;
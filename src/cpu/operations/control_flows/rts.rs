@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * RTS - Return From Subroutine
        Operation: PC↑, PC + 1 → PC
@@ -12,30 +14,26 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            RTS	                 $60	    1	        6
     */
-    pub fn rts(&mut self) {
-        self.rts_implied();
+    pub fn rts(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.stack_dummy_read();
+        self.rts_run();
     }
 
-    fn rts_implied(&mut self) {
+    fn rts_run(&mut self) {
         // Pull PCL from Stack
         // PCL
-        self.sp += 1;
-        let addr_stck = 0x0100 | self.sp as u16 & 0x00FF;
-        self.data = self.read(&addr_stck);
-        let pcl = self.data as u16 & 0x00FF;
+        let pcl = self.pull() as u16 & 0x00FF;
 
         // Pull PCH from Stack
         // PCH
-        self.sp += 1;
-        let addr_stck = 0x0100 | self.sp as u16 & 0x00FF;
-        self.data = self.read(&addr_stck);
-        let pch = self.data as u16 & 0x00FF;
+        let pch = self.pull() as u16 & 0x00FF;
 
         self.address_register = (pch << 8) & 0xFF00 | pcl;
-    }
+        // The pulled address is read once more while it is incremented
+        self.dummy_read(self.address_register);
 
-    fn rts_run(&mut self) {
         // Next Instruction
-        self.pc = self.address_register + 0x0001;
+        self.pc = self.address_register.wrapping_add(1);
     }
 }
@@ -1,6 +1,9 @@
-use crate::cpu::model::{State, CPU};
+use crate::constants::IRQ_VECTOR;
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * BRK - Break Command
        Operation: PC + 2↓, [FFFE] → PCL, [FFFF] → PCH
@@ -20,53 +23,16 @@ impl CPU {
        Processor Status register changes
        Sets the Interrupt Disable flag.
     */
-    pub fn brk(&mut self) {
-        self.brk_implied();
+    pub fn brk(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.brk_run();
     }
 
-    fn brk_implied(&mut self) {
-        // PC + 1
-        // PC on hardware interrupt
-        self.pc += 1;
-
-        // Push high order byte of program counter to stack
-        let pch = ((self.pc >> 8) & 0x00FF) as u8;
-        let addr_stck_pch = 0x0100 + self.sp as u16 & 0x00FF;
-        self.write(&addr_stck_pch, pch);
-
-        // Push low order byte of program counter to stack
-        self.sp -= 1;
-        let pcl = (self.pc & 0x00FF) as u8;
-        let addr_stck_pcl = 0x0100 + self.sp as u16 & 0x00FF;
-        self.write(&addr_stck_pcl, pcl);
-
-        // Push status register to stack
-        self.sp -= 1;
-        self.status.set_interrupt_disable();
-        self.status.set_break_command();
-        let status = self.status.get_status();
-        let addr_stck_sta = 0x0100 + self.sp as u16 & 0x00FF;
-        self.write(&addr_stck_sta, status & 0xFF);
-
-        self.status.unset_break_command();
-        self.sp -= 1;
-
-        // Fetch low order byte of interrupt vector
-        // NMI -> 0xFFFA
-        // RES -> 0xFFFD
-        let adl = self.read(&0xFFFE);
-
-        // Fetch high order byte of interrupt vector
-        // NMI -> 0xFFFB
-        // RES -> 0xFFFD
-        let adh = self.read(&0xFFFF);
-
-        // Interrupt vector
-        self.address_register = ((adh as u16) << 8) & 0xFF00 | (adl as u16) & 0x00FF;
-    }
-
     fn brk_run(&mut self) {
-        self.pc = self.address_register;
+        // PC + 2 : BRK skips the padding byte after the op code
+        self.pc = self.pc.wrapping_add(1);
+
+        // Push PC and status with the break flag set, then load [FFFE]
+        self.interrupt(IRQ_VECTOR, true);
     }
 }
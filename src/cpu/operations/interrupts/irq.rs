@@ -0,0 +1,92 @@
+use crate::constants::IRQ_VECTOR;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * IRQ - Interrupt Request
+       Operation: PC↓, P↓, 1 → I, [FFFE] → PCL, [FFFF] → PCH
+
+       The IRQ line is level triggered. While it is held low and the interrupt disable flag is
+       clear, the processor finishes the current instruction, pushes the program counter and the
+       processor status and transfers control to the interrupt vector.
+
+       The status byte pushed by a hardware interrupt has the break flag clear, which is how an
+       interrupt handler tells an IRQ apart from a BRK sharing the same vector.
+
+       No. Cycles
+       7
+
+       Processor Status register changes
+       Sets the Interrupt Disable flag. The 65C02 also clears the Decimal flag.
+    */
+    pub fn set_irq(&mut self, active: bool) {
+        self.irq_line = active;
+    }
+
+    /* IRQ is asserted by the host (set_irq) or by a device on the bus */
+    pub fn irq_asserted(&self) -> bool {
+        // A cycle-stepped replay keeps the level sampled when the instruction started
+        self.irq_line || (!self.tick.borrow().active && self.bus.irq())
+    }
+
+    pub fn irq(&mut self) {
+        // The op code fetch is discarded and the fetch is repeated
+        self.dummy_read(self.pc);
+        self.dummy_read(self.pc);
+        self.interrupt(IRQ_VECTOR, false);
+    }
+
+    /*
+       Check interrupt lines between instructions. NMI has priority over IRQ.
+       Returns true when an interrupt sequence was entered.
+    */
+    pub fn poll_interrupts(&mut self) -> bool {
+        if self.nmi_pending {
+            self.nmi();
+            return true;
+        }
+
+        if self.irq_asserted() && self.status.get_interrupt_disable() == 0 {
+            self.irq();
+            return true;
+        }
+
+        false
+    }
+
+    /*
+       Interrupt sequence shared by BRK, IRQ and NMI.
+       Push PCH, PCL and P, set I and load PC from the vector.
+    */
+    pub fn interrupt(&mut self, vector: u16, break_command: bool) {
+        // Push high order byte of program counter to stack
+        self.push(((self.pc >> 8) & 0x00FF) as u8);
+
+        // Push low order byte of program counter to stack
+        self.push((self.pc & 0x00FF) as u8);
+
+        // Push status register to stack : B set for BRK only, unused bit always set
+        let mut status = (self.status.get_status() & !0x10) | 0x20;
+        if break_command {
+            status |= 0x10;
+        }
+        self.push(status);
+
+        self.status.set_interrupt_disable();
+
+        // 65C02 clears decimal mode so the handler starts in binary
+        if self.is_cmos() {
+            self.status.unset_decimal_mode();
+        }
+
+        // Fetch the interrupt vector, low order byte first
+        self.address_register = self.read_word(&vector);
+        self.pc = self.address_register;
+
+        if !break_command {
+            // BRK is charged through the instruction table
+            self.cycles += 7;
+        }
+    }
+}
@@ -1,2 +1,7 @@
 pub mod brk;
+pub mod irq;
+pub mod nmi;
+pub mod reset;
 pub mod rti;
+pub mod stp;
+pub mod wai;
@@ -0,0 +1,35 @@
+use crate::constants::NMI_VECTOR;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * NMI - Non-Maskable Interrupt
+       Operation: PC↓, P↓, 1 → I, [FFFA] → PCL, [FFFB] → PCH
+
+       The NMI line is edge triggered. A transition to the active level latches a request that
+       is serviced after the current instruction regardless of the interrupt disable flag.
+       Holding the line active does not cause further interrupts; it has to be released and
+       asserted again.
+
+       No. Cycles
+       7
+
+       Processor Status register changes
+       Sets the Interrupt Disable flag.
+    */
+    pub fn set_nmi(&mut self, active: bool) {
+        if active && !self.nmi_line {
+            self.nmi_pending = true;
+        }
+        self.nmi_line = active;
+    }
+
+    pub fn nmi(&mut self) {
+        self.nmi_pending = false;
+        // The op code fetch is discarded and the fetch is repeated
+        self.dummy_read(self.pc);
+        self.dummy_read(self.pc);
+        self.interrupt(NMI_VECTOR, false);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::constants::RESET_VECTOR;
+use crate::cpu::model::{ResetKind, State, CPU};
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * RES - Reset Sequence
+       Operation: S - 3 → S, 1 → I, [FFFC] → PCL, [FFFD] → PCH
+
+       The reset line runs the same seven cycle sequence as an interrupt, but the three stack
+       pushes are turned into reads so nothing is written to the stack. The stack pointer is
+       still decremented three times, which leaves it at $FD after power-on.
+
+       Note on the MOS 6502:
+
+       The value of the decimal mode flag is indeterminate after a RESET.
+       A, X and Y keep their values on a warm reset.
+
+       No. Cycles
+       7
+
+       Processor Status register changes
+       Sets the Interrupt Disable flag.
+    */
+    pub fn reset_with(&mut self, kind: ResetKind) {
+        match kind {
+            ResetKind::Cold => {
+                self.reset_power_on(0x00, 0x00, 0x00);
+            }
+            ResetKind::ColdRandom(seed) => {
+                // xorshift64 : any non zero state works
+                let mut state = seed | 0x01;
+                let mut next = || {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    (state & 0xFF) as u8
+                };
+                let (a, x, y) = (next(), next(), next());
+                self.reset_power_on(a, x, y);
+                self.status.set_status(next());
+            }
+            ResetKind::Warm => {}
+        }
+        self.reset_run();
+    }
+
+    fn reset_power_on(&mut self, a: u8, x: u8, y: u8) {
+        self.accumulator = a;
+        self.x_register = x;
+        self.y_register = y;
+        self.sp = 0x00;
+        self.status.reset();
+        self.cycles = 0;
+    }
+
+    fn reset_run(&mut self) {
+        // Three dummy stack accesses : reads instead of pushes
+        for _ in 0..3 {
+            let addr_stck = 0x0100 | self.sp as u16;
+            self.read(&addr_stck);
+            self.sp = self.sp.wrapping_sub(1);
+        }
+
+        self.status.set_interrupt_disable();
+
+        // 65C02 clears decimal mode on reset
+        if self.is_cmos() {
+            self.status.unset_decimal_mode();
+        }
+
+        // Fetch the reset vector, low order byte first
+        self.address_register = self.read_word(&RESET_VECTOR);
+        self.pc = self.address_register;
+        self.instruction_reg = None;
+        self.fault = None;
+        self.state = State::Fetch;
+        self.cycles += 7;
+    }
+}
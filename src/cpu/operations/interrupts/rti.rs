@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * RTI - Return From Interrupt
        Operation: P↑ PC↑
@@ -17,38 +19,25 @@ impl CPU {
 
        Effectively clears the Interrupt Disable flag if the updated value does not have this flag set.
     */
-    pub fn rti(&mut self) {
-        self.rti_implied();
+    pub fn rti(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.stack_dummy_read();
         self.rti_run();
     }
 
-    fn rti_implied(&mut self) {
-        // Pull processor status from stack
-        self.sp += 1;
-        let addr_stck = 0x0100 + self.sp as u16 & 0x00FF;
-        self.data = self.read(&addr_stck);
-        let b = self.status.get_break_command();
-        let u = self.status.get_unused();
-
-        self.data &= !(b << 4);
-        self.data &= !(u << 5);
-
+    fn rti_run(&mut self) {
+        // Pull processor status from stack, break and unused bits are not real flags
+        self.data = self.pull();
         self.status.set_status(self.data);
+        self.status.set_unused();
 
         // Pull PCL from stack
-        self.sp += 1;
-        let addr_stck = 0x0100 + self.sp as u16 & 0x00FF;
-        let pcl = self.read(&addr_stck);
+        let pcl = self.pull();
 
         // Pull PCH from stack
-        self.sp += 1;
-        let addr_stck = 0x0100 + self.sp as u16 & 0x00FF;
-        let pch = self.read(&addr_stck);
+        let pch = self.pull();
 
         self.address_register = ((pch as u16) << 8) & 0xFF00 | (pcl as u16) & 0x00FF;
-    }
-
-    fn rti_run(&mut self) {
         self.pc = self.address_register;
     }
 }
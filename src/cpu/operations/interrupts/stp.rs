@@ -0,0 +1,28 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::{State, CPU};
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * STP - Stop the Processor (W65C02S)
+       Operation: Stop until RESET
+
+       This instruction stops the processor clock. Interrupts are ignored and only a RESET brings the processor back to life.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Implied	            STP	                  $DB	    1	        3
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn stp(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        // One more cycle to stop the clock
+        self.dummy_read(self.pc);
+        self.stp_run();
+    }
+
+    fn stp_run(&mut self) {
+        self.state = State::Stop;
+    }
+}
@@ -0,0 +1,51 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::{State, CPU};
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * WAI - Wait for Interrupt (W65C02S)
+       Operation: Sleep until IRQ or NMI
+
+       This instruction puts the processor to sleep. It stays in the Wait state, without fetching instructions, until the IRQ or NMI line is asserted.
+
+       An NMI, or an IRQ with the interrupt disable flag clear, is serviced as usual. An IRQ with the interrupt disable flag set only wakes the processor, which continues with the instruction after WAI.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Implied	            WAI	                  $CB	    1	        3
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn wai(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        // One more cycle to stop the clock
+        self.dummy_read(self.pc);
+        self.wai_run();
+    }
+
+    fn wai_run(&mut self) {
+        self.state = State::Wait;
+    }
+
+    /*
+       Leave the Wait state once an interrupt line is asserted.
+       Returns false while the processor keeps sleeping.
+    */
+    pub fn wake(&mut self) -> bool {
+        if self.nmi_pending || self.irq_asserted() {
+            // Pending interrupt is serviced on the next fetch
+            self.state = State::Fetch;
+            return true;
+        }
+
+        false
+    }
+
+    /* Sleeping clock : devices keep running and may raise the interrupt that wakes the processor */
+    pub fn idle_cycle(&mut self) -> bool {
+        self.cycles += 1;
+        self.bus.tick(1);
+        self.wake()
+    }
+}
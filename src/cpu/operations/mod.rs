@@ -1,5 +1,7 @@
 pub mod arithemetic_and_logic;
 pub mod axy_registers;
+pub mod bit_manipulation;
 pub mod control_flows;
 pub mod interrupts;
 pub mod status_registers;
+pub mod undocumented;
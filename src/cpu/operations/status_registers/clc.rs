@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * CLC - Clear Carry Flag
        Operation: 0 → C
@@ -12,15 +14,11 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            CLC	                 $18	    1	        2
     */
-    pub fn clc(&mut self) {
-        self.clc_implied();
+    pub fn clc(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.clc_run();
     }
 
-    fn clc_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn clc_run(&mut self) {
         self.status.unset_carry();
     }
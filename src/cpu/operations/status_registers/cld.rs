@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * CLD - Clear Decimal Mode
        Operation: 0 → D
@@ -17,15 +19,11 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            CLD	                  $D8	    1	        2
     */
-    pub fn cld(&mut self) {
-        self.cld_implied();
+    pub fn cld(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.cld_run();
     }
 
-    fn cld_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn cld_run(&mut self) {
         self.status.unset_decimal_mode();
     }
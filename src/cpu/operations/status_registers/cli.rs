@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * CLI - Clear Interrupt Disable
        Operation: 0 → I
@@ -12,15 +14,11 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            CLI	                 $58	    1	        2
     */
-    pub fn cli(&mut self) {
-        self.cli_implied();
+    pub fn cli(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.cli_run();
     }
 
-    fn cli_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn cli_run(&mut self) {
         self.status.unset_interrupt_disable();
     }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * CLV - Clear Overflow Flag
        Operation: 0 → V
@@ -12,15 +14,11 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            CLV	                 $B8	    1	        2
     */
-    pub fn clv(&mut self) {
-        self.clv_implied();
+    pub fn clv(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.clv_run();
     }
 
-    fn clv_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn clv_run(&mut self) {
         self.status.unset_overflow();
     }
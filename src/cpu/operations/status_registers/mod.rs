@@ -4,8 +4,12 @@ pub mod cli;
 pub mod clv;
 pub mod pha;
 pub mod php;
+pub mod phx;
+pub mod phy;
 pub mod pla;
 pub mod plp;
+pub mod plx;
+pub mod ply;
 pub mod sec;
 pub mod sed;
 pub mod sei;
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * PHA - Push Accumulator On Stack
        Operation: A↓
@@ -12,18 +14,12 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            PHA	                 $48	    1	        3
     */
-    pub fn pha(&mut self) {
-        self.pha_implied();
+    pub fn pha(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.pha_run();
     }
 
-    fn pha_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn pha_run(&mut self) {
-        let addr = 0x0100 + self.sp.clone() as u16 & 0x00FF;
-        self.write(&addr, self.accumulator.clone());
-        self.sp -= 1;
+        self.push(self.accumulator);
     }
 }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * PHP - Push Processor Status On Stack
        Operation: P↓
@@ -12,20 +14,14 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied         	PHP	                 $08	    1	        3
     */
-    pub fn php(&mut self) {
-        self.php_implied();
+    pub fn php(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.php_run();
     }
 
-    fn php_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn php_run(&mut self) {
-        let addr = 0x0100 + self.sp.clone() as u16 & 0x00FF;
-        let status: u8 =
-            self.status.get_status() | self.status.get_break_command() | self.status.get_unused();
-        self.write(&addr, status);
-        self.sp -= 1;
+        // Pushed copy always has the break and unused bits set
+        let status: u8 = self.status.get_status() | 0x30;
+        self.push(status);
     }
 }
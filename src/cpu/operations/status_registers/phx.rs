@@ -0,0 +1,25 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * PHX - Push Index Register X On Stack (65C02)
+       Operation: X↓
+
+       This instruction transfers the current value of index register X to the next location on the stack, automatically decrementing the stack to point to the next empty location.
+
+       The Push X instruction only affects the stack pointer register which is decremented by 1 as a result of the operation. It affects no flags.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Implied	            PHX	                 $DA	    1	        3
+    */
+    pub fn phx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.phx_run();
+    }
+
+    fn phx_run(&mut self) {
+        self.push(self.x_register);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * PHY - Push Index Register Y On Stack (65C02)
+       Operation: Y↓
+
+       This instruction transfers the current value of index register Y to the next location on the stack, automatically decrementing the stack to point to the next empty location.
+
+       The Push Y instruction only affects the stack pointer register which is decremented by 1 as a result of the operation. It affects no flags.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Implied	            PHY	                 $5A	    1	        3
+    */
+    pub fn phy(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.phy_run();
+    }
+
+    fn phy_run(&mut self) {
+        self.push(self.y_register);
+    }
+}
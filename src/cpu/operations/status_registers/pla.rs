@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * PLA - Pull Accumulator From Stack
        Operation: A↑
@@ -17,19 +19,14 @@ impl CPU {
        Zero flag	    Set if the copied value is zero, otherwise cleared.
        Negative flag	Updated to the value of bit #7 of the copied value.
     */
-    pub fn pla(&mut self) {
-        self.pla_implied();
+    pub fn pla(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.stack_dummy_read();
         self.pla_run();
     }
 
-    fn pla_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn pla_run(&mut self) {
-        self.sp += 1;
-        let addr = 0x0100 + self.sp.clone() as u16 & 0x00FF;
-        self.accumulator = self.read(&addr);
+        self.accumulator = self.pull();
 
         // Set if the copied value is zero, otherwise cleared.
         if self.accumulator & 0xFF == 0x00 {
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * PLP - Pull Processor Status From Stack
        Operation: P↑
@@ -15,19 +17,14 @@ impl CPU {
        Processor Status register changes
        Updates all flags since the entire value for this register is popped from the stack.
     */
-    pub fn plp(&mut self) {
-        self.plp_implied();
+    pub fn plp(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.stack_dummy_read();
         self.plp_run();
     }
 
-    fn plp_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn plp_run(&mut self) {
-        self.sp += 1;
-        let addr = 0x0100 + self.sp.clone() as u16 & 0x00FF;
-        let data = self.read(&addr);
+        let data = self.pull();
         self.status.set_status(data);
         self.status.set_unused();
     }
@@ -0,0 +1,45 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * PLX - Pull Index Register X From Stack (65C02)
+       Operation: X↑
+
+       This instruction adds 1 to the current value of the stack pointer and uses it to address the stack and loads the contents of the stack into index register X.
+
+       The PLX instruction does not affect the carry or overflow flags. It sets N if bit 7 is on in index register X as a result of instructions, otherwise it is reset. If index register X is zero as a result of the PLX, then the Z flag is set, otherwise it is reset.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Implied	                PLX	             $FA	    1	        4
+
+       Processor Status register changes
+       Flag	Effect
+       Zero flag	    Set if the copied value is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the copied value.
+    */
+    pub fn plx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.stack_dummy_read();
+        self.plx_run();
+    }
+
+    fn plx_run(&mut self) {
+        self.x_register = self.pull();
+
+        // Set if the copied value is zero, otherwise cleared.
+        if self.x_register == 0x00 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
+
+        // Updated to the value of bit #7 of the copied value.
+        if self.x_register & 0x80 > 0 {
+            self.status.set_negative();
+        } else {
+            self.status.unset_negative();
+        }
+    }
+}
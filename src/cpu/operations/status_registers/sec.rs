@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * SEC - Set Carry Flag
        Operation: 1 → C
@@ -12,15 +14,11 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            SEC	                  $38	    1	        2
     */
-    pub fn sec(&mut self) {
-        self.sec_implied();
+    pub fn sec(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.sec_run();
     }
 
-    fn sec_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn sec_run(&mut self) {
         self.status.set_carry();
     }
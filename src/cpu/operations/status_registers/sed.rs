@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * SED - Set Decimal Mode
        Operation: 1 → D
@@ -17,15 +19,11 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            SED	                 $F8	    1	        2
     */
-    pub fn sed(&mut self) {
-        self.sed_implied();
+    pub fn sed(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.sed_run();
     }
 
-    fn sed_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn sed_run(&mut self) {
         self.status.set_decimal_mode();
     }
@@ -1,6 +1,8 @@
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
 
-impl CPU {
+impl<B: IBus> CPU<B> {
     /**
     * SEI - Set Interrupt Disable
        Operation: 1 → I
@@ -12,15 +14,11 @@ impl CPU {
        Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
        Implied	            SEI	                 $78	    1	        2
     */
-    pub fn sei(&mut self) {
-        self.sei_implied();
+    pub fn sei(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
         self.sei_run();
     }
 
-    fn sei_implied(&mut self) {
-        self.pc += 1;
-    }
-
     fn sei_run(&mut self) {
         self.status.set_interrupt_disable();
     }
@@ -0,0 +1,31 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * ALR - "AND" Immediate then Logical Shift Right Accumulator (undocumented)
+       Operation: 0 → /(A ∧ M)7...(A ∧ M)0/ → C
+
+       Also known as ASR. ANDs the immediate operand with the accumulator, then shifts the accumulator one bit to the right like LSR A.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Immediate	        ALR #$nn	          $4B	    2	        2
+
+       Processor Status register changes
+       Flag	Effect
+       Carry flag	The value of bit #0 of the AND result.
+       Zero flag	Set if the result is zero, otherwise cleared.
+       Negative flag	Always cleared.
+    */
+    pub fn alr(&mut self, mode: &AddressingMode) {
+        self.fetch_operand(mode);
+        self.alr_run();
+    }
+
+    fn alr_run(&mut self) {
+        self.and_run();
+        self.data = self.accumulator;
+        self.lsr_run(&AddressingMode::Accumulator);
+    }
+}
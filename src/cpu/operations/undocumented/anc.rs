@@ -0,0 +1,37 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * ANC - "AND" Immediate then Copy Bit 7 to Carry (undocumented)
+       Operation: A ∧ M → A, N → C
+
+       ANDs the immediate operand with the accumulator like AND, then copies the resulting negative flag into the carry flag.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Immediate	        ANC #$nn	          $0B	    2	        2
+       Immediate	        ANC #$nn	          $2B	    2	        2
+
+       Processor Status register changes
+       Flag	Effect
+       Carry flag	Updated to the value of bit #7 of the result.
+       Zero flag	Set if the result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the result.
+    */
+    pub fn anc(&mut self, mode: &AddressingMode) {
+        self.fetch_operand(mode);
+        self.anc_run();
+    }
+
+    fn anc_run(&mut self) {
+        self.and_run();
+
+        // Updated to the value of bit #7 of the result.
+        if self.status.get_negative() == 1 {
+            self.status.set_carry();
+        } else {
+            self.status.unset_carry();
+        }
+    }
+}
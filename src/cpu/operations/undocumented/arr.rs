@@ -0,0 +1,85 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * ARR - "AND" Immediate then Rotate Right Accumulator (undocumented)
+       Operation: C → /(A ∧ M)7...(A ∧ M)0/ → C
+
+       ANDs the immediate operand with the accumulator, then rotates the accumulator one bit to the right. The rotate happens inside the adder, which is why the carry and overflow flags come out differently from ROR and why the result is BCD corrected in decimal mode.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Immediate	        ARR #$nn	          $6B	    2	        2
+
+       Processor Status register changes
+       Flag	Effect
+       Carry flag	Binary mode: bit #6 of the result. Decimal mode: set if the high nibble was corrected.
+       Overflow flag	Bit #6 exclusive OR bit #5 of the result (bit #6 changed in decimal mode).
+       Zero flag	Set if the result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the result (the old carry).
+    */
+    pub fn arr(&mut self, mode: &AddressingMode) {
+        self.fetch_operand(mode);
+        self.arr_run();
+    }
+
+    fn arr_run(&mut self) {
+        let and = self.accumulator & self.data;
+        let mut temp = (and >> 1) | (self.status.get_carry() << 7);
+
+        // Set if the result is zero, otherwise cleared.
+        if temp == 0x00 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
+
+        // Updated to the value of bit #7 of the result.
+        if temp & 0x80 > 0 {
+            self.status.set_negative();
+        } else {
+            self.status.unset_negative();
+        }
+
+        if self.decimal_enabled() {
+            // Overflow : bit #6 changed by the rotate
+            if (and ^ temp) & 0x40 > 0 {
+                self.status.set_overflow();
+            } else {
+                self.status.unset_overflow();
+            }
+
+            // Low nibble BCD fixup
+            let al = and & 0x0F;
+            if al + (al & 0x01) > 0x05 {
+                temp = (temp & 0xF0) | (temp.wrapping_add(0x06) & 0x0F);
+            }
+
+            // High nibble BCD fixup also decides the carry
+            let ah = and >> 4;
+            if ah + (ah & 0x01) > 0x05 {
+                self.status.set_carry();
+                temp = temp.wrapping_add(0x60);
+            } else {
+                self.status.unset_carry();
+            }
+        } else {
+            // The carry is bit #6 of the result
+            if temp & 0x40 > 0 {
+                self.status.set_carry();
+            } else {
+                self.status.unset_carry();
+            }
+
+            // Overflow is bit #6 exclusive OR bit #5 of the result
+            if ((temp >> 6) ^ (temp >> 5)) & 0x01 > 0 {
+                self.status.set_overflow();
+            } else {
+                self.status.unset_overflow();
+            }
+        }
+
+        self.accumulator = temp;
+    }
+}
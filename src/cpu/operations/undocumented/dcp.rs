@@ -0,0 +1,29 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * DCP - Decrement Memory then Compare with Accumulator (undocumented)
+       Operation: M - 1 → M, A - M
+
+       Decrements the memory location like DEC, then compares the accumulator with the decremented value like CMP.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	                    DCP $nnnn	          $CF	    3	        6
+       X-Indexed Absolute	            DCP $nnnn,X	          $DF	    3	        7
+       Y-Indexed Absolute	            DCP $nnnn,Y	          $DB	    3	        7
+       Zero Page	                    DCP $nn	              $C7	    2	        5
+       X-Indexed Zero Page	            DCP $nn,X	          $D7	    2	        6
+       X-Indexed Zero Page Indirect	DCP ($nn,X)	          $C3	    2	        8
+       Zero Page Indirect Y-Indexed	DCP ($nn),Y	          $D3	    2	        8
+
+       Processor Status register changes
+       Same as CMP.
+    */
+    pub fn dcp(&mut self, mode: &AddressingMode) {
+        self.fetch_modify_operand(mode);
+        self.dec_run(mode);
+        self.cmp_run();
+    }
+}
@@ -0,0 +1,29 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * ISC - Increment Memory then Subtract from Accumulator with Borrow (undocumented)
+       Operation: M + 1 → M, A - M - ~C → A
+
+       Increments the memory location like INC, then subtracts the incremented value from the accumulator like SBC, including decimal mode.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	                    ISC $nnnn	          $EF	    3	        6
+       X-Indexed Absolute	            ISC $nnnn,X	          $FF	    3	        7
+       Y-Indexed Absolute	            ISC $nnnn,Y	          $FB	    3	        7
+       Zero Page	                    ISC $nn	              $E7	    2	        5
+       X-Indexed Zero Page	            ISC $nn,X	          $F7	    2	        6
+       X-Indexed Zero Page Indirect	ISC ($nn,X)	          $E3	    2	        8
+       Zero Page Indirect Y-Indexed	ISC ($nn),Y	          $F3	    2	        8
+
+       Processor Status register changes
+       Same as SBC.
+    */
+    pub fn isc(&mut self, mode: &AddressingMode) {
+        self.fetch_modify_operand(mode);
+        self.inc_run(mode);
+        self.sbc_run();
+    }
+}
@@ -0,0 +1,27 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::{CpuError, State, CPU};
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * JAM - Halt the Processor (undocumented)
+       Operation: Stop
+
+       Also known as KIL or HLT. These op codes send the instruction decoder into a state it never leaves: the processor stops fetching instructions and only a RESET brings it back. The program counter is left on the JAM op code.
+
+       Addressing Mode	Assembly Language Form	Opcode	                                            No. Bytes	No. Cycles
+       Implied	            JAM	                  $02 $12 $22 $32 $42 $52 $62 $72 $92 $B2 $D2 $F2	    1	        -
+
+       The condition is reported as CpuError::Jam, the policy decides whether the processor stays halted.
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn jam(&mut self, _mode: &AddressingMode) {
+        self.state = State::Jam;
+        self.raise(CpuError::Jam {
+            pc: self.pc,
+            opcode: self.data,
+        });
+    }
+}
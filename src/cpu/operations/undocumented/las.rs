@@ -0,0 +1,33 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * LAS - "AND" Memory with Stack Pointer (undocumented)
+       Operation: M ∧ S → A, X, S
+
+       Also known as LAR. ANDs the memory value with the stack pointer and loads the result into the accumulator, index register X and the stack pointer.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Y-Indexed Absolute	LAS $nnnn,Y	          $BB	    3	        4+p
+       p: =1 if page is crossed.
+
+       Processor Status register changes
+       Flag	Effect
+       Zero flag	Set if the result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the result.
+    */
+    pub fn las(&mut self, mode: &AddressingMode) {
+        // Fetch Data : one more cycle if the index crosses a page
+        self.fetch_operand(mode);
+        self.las_run();
+    }
+
+    fn las_run(&mut self) {
+        self.data &= self.sp;
+        self.lda_run();
+        self.x_register = self.accumulator;
+        self.sp = self.accumulator;
+    }
+}
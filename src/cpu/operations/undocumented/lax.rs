@@ -0,0 +1,56 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * LAX - Load Accumulator and Index Register X from Memory (undocumented)
+       Operation: M → A, M → X
+
+       Loads the memory value into both the accumulator and index register X, combining LDA and LDX.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	                    LAX $nnnn	          $AF	    3	        4
+       Y-Indexed Absolute	            LAX $nnnn,Y	          $BF	    3	        4+p
+       Zero Page	                    LAX $nn	              $A7	    2	        3
+       Y-Indexed Zero Page	            LAX $nn,Y	          $B7	    2	        4
+       X-Indexed Zero Page Indirect	LAX ($nn,X)	          $A3	    2	        6
+       Zero Page Indirect Y-Indexed	LAX ($nn),Y	          $B3	    2	        5+p
+       p: =1 if page is crossed.
+
+       Processor Status register changes
+       Flag	Effect
+       Zero flag	Set if the loaded value is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the loaded value.
+    */
+    pub fn lax(&mut self, mode: &AddressingMode) {
+        // Fetch Data : one more cycle if the index crosses a page
+        self.fetch_operand(mode);
+        self.lax_run();
+    }
+
+    fn lax_run(&mut self) {
+        self.lda_run();
+        self.x_register = self.accumulator;
+    }
+
+    /**
+    * LXA - Load Accumulator and Index Register X Immediate (undocumented, unstable)
+       Operation: (A ∨ CONST) ∧ M → A, X
+
+       The immediate form of LAX does not load the operand directly. Like XAA the result depends on analogue effects on the chip: the accumulator is ORed with a "magic" constant before the AND. The constant differs between chips and temperature, so it is taken from the CPU configuration.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Immediate	        LXA #$nn	          $AB	    2	        2
+
+       Processor Status register changes
+       Flag	Effect
+       Zero flag	Set if the result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the result.
+    */
+    pub fn lxa(&mut self, mode: &AddressingMode) {
+        self.fetch_operand(mode);
+        self.data &= self.accumulator | self.magic_constant;
+        self.lax_run();
+    }
+}
@@ -0,0 +1,20 @@
+pub mod alr;
+pub mod anc;
+pub mod arr;
+pub mod dcp;
+pub mod isc;
+pub mod jam;
+pub mod las;
+pub mod lax;
+pub mod nop;
+pub mod rla;
+pub mod rra;
+pub mod sax;
+pub mod sbx;
+pub mod sha;
+pub mod shx;
+pub mod shy;
+pub mod slo;
+pub mod sre;
+pub mod tas;
+pub mod xaa;
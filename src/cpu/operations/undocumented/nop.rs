@@ -0,0 +1,48 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * NOP - No Operation
+       Operation: No operation
+
+       The official NOP is a one byte, two cycle implied instruction. The NMOS 6502 also decodes a number of undocumented op codes as NOPs that still run their addressing mode: they fetch their operand bytes and read the effective address, and the absolute indexed forms take one more cycle when a page is crossed.
+
+       Addressing Mode	Assembly Language Form	Opcode	                        No. Bytes	No. Cycles
+       Implied	            NOP	                  $EA	                            1	        2
+       Implied	            NOP	                  $1A $3A $5A $7A $DA $FA	        1	        2
+       Immediate	            NOP #$nn	          $80 $82 $89 $C2 $E2	            2	        2
+       Zero Page	            NOP $nn	              $04 $44 $64	                    2	        3
+       X-Indexed Zero Page	NOP $nn,X	          $14 $34 $54 $74 $D4 $F4	        2	        4
+       Absolute	            NOP $nnnn	          $0C	                            3	        4
+       X-Indexed Absolute	NOP $nnnn,X	          $1C $3C $5C $7C $DC $FC	        3	        4+p
+       p: =1 if page is crossed.
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn nop(&mut self, mode: &AddressingMode) {
+        let cycles = self
+            .instruction_reg
+            .map_or(2, |instruction| instruction.cycle);
+        if *mode == AddressingMode::Implied {
+            if cycles == 1 {
+                // 65C02 one cycle NOPs : only the op code is fetched
+                self.pc = self.pc.wrapping_add(1);
+            } else {
+                self.resolve_address(mode);
+            }
+        } else {
+            // Dummy read of the operand : one more cycle if the index crosses a page
+            self.fetch_operand(mode);
+
+            // 65C02 $5C keeps reading the address for the rest of its cycles
+            if *mode == AddressingMode::Absolute {
+                for _ in 4..cycles {
+                    self.dummy_read(self.address);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * RLA - Rotate Left then "AND" Memory with Accumulator (undocumented)
+       Operation: C ← /M7...M0/ ← C, A ∧ M → A
+
+       Rotates the memory location one bit to the left like ROL, then ANDs the rotated value into the accumulator like AND.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	                    RLA $nnnn	          $2F	    3	        6
+       X-Indexed Absolute	            RLA $nnnn,X	          $3F	    3	        7
+       Y-Indexed Absolute	            RLA $nnnn,Y	          $3B	    3	        7
+       Zero Page	                    RLA $nn	              $27	    2	        5
+       X-Indexed Zero Page	            RLA $nn,X	          $37	    2	        6
+       X-Indexed Zero Page Indirect	RLA ($nn,X)	          $23	    2	        8
+       Zero Page Indirect Y-Indexed	RLA ($nn),Y	          $33	    2	        8
+
+       Processor Status register changes
+       Flag	Effect
+       Carry flag	The old value of bit #7 of the memory byte.
+       Zero flag	Set if the accumulator result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the accumulator result.
+    */
+    pub fn rla(&mut self, mode: &AddressingMode) {
+        self.fetch_modify_operand(mode);
+        self.rol_run(mode);
+        self.and_run();
+    }
+}
@@ -0,0 +1,29 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * RRA - Rotate Right then Add Memory to Accumulator with Carry (undocumented)
+       Operation: C → /M7...M0/ → C, A + M + C → A, C
+
+       Rotates the memory location one bit to the right like ROR, then adds the rotated value to the accumulator like ADC. The carry shifted out of the memory byte is the carry used by the addition, and decimal mode is honoured exactly as in ADC.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	                    RRA $nnnn	          $6F	    3	        6
+       X-Indexed Absolute	            RRA $nnnn,X	          $7F	    3	        7
+       Y-Indexed Absolute	            RRA $nnnn,Y	          $7B	    3	        7
+       Zero Page	                    RRA $nn	              $67	    2	        5
+       X-Indexed Zero Page	            RRA $nn,X	          $77	    2	        6
+       X-Indexed Zero Page Indirect	RRA ($nn,X)	          $63	    2	        8
+       Zero Page Indirect Y-Indexed	RRA ($nn),Y	          $73	    2	        8
+
+       Processor Status register changes
+       Same as ADC.
+    */
+    pub fn rra(&mut self, mode: &AddressingMode) {
+        self.fetch_modify_operand(mode);
+        self.ror_run(mode);
+        self.adc_run();
+    }
+}
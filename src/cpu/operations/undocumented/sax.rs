@@ -0,0 +1,30 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * SAX - Store Accumulator "AND" Index Register X in Memory (undocumented)
+       Operation: A ∧ X → M
+
+       Puts the accumulator and index register X on the bus at the same time, which stores the bitwise AND of both. Neither register nor any flag is changed.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	                    SAX $nnnn	          $8F	    3	        4
+       Zero Page	                    SAX $nn	              $87	    2	        3
+       Y-Indexed Zero Page	            SAX $nn,Y	          $97	    2	        4
+       X-Indexed Zero Page Indirect	SAX ($nn,X)	          $83	    2	        6
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn sax(&mut self, mode: &AddressingMode) {
+        self.resolve_write_address(mode);
+        self.sax_run();
+    }
+
+    fn sax_run(&mut self) {
+        let addr = self.address;
+        self.write(&addr, self.accumulator & self.x_register);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * SBX - Subtract Immediate from Accumulator "AND" Index Register X (undocumented)
+       Operation: (A ∧ X) - M → X
+
+       Also known as AXS. ANDs the accumulator with index register X and subtracts the immediate operand without borrow, storing the result in X. The flags are set as in CMP; the decimal flag is ignored.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Immediate	        SBX #$nn	          $CB	    2	        2
+
+       Processor Status register changes
+       Flag	Effect
+       Carry flag	Set if (A ∧ X) is greater than or equal to the operand, otherwise cleared.
+       Zero flag	Set if the result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the result.
+    */
+    pub fn sbx(&mut self, mode: &AddressingMode) {
+        self.fetch_operand(mode);
+        self.sbx_run();
+    }
+
+    fn sbx_run(&mut self) {
+        let and = self.accumulator & self.x_register;
+        let temp = and.wrapping_sub(self.data);
+
+        // Set if no borrow was needed, otherwise cleared.
+        if and >= self.data {
+            self.status.set_carry();
+        } else {
+            self.status.unset_carry();
+        }
+
+        // Set if the result is zero, otherwise cleared.
+        if temp == 0x00 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
+
+        // Updated to the value of bit #7 of the result.
+        if temp & 0x80 > 0 {
+            self.status.set_negative();
+        } else {
+            self.status.unset_negative();
+        }
+
+        self.x_register = temp;
+    }
+}
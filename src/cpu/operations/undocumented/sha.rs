@@ -0,0 +1,42 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * SHA - Store Accumulator "AND" Index Register X "AND" High Address + 1 (undocumented, unstable)
+       Operation: A ∧ X ∧ (H + 1) → M
+
+       Also known as AHX. Stores the accumulator ANDed with index register X and with the high byte of the base address plus one. When the index crosses a page the high byte of the effective address is replaced by the stored value.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Y-Indexed Absolute	            SHA $nnnn,Y	          $9F	    3	        5
+       Zero Page Indirect Y-Indexed	SHA ($nn),Y	          $93	    2	        6
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn sha(&mut self, mode: &AddressingMode) {
+        let page_cross = self.resolve_write_address(mode);
+        let value = self.accumulator & self.x_register;
+        self.store_high_and(value, self.y_register, page_cross);
+    }
+
+    /*
+       Store shared by SHA, SHX, SHY and TAS.
+       The value is ANDed with the high byte of the unindexed base address plus one,
+       and a page crossing puts that value on the high address lines.
+    */
+    pub(crate) fn store_high_and(&mut self, value: u8, index: u8, page_cross: bool) {
+        let base = self.address_register.wrapping_sub(index as u16);
+        let temp = value & ((base >> 8) as u8).wrapping_add(1);
+
+        if page_cross {
+            self.address_register = ((temp as u16) << 8) | (self.address_register & 0x00FF);
+            self.address = self.address_register;
+        }
+
+        let addr = self.address;
+        self.write(&addr, temp);
+    }
+}
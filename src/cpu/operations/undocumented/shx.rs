@@ -0,0 +1,22 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * SHX - Store Index Register X "AND" High Address + 1 (undocumented, unstable)
+       Operation: X ∧ (H + 1) → M
+
+       Also known as SXA. Stores index register X ANDed with the high byte of the base address plus one. When the index crosses a page the high byte of the effective address is replaced by the stored value.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Y-Indexed Absolute	SHX $nnnn,Y	          $9E	    3	        5
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn shx(&mut self, mode: &AddressingMode) {
+        let page_cross = self.resolve_write_address(mode);
+        self.store_high_and(self.x_register, self.y_register, page_cross);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * SHY - Store Index Register Y "AND" High Address + 1 (undocumented, unstable)
+       Operation: Y ∧ (H + 1) → M
+
+       Also known as SYA. Stores index register Y ANDed with the high byte of the base address plus one. When the index crosses a page the high byte of the effective address is replaced by the stored value.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       X-Indexed Absolute	SHY $nnnn,X	          $9C	    3	        5
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn shy(&mut self, mode: &AddressingMode) {
+        let page_cross = self.resolve_write_address(mode);
+        self.store_high_and(self.y_register, self.x_register, page_cross);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * SLO - Arithmetic Shift Left then "OR" Memory with Accumulator (undocumented)
+       Operation: M * 2 → M, A ∨ M → A
+
+       Shifts the memory location one bit to the left like ASL, then ORs the shifted value into the accumulator like ORA.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	                    SLO $nnnn	          $0F	    3	        6
+       X-Indexed Absolute	            SLO $nnnn,X	          $1F	    3	        7
+       Y-Indexed Absolute	            SLO $nnnn,Y	          $1B	    3	        7
+       Zero Page	                    SLO $nn	              $07	    2	        5
+       X-Indexed Zero Page	            SLO $nn,X	          $17	    2	        6
+       X-Indexed Zero Page Indirect	SLO ($nn,X)	          $03	    2	        8
+       Zero Page Indirect Y-Indexed	SLO ($nn),Y	          $13	    2	        8
+
+       Processor Status register changes
+       Flag	Effect
+       Carry flag	The old value of bit #7 of the memory byte.
+       Zero flag	Set if the accumulator result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the accumulator result.
+    */
+    pub fn slo(&mut self, mode: &AddressingMode) {
+        self.fetch_modify_operand(mode);
+        self.asl_run(mode);
+        self.ora_run();
+    }
+}
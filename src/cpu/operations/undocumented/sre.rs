@@ -0,0 +1,32 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * SRE - Logical Shift Right then "Exclusive OR" Memory with Accumulator (undocumented)
+       Operation: 0 → /M7...M0/ → C, A ⊻ M → A
+
+       Shifts the memory location one bit to the right like LSR, then exclusive ORs the shifted value into the accumulator like EOR.
+
+       Addressing Mode	            Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Absolute	                    SRE $nnnn	          $4F	    3	        6
+       X-Indexed Absolute	            SRE $nnnn,X	          $5F	    3	        7
+       Y-Indexed Absolute	            SRE $nnnn,Y	          $5B	    3	        7
+       Zero Page	                    SRE $nn	              $47	    2	        5
+       X-Indexed Zero Page	            SRE $nn,X	          $57	    2	        6
+       X-Indexed Zero Page Indirect	SRE ($nn,X)	          $43	    2	        8
+       Zero Page Indirect Y-Indexed	SRE ($nn),Y	          $53	    2	        8
+
+       Processor Status register changes
+       Flag	Effect
+       Carry flag	The old value of bit #0 of the memory byte.
+       Zero flag	Set if the accumulator result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the accumulator result.
+    */
+    pub fn sre(&mut self, mode: &AddressingMode) {
+        self.fetch_modify_operand(mode);
+        self.lsr_run(mode);
+        self.eor_run();
+    }
+}
@@ -0,0 +1,23 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * TAS - Transfer Accumulator "AND" Index Register X to Stack Pointer, then Store (undocumented, unstable)
+       Operation: A ∧ X → S, S ∧ (H + 1) → M
+
+       Also known as SHS. Loads the stack pointer with the accumulator ANDed with index register X, then stores the stack pointer ANDed with the high byte of the base address plus one, like SHA.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Y-Indexed Absolute	TAS $nnnn,Y	          $9B	    3	        5
+
+       Processor Status register changes
+       Does not update any flags.
+    */
+    pub fn tas(&mut self, mode: &AddressingMode) {
+        let page_cross = self.resolve_write_address(mode);
+        self.sp = self.accumulator & self.x_register;
+        self.store_high_and(self.sp, self.y_register, page_cross);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::cpu::instruction::AddressingMode;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU<B> {
+    /**
+    * XAA - Transfer Index Register X to Accumulator then "AND" Immediate (undocumented, unstable)
+       Operation: (A ∨ CONST) ∧ X ∧ M → A
+
+       Also known as ANE. The accumulator is ORed with a "magic" constant, then ANDed with index register X and the immediate operand. The constant depends on the individual chip, so it is taken from the CPU configuration. Only a constant of $FF makes the instruction deterministic.
+
+       Addressing Mode	Assembly Language Form	Opcode	No. Bytes	No. Cycles
+       Immediate	        XAA #$nn	          $8B	    2	        2
+
+       Processor Status register changes
+       Flag	Effect
+       Zero flag	Set if the result is zero, otherwise cleared.
+       Negative flag	Updated to the value of bit #7 of the result.
+    */
+    pub fn xaa(&mut self, mode: &AddressingMode) {
+        self.fetch_operand(mode);
+        self.xaa_run();
+    }
+
+    fn xaa_run(&mut self) {
+        self.data &= (self.accumulator | self.magic_constant) & self.x_register;
+        self.lda_run();
+    }
+}
@@ -116,6 +116,10 @@ impl ProcessorStatus {
         self.D = 0x00;
     }
 
+    pub fn get_decimal_mode(&self) -> u8 {
+        return self.D.clone();
+    }
+
     pub fn set_interrupt_disable(&mut self) {
         self.I = 0x01;
     }
@@ -124,6 +128,10 @@ impl ProcessorStatus {
         self.I = 0x00;
     }
 
+    pub fn get_interrupt_disable(&self) -> u8 {
+        return self.I.clone();
+    }
+
     pub fn set_zero(&mut self) {
         self.Z = 0x01;
     }
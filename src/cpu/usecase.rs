@@ -1,12 +1,46 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+
 use crate::bus::model::Bus;
-use crate::cpu::instruction::CPU_6502_OPERATION_CODES_MAP;
-use crate::cpu::model::{State, CPU};
+use crate::cpu::instruction::{
+    AddressingMode, Instruction, CPU_6502_OPERATION_CODES_MAP, CPU_65C02_OPERATION_CODES_MAP,
+    CPU_W65C02S_OPERATION_CODES_MAP,
+};
+use crate::cpu::model::{
+    BusCycle, CpuError, ErrorPolicies, ErrorPolicy, Registers, ResetKind, State, StepResult,
+    TickState, Variant, CPU,
+};
 use crate::cpu::processor_status::ProcessorStatus;
 use crate::interfaces::bus::IBus;
 use crate::interfaces::cpu::ICPU;
 
 impl CPU {
     pub fn new() -> Self {
+        Self::with_variant(Variant::Nmos)
+    }
+
+    pub fn with_variant(variant: Variant) -> Self {
+        Self::with_bus(Bus::flat(), variant)
+    }
+}
+
+impl<B: IBus> CPU<B> {
+    /* 65C02 family : Cmos and Wdc */
+    pub fn is_cmos(&self) -> bool {
+        matches!(self.variant, Variant::Cmos | Variant::Wdc)
+    }
+
+    /*
+       ADC and SBC work in BCD when the D flag is set.
+       The 2A03 keeps the D flag but has no decimal adder.
+    */
+    pub fn decimal_enabled(&self) -> bool {
+        self.status.get_decimal_mode() == 1 && self.variant != Variant::Ricoh2A03
+    }
+
+    /* Processor wired to an application supplied bus */
+    pub fn with_bus(bus: B, variant: Variant) -> Self {
         CPU {
             pc: 0x0000,
             address: 0x0000,
@@ -17,20 +51,30 @@ impl CPU {
             instruction_reg: None,
             status: ProcessorStatus::new(),
             address_register: 0x0000,
-            cycles: 0x0000,
+            cycles: 0,
             state: State::Fetch,
-            bus: Bus::new(),
+            bus,
             sp: 0x00,
+            irq_line: false,
+            nmi_line: false,
+            nmi_pending: false,
+            magic_constant: 0xEE,
+            variant,
+            instruction_pc: 0x0000,
+            fault: None,
+            policies: ErrorPolicies::new(),
+            tick: RefCell::new(TickState::new()),
         }
     }
 
-    fn fetch(&mut self) {
+    pub(crate) fn fetch(&mut self) {
         // Load 16-bit from program counter (PC) and set to address
         // PC -> Address
         // Example :
         //           PC = 0x0000
         //           Address = 0x0000
         self.address = self.pc.clone();
+        self.instruction_pc = self.pc;
 
         // Load data from cpu6502 instruction memory 8-bit.And store data
         // Instrunction Memory 8-bit -> Data
@@ -38,35 +82,254 @@ impl CPU {
         //           Memory = 0x0000 : 4C
         //           Data   = 0x4C
         self.data = self.read(&self.address);
+
+        if !self.bus.executable(&self.address) {
+            self.raise(CpuError::BusFault {
+                pc: self.instruction_pc,
+                address: self.address,
+            });
+        }
     }
 
-    fn decode(&mut self) {
-        // Fetch OP CODE
-        self.instruction_reg = CPU_6502_OPERATION_CODES_MAP.get(&self.data).copied();
+    /*
+       Op code fetched from a data-only region under Halt or Trap : the instruction is not
+       decoded, registers and memory are untouched and PC stays on the op code.
+    */
+    fn fetch_fault(&mut self) -> Option<CpuError> {
+        match self.fault {
+            Some(error @ CpuError::BusFault { .. })
+                if self.policies.policy(&error) != ErrorPolicy::Nop =>
+            {
+                self.fault.take()
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn decode(&mut self) {
+        // Fetch OP CODE from the table of the selected processor
+        let operation_codes = match self.variant {
+            Variant::Nmos | Variant::Ricoh2A03 => &*CPU_6502_OPERATION_CODES_MAP,
+            Variant::Cmos => &*CPU_65C02_OPERATION_CODES_MAP,
+            Variant::Wdc => &*CPU_W65C02S_OPERATION_CODES_MAP,
+        };
+        self.instruction_reg = operation_codes.get(&self.data).copied();
     }
 }
 
 /** R/W Memory */
-impl CPU {
+impl<B: IBus> CPU<B> {
     pub fn read(&self, address: &u16) -> u8 {
-        return self.bus.read(address);
+        let mut tick = self.tick.borrow_mut();
+        if !tick.active {
+            return self.bus.read(address);
+        }
+
+        // Cycle-stepped replay : earlier cycles come from the log
+        let position = tick.position;
+        tick.position += 1;
+        if let Some(BusCycle::Read { data, .. }) = tick.accesses.get(position) {
+            return *data;
+        }
+        if position < tick.accesses.len() || tick.issued {
+            // Access of a later cycle, the value is thrown away with the replay
+            return 0x00;
+        }
+
+        let data = self.bus.read(address);
+        tick.accesses.push(BusCycle::Read {
+            address: *address,
+            data,
+        });
+        tick.issued = true;
+        data
     }
 
     pub fn write(&mut self, address: &u16, data: u8) {
+        // The write still goes to the bus : ROM ignores it like real hardware
+        if !self.bus.writable(address) {
+            self.raise(CpuError::BusFault {
+                pc: self.instruction_pc,
+                address: *address,
+            });
+        }
+
+        let tick = self.tick.get_mut();
+        if tick.active {
+            let position = tick.position;
+            tick.position += 1;
+            if position < tick.accesses.len() || tick.issued {
+                return;
+            }
+            tick.accesses.push(BusCycle::Write {
+                address: *address,
+                data,
+            });
+            tick.issued = true;
+        }
+
         self.bus.write(address, data);
     }
 }
 
-impl ICPU for CPU {
-    fn reset(&mut self) {}
+/*
+   The processor is a bus for its own operations : the word helpers of IBus
+   go through CPU::read, so cycle-stepped mode sees every byte.
+*/
+impl<B: IBus> IBus for CPU<B> {
+    fn read(&self, address: &u16) -> u8 {
+        CPU::read(self, address)
+    }
+
+    fn write(&mut self, address: &u16, data: u8) {
+        CPU::write(self, address, data);
+    }
+
+    fn peek(&self, address: &u16) -> u8 {
+        self.bus.peek(address)
+    }
+
+    fn poke(&mut self, address: &u16, data: u8) {
+        self.bus.poke(address, data);
+    }
+}
+
+/** Cycle stepping */
+impl<B: IBus> CPU<B> {
+    /*
+       Advance one clock and issue exactly one bus access.
+       Returns the step result on the tick that completes an instruction or interrupt sequence.
+       A sleeping or halted processor ticks without touching the bus.
+    */
+    pub fn tick(&mut self) -> Result<Option<StepResult>, CpuError> {
+        let start = match self.tick.get_mut().start {
+            Some(start) => start,
+            None => {
+                let mut start = self.registers();
+                // Device IRQ is sampled once, when the instruction starts
+                start.irq_line |= self.bus.irq();
+                let tick = self.tick.get_mut();
+                tick.accesses.clear();
+                tick.start = Some(start);
+                start
+            }
+        };
+
+        // Interrupt lines keep their live values, the replay sees the ones the instruction started with
+        let (irq_line, nmi_line) = (self.irq_line, self.nmi_line);
+        let late_nmi = self.nmi_pending && !start.nmi_pending;
+        self.restore(&start);
+
+        {
+            let tick = self.tick.get_mut();
+            tick.position = 0;
+            tick.issued = false;
+            tick.active = true;
+        }
+        let result = self.step();
+        let tick = self.tick.get_mut();
+        tick.active = false;
+        let accesses = tick.accesses.len() as u64;
+        let complete = tick.position == tick.accesses.len();
+
+        if complete {
+            tick.start = None;
+        } else {
+            self.restore(&start);
+        }
+        self.cycles = start.cycles + accesses.max(1);
+        self.irq_line = irq_line;
+        self.nmi_line = nmi_line;
+        self.nmi_pending |= late_nmi;
+        self.bus.tick(1);
 
-    fn run(&mut self) {
+        if !complete {
+            return Ok(None);
+        }
+        let mut result = result?;
+        result.cycles = accesses.max(1);
+        Ok(Some(result))
+    }
+
+    fn registers(&self) -> Registers {
+        Registers {
+            pc: self.pc,
+            address: self.address,
+            address_register: self.address_register,
+            data: self.data,
+            x_register: self.x_register,
+            y_register: self.y_register,
+            accumulator: self.accumulator,
+            instruction_reg: self.instruction_reg,
+            status: self.status.get_status(),
+            cycles: self.cycles,
+            state: self.state,
+            sp: self.sp,
+            irq_line: self.irq_line,
+            nmi_pending: self.nmi_pending,
+            instruction_pc: self.instruction_pc,
+            fault: self.fault,
+        }
+    }
+
+    fn restore(&mut self, registers: &Registers) {
+        self.pc = registers.pc;
+        self.address = registers.address;
+        self.address_register = registers.address_register;
+        self.data = registers.data;
+        self.x_register = registers.x_register;
+        self.y_register = registers.y_register;
+        self.accumulator = registers.accumulator;
+        self.instruction_reg = registers.instruction_reg;
+        self.status.set_status(registers.status);
+        self.cycles = registers.cycles;
+        self.state = registers.state;
+        self.sp = registers.sp;
+        self.irq_line = registers.irq_line;
+        self.nmi_pending = registers.nmi_pending;
+        self.instruction_pc = registers.instruction_pc;
+        self.fault = registers.fault;
+    }
+}
+
+impl TickState {
+    pub fn new() -> Self {
+        TickState {
+            accesses: Vec::new(),
+            position: 0,
+            issued: false,
+            active: false,
+            start: None,
+        }
+    }
+}
+
+impl Default for TickState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: IBus> ICPU for CPU<B> {
+    fn reset(&mut self) {
+        self.reset_with(ResetKind::Warm);
+    }
+
+    fn run(&mut self) -> Result<(), CpuError> {
+        let mut clocked = self.cycles;
         loop {
             // Process Instruction With State
             match self.state {
                 State::Fetch => {
+                    // Pending NMI / IRQ are serviced between instructions
+                    if self.poll_interrupts() {
+                        continue;
+                    }
                     // State fetch
                     self.fetch();
+                    if let Some(error) = self.fetch_fault() {
+                        return self.handle_error(error);
+                    }
                     self.state = State::Decode;
                 }
                 State::Decode => {
@@ -77,262 +340,771 @@ impl ICPU for CPU {
                 State::Execute => {
                     // State Execute
                     self.execute();
-                    self.state = State::Fetch;
+                    // JAM, WAI and STP leave the processor halted
+                    if matches!(self.state, State::Execute) {
+                        self.state = State::Fetch;
+                    }
                 }
-                State::Exit => {
+                State::Wait => {
+                    // WAI sleeps until an interrupt line is asserted, the devices keep running
+                    if !self.wake() {
+                        self.cycles += 1;
+                    }
+                }
+                State::Exit | State::Jam | State::Stop => {
                     break;
                 }
-                _ => {
-                    panic!("Program has problem!.");
+            }
+
+            // Devices follow the cycles spent by the instruction or the interrupt sequence
+            if self.cycles != clocked {
+                self.bus.tick(self.cycles - clocked);
+                clocked = self.cycles;
+            }
+
+            // Conditions raised by the instruction or the interrupt sequence
+            if let Some(error) = self.fault.take() {
+                self.handle_error(error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn step(&mut self) -> Result<StepResult, CpuError> {
+        let cycles = self.cycles;
+        let mut result = StepResult::new(self.pc as u32, self.state);
+
+        // A sleeping processor only moves on once an interrupt line is asserted
+        if self.state == State::Wait && !self.wake() {
+            return Ok(result);
+        }
+        if matches!(self.state, State::Exit | State::Jam | State::Stop) {
+            return Ok(result);
+        }
+
+        // Pending NMI / IRQ : the step is the interrupt sequence
+        let nmi = self.nmi_pending;
+        if self.poll_interrupts() {
+            result.mnemonic = if nmi { "NMI" } else { "IRQ" };
+            result.interrupt = true;
+        } else {
+            self.fetch();
+            if let Some(error) = self.fetch_fault() {
+                self.handle_error(error)?;
+                return Ok(result);
+            }
+            self.decode();
+            result.opcode = self.data;
+
+            if let Some(instruction) = self.instruction_reg {
+                result.mnemonic = instruction.name;
+                result.length = instruction.length;
+            }
+            // Peek the operand bytes, the instruction issues its own bus accesses
+            for offset in 1..result.length {
+                let byte = self.bus.peek(&self.pc.wrapping_add(offset as u16)) as u32;
+                result.operand |= byte << (8 * (offset - 1));
+            }
+
+            self.state = State::Execute;
+            self.execute();
+            if matches!(self.state, State::Execute) {
+                self.state = State::Fetch;
+            }
+
+            if let Some(instruction) = self.instruction_reg {
+                if !matches!(
+                    instruction.mode,
+                    AddressingMode::Implied | AddressingMode::Accumulator
+                ) {
+                    result.effective_address = Some(self.address as u32);
                 }
+                // Taken branches always cost more than the base cycles
+                result.branch_taken = matches!(
+                    instruction.mode,
+                    AddressingMode::Relative | AddressingMode::ZeroPageRelative
+                ) && self.cycles - cycles > instruction.cycle as u64;
             }
         }
+
+        result.cycles = self.cycles - cycles;
+        // Cycle-stepped mode clocks the devices once per tick
+        if !self.tick.get_mut().active {
+            self.bus.tick(result.cycles);
+        }
+        if let Some(error) = self.fault.take() {
+            self.handle_error(error)?;
+        }
+        result.state = self.state;
+        Ok(result)
+    }
+
+    fn idle(&mut self) -> bool {
+        self.idle_cycle()
+    }
+}
+
+impl StepResult {
+    /* Empty result for the op code at PC, filled in by step */
+    pub fn new(pc: u32, state: State) -> Self {
+        StepResult {
+            pc,
+            opcode: 0x00,
+            mnemonic: "???",
+            operand: 0x0000,
+            length: 1,
+            effective_address: None,
+            cycles: 0,
+            branch_taken: false,
+            interrupt: false,
+            state,
+        }
     }
 }
 
-impl CPU {
-    fn execute(&mut self) {
+impl<B: IBus> CPU<B> {
+    pub(crate) fn execute(&mut self) {
         match self.instruction_reg {
             Some(instruction) => {
+                // Base cost, page-cross and branch penalties are added by the operation
+                self.cycles += instruction.cycle as u64;
+
+                // W65C02S bit instructions, WAI and STP
+                if self.variant == Variant::Wdc && self.execute_w65c02s(instruction) {
+                    return;
+                }
+
+                // 65C02 op codes that differ from the NMOS table
+                if self.is_cmos() && self.execute_65c02(instruction) {
+                    return;
+                }
+
                 match instruction.code {
                     /* CPX Compare X Register */
                     0xE0 | 0xE4 | 0xEC => {
-                        self.cpx(&instruction.code);
+                        self.cpx(&instruction.mode);
                     }
                     /* CPY Compare Y Register */
                     0xC0 | 0xC4 | 0xCC => {
-                        self.cpy(&instruction.code);
+                        self.cpy(&instruction.mode);
                     }
                     /* DEX Decrement X Register */
                     0xCA => {
-                        self.dex();
+                        self.dex(&instruction.mode);
                     }
                     /* DEY Decrement Y Register */
                     0x88 => {
-                        self.dey();
+                        self.dey(&instruction.mode);
                     }
                     /* INC - Increment Memory By One */
                     0xE6 | 0xF6 | 0xEE | 0xFE => {
-                        self.inc(&instruction.code);
+                        self.inc(&instruction.mode);
                     }
                     /* INX - Increment Index Register X By One */
                     0xE8 => {
-                        self.inx();
+                        self.inx(&instruction.mode);
                     }
                     /* INY - Increment Index Register Y By One */
                     0xC8 => {
-                        self.iny();
+                        self.iny(&instruction.mode);
                     }
                     /* LDA - Load Accumulator with Memory */
                     0xA9 | 0xA5 | 0xB5 | 0xAD | 0xBD | 0xB9 | 0xA1 | 0xB1 => {
-                        self.lda(&instruction.code);
+                        self.lda(&instruction.mode);
                     }
                     /* LDX - Load Index Register X From Memory */
                     0xA2 | 0xA6 | 0xB6 | 0xAE | 0xBE => {
-                        self.ldx(&instruction.code);
+                        self.ldx(&instruction.mode);
                     }
                     /* LDY - Load Index Register Y From Memory */
                     0xA0 | 0xA4 | 0xB4 | 0xAC | 0xBC => {
-                        self.ldy(&instruction.code);
+                        self.ldy(&instruction.mode);
                     }
                     /* STA - Store Accumulator in Memory */
                     0x85 | 0x95 | 0x8D | 0x9D | 0x99 | 0x81 | 0x91 => {
-                        self.sta(&instruction.code);
+                        self.sta(&instruction.mode);
                     }
                     /* STX - Store Index Register X In Memory */
                     0x86 | 0x96 | 0x8E => {
-                        self.stx(&instruction.code);
+                        self.stx(&instruction.mode);
                     }
                     /* STY - Store Index Register Y In Memory */
                     0x84 | 0x94 | 0x8C => {
-                        self.sty(&instruction.code);
+                        self.sty(&instruction.mode);
                     }
                     /* TAX - Transfer Accumulator To Index X */
                     0xAA => {
-                        self.tax();
+                        self.tax(&instruction.mode);
                     }
                     /* TAY - Transfer Accumula Tor To Index Y */
                     0xA8 => {
-                        self.tay();
+                        self.tay(&instruction.mode);
                     }
                     /* TSX - Transfer Stack Pointer To Index X */
                     0xBA => {
-                        self.tsx();
+                        self.tsx(&instruction.mode);
                     }
                     /* TXA - Transfer Index X To Accumulator */
                     0x8A => {
-                        self.txa();
+                        self.txa(&instruction.mode);
                     }
                     /* TXS - Transfer Index X To Stack Pointer */
                     0x9A => {
-                        self.txs();
+                        self.txs(&instruction.mode);
                     }
                     /* TYA - Transfer Index Y To Accumulator */
                     0x98 => {
-                        self.tya();
+                        self.tya(&instruction.mode);
                     }
                     /* CLC - Clear Carry Flag */
                     0x18 => {
-                        self.clc();
+                        self.clc(&instruction.mode);
                     }
                     /* CLD - Clear Decimal Mode */
                     0xD8 => {
-                        self.cld();
+                        self.cld(&instruction.mode);
                     }
                     /* CLI - Clear Interrupt Disable */
                     0x58 => {
-                        self.cli();
+                        self.cli(&instruction.mode);
                     }
                     /* CLV - Clear Overflow Flag */
                     0xB8 => {
-                        self.clv();
+                        self.clv(&instruction.mode);
                     }
                     /* SEC - Set Carry Flag */
                     0x38 => {
-                        self.sec();
+                        self.sec(&instruction.mode);
                     }
                     /* SED - Set Decimal Mode */
                     0xF8 => {
-                        self.sed();
+                        self.sed(&instruction.mode);
                     }
                     /* SEI - Set Interrupt Disable */
                     0x78 => {
-                        self.sei();
+                        self.sei(&instruction.mode);
                     }
                     /* PHA - Push Accumulator On Stack */
                     0x48 => {
-                        self.pha();
+                        self.pha(&instruction.mode);
                     }
                     /* PHP - Push Processor Status On Stack */
                     0x08 => {
-                        self.php();
+                        self.php(&instruction.mode);
                     }
                     /* PLA - Pull Accumulator From Stack */
                     0x68 => {
-                        self.pla();
+                        self.pla(&instruction.mode);
                     }
                     /* PLP - Pull Processor Status From Stack */
                     0x28 => {
-                        self.plp();
+                        self.plp(&instruction.mode);
                     }
                     /* BCC - Branch on Carry Clear */
                     0x90 => {
-                        self.bcc();
+                        self.bcc(&instruction.mode);
                     }
                     /* BCS - Branch on Carry Set */
                     0xB0 => {
-                        self.bcs();
+                        self.bcs(&instruction.mode);
                     }
                     /* BEQ - Branch on Result Zero */
                     0xF0 => {
-                        self.beq();
+                        self.beq(&instruction.mode);
                     }
                     /* BMI - Branch on Result Minus */
                     0x30 => {
-                        self.bmi();
+                        self.bmi(&instruction.mode);
                     }
                     /* BNE - Branch on Result Not Zero */
                     0xD0 => {
-                        self.bne();
+                        self.bne(&instruction.mode);
                     }
                     /* BPL - Branch on Result Plus */
                     0x10 => {
-                        self.bpl();
+                        self.bpl(&instruction.mode);
                     }
                     /* BVC - Branch on Overflow Clear */
                     0x50 => {
-                        self.bvc();
+                        self.bvc(&instruction.mode);
                     }
                     /* JMP - JMP Indirect */
                     0x4C | 0x6C => {
-                        self.jmp(&instruction.code);
+                        self.jmp(&instruction.mode);
                     }
                     /* JSR - Jump To Subroutine */
                     0x20 => {
-                        self.jsr();
+                        self.jsr(&instruction.mode);
                     }
                     /* RTS - Return From Subroutine */
                     0x60 => {
-                        self.rts();
+                        self.rts(&instruction.mode);
                     }
                     /* BRK - Break Command */
                     0x00 => {
-                        self.brk();
+                        self.brk(&instruction.mode);
                     }
                     /* RTI - Return From Interrupt */
                     0x40 => {
-                        self.rti();
+                        self.rti(&instruction.mode);
                     }
                     /* ADC - Add Memory to Accumulator with Carry */
                     0x69 | 0x6D | 0x7D | 0x79 | 0x65 | 0x75 | 0x61 | 0x71 => {
-                        self.adc(&instruction.code);
+                        self.adc(&instruction.mode);
                     }
                     /* AND - "AND" Memory with Accumulator */
                     0x29 | 0x2D | 0x3D | 0x39 | 0x25 | 0x35 | 0x21 | 0x31 => {
-                        self.and(&instruction.code);
+                        self.and(&instruction.mode);
                     }
                     /* ASL - Arithmetic Shift Left */
                     0x0A | 0x0E | 0x1E | 0x06 | 0x16 => {
-                        self.asl(&instruction.code);
+                        self.asl(&instruction.mode);
                     }
                     /* BIT - Test Bits in Memory with Accumulator */
                     0x2C | 0x24 => {
-                        self.bit(&instruction.code);
+                        self.bit(&instruction.mode);
                     }
                     /* CMP - Compare Memory and Accumulator */
                     0xC9 | 0xCD | 0xDD | 0xD9 | 0xC5 | 0xD5 | 0xC1 | 0xD1 => {
-                        self.cmp(&instruction.code);
+                        self.cmp(&instruction.mode);
                     }
                     /* DEC - Decrement Memory By One */
                     0xCE | 0xDE | 0xC6 | 0xD6 => {
-                        self.dec(&instruction.code);
+                        self.dec(&instruction.mode);
                     }
                     /* EOR - "Exclusive OR" Memory with Accumulator */
                     0x49 | 0x4D | 0x5D | 0x59 | 0x45 | 0x55 | 0x41 | 0x51 => {
-                        self.eor(&instruction.code);
+                        self.eor(&instruction.mode);
                     }
                     /* LSR - Logical Shift Right */
                     0x4A | 0x4E | 0x5E | 0x46 | 0x56 => {
-                        self.lsr(&instruction.code);
+                        self.lsr(&instruction.mode);
                     }
                     /* ORA - "OR" Memory with Accumulator */
                     0x09 | 0x0D | 0x1D | 0x19 | 0x05 | 0x15 | 0x01 | 0x11 => {
-                        self.ora(&instruction.code);
+                        self.ora(&instruction.mode);
                     }
                     /* ROL - Rotate Left */
                     0x2A | 0x2E | 0x3E | 0x26 | 0x36 => {
-                        self.rol(&instruction.code);
+                        self.rol(&instruction.mode);
                     }
                     /* ROR - Rotate Right */
                     0x6A | 0x6E | 0x7E | 0x66 | 0x76 => {
-                        self.ror(&instruction.code);
+                        self.ror(&instruction.mode);
                     }
                     /* SBC - Subtract Memory from Accumulator with Borrow */
                     0xE9 | 0xED | 0xFD | 0xF9 | 0xE5 | 0xF5 | 0xE1 | 0xF1 => {
-                        self.sbc(&instruction.code);
+                        self.sbc(&instruction.mode);
                     }
                     /* BVS - Branch on Overflow Set */
                     0x70 => {
-                        self.bvs();
+                        self.bvs(&instruction.mode);
                     }
-                    _ => {
-                        self.state = State::Fetch;
+                    /* NOP - No Operation, including the undocumented NOPs */
+                    0xEA | 0x1A | 0x3A | 0x5A | 0x7A | 0xDA | 0xFA | 0x80 | 0x82 | 0x89 | 0xC2
+                    | 0xE2 | 0x04 | 0x44 | 0x64 | 0x14 | 0x34 | 0x54 | 0x74 | 0xD4 | 0xF4
+                    | 0x0C | 0x1C | 0x3C | 0x5C | 0x7C | 0xDC | 0xFC => {
+                        self.nop(&instruction.mode);
+                    }
+                    /* SLO - Shift Left then "OR" (undocumented) */
+                    0x07 | 0x17 | 0x0F | 0x1F | 0x1B | 0x03 | 0x13 => {
+                        self.slo(&instruction.mode);
+                    }
+                    /* RLA - Rotate Left then "AND" (undocumented) */
+                    0x27 | 0x37 | 0x2F | 0x3F | 0x3B | 0x23 | 0x33 => {
+                        self.rla(&instruction.mode);
+                    }
+                    /* SRE - Shift Right then "Exclusive OR" (undocumented) */
+                    0x47 | 0x57 | 0x4F | 0x5F | 0x5B | 0x43 | 0x53 => {
+                        self.sre(&instruction.mode);
+                    }
+                    /* RRA - Rotate Right then Add with Carry (undocumented) */
+                    0x67 | 0x77 | 0x6F | 0x7F | 0x7B | 0x63 | 0x73 => {
+                        self.rra(&instruction.mode);
+                    }
+                    /* SAX - Store A "AND" X (undocumented) */
+                    0x87 | 0x97 | 0x8F | 0x83 => {
+                        self.sax(&instruction.mode);
+                    }
+                    /* LAX - Load A and X (undocumented) */
+                    0xA7 | 0xB7 | 0xAF | 0xBF | 0xA3 | 0xB3 => {
+                        self.lax(&instruction.mode);
+                    }
+                    /* DCP - Decrement then Compare (undocumented) */
+                    0xC7 | 0xD7 | 0xCF | 0xDF | 0xDB | 0xC3 | 0xD3 => {
+                        self.dcp(&instruction.mode);
+                    }
+                    /* ISC - Increment then Subtract with Borrow (undocumented) */
+                    0xE7 | 0xF7 | 0xEF | 0xFF | 0xFB | 0xE3 | 0xF3 => {
+                        self.isc(&instruction.mode);
+                    }
+                    /* ANC - "AND" then Copy N to C (undocumented) */
+                    0x0B | 0x2B => {
+                        self.anc(&instruction.mode);
+                    }
+                    /* ALR - "AND" then Shift Right (undocumented) */
+                    0x4B => {
+                        self.alr(&instruction.mode);
+                    }
+                    /* ARR - "AND" then Rotate Right (undocumented) */
+                    0x6B => {
+                        self.arr(&instruction.mode);
+                    }
+                    /* SBX - (A "AND" X) minus Immediate to X (undocumented) */
+                    0xCB => {
+                        self.sbx(&instruction.mode);
+                    }
+                    /* USBC - Same as SBC Immediate (undocumented) */
+                    0xEB => {
+                        self.sbc(&instruction.mode);
+                    }
+                    /* LAS - "AND" Memory with Stack Pointer (undocumented) */
+                    0xBB => {
+                        self.las(&instruction.mode);
+                    }
+                    /* XAA - X "AND" Immediate to A (undocumented, unstable) */
+                    0x8B => {
+                        self.xaa(&instruction.mode);
+                    }
+                    /* LXA - Load A and X Immediate (undocumented, unstable) */
+                    0xAB => {
+                        self.lxa(&instruction.mode);
+                    }
+                    /* SHA - Store A "AND" X "AND" H+1 (undocumented, unstable) */
+                    0x93 | 0x9F => {
+                        self.sha(&instruction.mode);
+                    }
+                    /* SHX - Store X "AND" H+1 (undocumented, unstable) */
+                    0x9E => {
+                        self.shx(&instruction.mode);
+                    }
+                    /* SHY - Store Y "AND" H+1 (undocumented, unstable) */
+                    0x9C => {
+                        self.shy(&instruction.mode);
+                    }
+                    /* TAS - A "AND" X to SP, then store (undocumented, unstable) */
+                    0x9B => {
+                        self.tas(&instruction.mode);
+                    }
+                    /* JAM - Halt the processor (undocumented) */
+                    0x02 | 0x12 | 0x22 | 0x32 | 0x42 | 0x52 | 0x62 | 0x72 | 0x92 | 0xB2 | 0xD2
+                    | 0xF2 => {
+                        self.jam(&instruction.mode);
                     }
                 }
             }
             None => {
-                panic!("Not found instruction");
+                let pc = self.pc;
+                let bytes = [
+                    self.bus.peek(&pc),
+                    self.bus.peek(&pc.wrapping_add(1)),
+                    self.bus.peek(&pc.wrapping_add(2)),
+                ];
+                self.raise(CpuError::UnknownOpcode { pc, bytes });
             }
         }
     }
+
+    /*
+       Dispatch the W65C02S op codes that are NOPs on a plain 65C02.
+       Returns false when the op code is shared with the 65C02 table.
+    */
+    fn execute_w65c02s(&mut self, instruction: &'static Instruction) -> bool {
+        // Bit number is encoded in bits 4-6 of the op code
+        let bit = (instruction.code >> 4) & 0x07;
+
+        match instruction.code {
+            /* RMB - Reset Memory Bit */
+            0x07 | 0x17 | 0x27 | 0x37 | 0x47 | 0x57 | 0x67 | 0x77 => {
+                self.rmb(&instruction.mode, bit);
+            }
+            /* SMB - Set Memory Bit */
+            0x87 | 0x97 | 0xA7 | 0xB7 | 0xC7 | 0xD7 | 0xE7 | 0xF7 => {
+                self.smb(&instruction.mode, bit);
+            }
+            /* BBR - Branch on Bit Reset */
+            0x0F | 0x1F | 0x2F | 0x3F | 0x4F | 0x5F | 0x6F | 0x7F => {
+                self.bbr(&instruction.mode, bit);
+            }
+            /* BBS - Branch on Bit Set */
+            0x8F | 0x9F | 0xAF | 0xBF | 0xCF | 0xDF | 0xEF | 0xFF => {
+                self.bbs(&instruction.mode, bit);
+            }
+            /* WAI - Wait for Interrupt */
+            0xCB => {
+                self.wai(&instruction.mode);
+            }
+            /* STP - Stop the Processor */
+            0xDB => {
+                self.stp(&instruction.mode);
+            }
+            _ => {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /*
+       Dispatch the 65C02 op codes that are new or behave differently from NMOS.
+       Returns false when the op code is shared with the NMOS table.
+    */
+    fn execute_65c02(&mut self, instruction: &'static Instruction) -> bool {
+        match instruction.code {
+            /* BRA - Branch Always */
+            0x80 => {
+                self.bra(&instruction.mode);
+            }
+            /* PHX - Push Index Register X On Stack */
+            0xDA => {
+                self.phx(&instruction.mode);
+            }
+            /* PHY - Push Index Register Y On Stack */
+            0x5A => {
+                self.phy(&instruction.mode);
+            }
+            /* PLX - Pull Index Register X From Stack */
+            0xFA => {
+                self.plx(&instruction.mode);
+            }
+            /* PLY - Pull Index Register Y From Stack */
+            0x7A => {
+                self.ply(&instruction.mode);
+            }
+            /* STZ - Store Zero in Memory */
+            0x64 | 0x74 | 0x9C | 0x9E => {
+                self.stz(&instruction.mode);
+            }
+            /* TRB - Test And Reset Memory Bits With Accumulator */
+            0x14 | 0x1C => {
+                self.trb(&instruction.mode);
+            }
+            /* TSB - Test And Set Memory Bits With Accumulator */
+            0x04 | 0x0C => {
+                self.tsb(&instruction.mode);
+            }
+            /* INC A - Increment Accumulator By One */
+            0x1A => {
+                self.inc(&instruction.mode);
+            }
+            /* DEC A - Decrement Accumulator By One */
+            0x3A => {
+                self.dec(&instruction.mode);
+            }
+            /* (zp) - Zero Page Indirect versions of the accumulator instructions */
+            0x12 => {
+                self.ora(&instruction.mode);
+            }
+            0x32 => {
+                self.and(&instruction.mode);
+            }
+            0x52 => {
+                self.eor(&instruction.mode);
+            }
+            0x72 => {
+                self.adc(&instruction.mode);
+            }
+            0x92 => {
+                self.sta(&instruction.mode);
+            }
+            0xB2 => {
+                self.lda(&instruction.mode);
+            }
+            0xD2 => {
+                self.cmp(&instruction.mode);
+            }
+            0xF2 => {
+                self.sbc(&instruction.mode);
+            }
+            /* BIT - Immediate and X-Indexed */
+            0x89 | 0x34 | 0x3C => {
+                self.bit(&instruction.mode);
+            }
+            /* JMP - X-Indexed Absolute Indirect */
+            0x7C => {
+                self.jmp(&instruction.mode);
+            }
+            /* NOP - Unused op codes are NOPs on the 65C02 */
+            0x03 | 0x13 | 0x23 | 0x33 | 0x43 | 0x53 | 0x63 | 0x73 | 0x83 | 0x93 | 0xA3 | 0xB3
+            | 0xC3 | 0xD3 | 0xE3 | 0xF3 | 0x07 | 0x17 | 0x27 | 0x37 | 0x47 | 0x57 | 0x67 | 0x77
+            | 0x87 | 0x97 | 0xA7 | 0xB7 | 0xC7 | 0xD7 | 0xE7 | 0xF7 | 0x0B | 0x1B | 0x2B | 0x3B
+            | 0x4B | 0x5B | 0x6B | 0x7B | 0x8B | 0x9B | 0xAB | 0xBB | 0xCB | 0xDB | 0xEB | 0xFB
+            | 0x0F | 0x1F | 0x2F | 0x3F | 0x4F | 0x5F | 0x6F | 0x7F | 0x8F | 0x9F | 0xAF | 0xBF
+            | 0xCF | 0xDF | 0xEF | 0xFF | 0x02 | 0x22 | 0x42 | 0x62 | 0x82 | 0xC2 | 0xE2 | 0x44
+            | 0x54 | 0xD4 | 0xF4 | 0x5C | 0xDC | 0xFC => {
+                self.nop(&instruction.mode);
+            }
+            _ => {
+                return false;
+            }
+        }
+
+        true
+    }
 }
 
 /** Calculate */
-impl CPU {
-    pub fn page_cross(&mut self, old_addr: u16, new_addr: u16) -> bool {
-        if old_addr != new_addr {
-            return true;
+impl<B: IBus> CPU<B> {
+    pub fn page_cross(&self, old_addr: u16, new_addr: u16) -> bool {
+        old_addr & 0xFF00 != new_addr & 0xFF00
+    }
+
+    /*
+       Take a branch to the resolved target address.
+       One cycle for the taken branch and one more when the target is on another page.
+    */
+    pub fn branch(&mut self, page_cross: bool) {
+        // The next op code is read while the offset is added
+        self.cycles += 1;
+        self.dummy_read(self.pc);
+        if page_cross {
+            // Then the target before the carry reaches PCH
+            self.cycles += 1;
+            self.dummy_read(self.pc & 0xFF00 | self.address_register & 0x00FF);
+        }
+        self.pc = self.address_register;
+    }
+}
+
+/** Stack */
+impl<B: IBus> CPU<B> {
+    /* Write to the stack page and decrement the stack pointer */
+    pub fn push(&mut self, data: u8) {
+        let addr_stck = 0x0100 | self.sp as u16;
+        self.write(&addr_stck, data);
+        if self.sp == 0x00 {
+            self.raise(CpuError::StackWrap {
+                pc: self.instruction_pc,
+                sp: self.sp,
+            });
+        }
+        self.sp = self.sp.wrapping_sub(1);
+    }
+
+    /* Read the stack top without moving the pointer : the cycle spent before the first pull */
+    pub fn stack_dummy_read(&mut self) {
+        self.dummy_read(0x0100 | self.sp as u16);
+    }
+
+    /* Increment the stack pointer and read from the stack page */
+    pub fn pull(&mut self) -> u8 {
+        if self.sp == 0xFF {
+            self.raise(CpuError::StackWrap {
+                pc: self.instruction_pc,
+                sp: self.sp,
+            });
+        }
+        self.sp = self.sp.wrapping_add(1);
+        let addr_stck = 0x0100 | self.sp as u16;
+        self.read(&addr_stck)
+    }
+}
+
+/** Errors */
+impl<B: IBus> CPU<B> {
+    /* Record a condition, the first one raised by an instruction wins */
+    pub fn raise(&mut self, error: CpuError) {
+        if self.fault.is_none() {
+            self.fault = Some(error);
+        }
+    }
+
+    /*
+       Apply the configured policy to a raised condition.
+       Returns the error when the host has to see it (Halt and Trap).
+    */
+    pub fn handle_error(&mut self, error: CpuError) -> Result<(), CpuError> {
+        self.policies.resolve(error, &mut self.pc, &mut self.state)
+    }
+}
+
+impl ErrorPolicies {
+    /*
+       Unknown op codes and JAM halt the processor.
+       Stack wrap and bus faults are ignored like on real hardware.
+    */
+    pub fn new() -> Self {
+        ErrorPolicies {
+            unknown_opcode: ErrorPolicy::Halt,
+            jam: ErrorPolicy::Halt,
+            stack_wrap: ErrorPolicy::Nop,
+            bus_fault: ErrorPolicy::Nop,
+        }
+    }
+
+    pub fn policy(&self, error: &CpuError) -> ErrorPolicy {
+        match error {
+            CpuError::UnknownOpcode { .. } => self.unknown_opcode,
+            CpuError::Jam { .. } => self.jam,
+            CpuError::StackWrap { .. } => self.stack_wrap,
+            CpuError::BusFault { .. } => self.bus_fault,
+        }
+    }
+
+    /*
+       Processor side of a policy, shared by the 6502 and 65C816 cores.
+       Halt stops the processor, Nop and Trap step over an op code that could not be executed.
+       Returns the error when the host has to see it (Halt and Trap).
+    */
+    pub fn resolve(
+        &self,
+        error: CpuError,
+        pc: &mut u16,
+        state: &mut State,
+    ) -> Result<(), CpuError> {
+        let policy = self.policy(&error);
+
+        if policy == ErrorPolicy::Halt {
+            // A JAM stays jammed, anything else stops the processor
+            if *state != State::Jam {
+                *state = State::Exit;
+            }
+            return Err(error);
+        }
+
+        // Step over the op code that could not be executed
+        match error {
+            CpuError::UnknownOpcode { pc: address, .. } | CpuError::Jam { pc: address, .. } => {
+                *pc = address.wrapping_add(1);
+                *state = State::Fetch;
+            }
+            CpuError::StackWrap { .. } | CpuError::BusFault { .. } => {}
+        }
+
+        match policy {
+            ErrorPolicy::Trap => Err(error),
+            _ => Ok(()),
         }
-        return false;
     }
 }
+
+impl Default for ErrorPolicies {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for CpuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CpuError::UnknownOpcode { pc, bytes } => write!(
+                f,
+                "unknown op code at ${:04X} : {:02X} {:02X} {:02X}",
+                pc, bytes[0], bytes[1], bytes[2]
+            ),
+            CpuError::Jam { pc, opcode } => {
+                write!(f, "JAM op code ${:02X} at ${:04X}", opcode, pc)
+            }
+            CpuError::StackWrap { pc, sp } => {
+                write!(f, "stack wrapped at ${:02X} by ${:04X}", sp, pc)
+            }
+            CpuError::BusFault { pc, address } => {
+                write!(f, "bus fault at ${:04X} by ${:04X}", address, pc)
+            }
+        }
+    }
+}
+
+impl Error for CpuError {}
@@ -0,0 +1,320 @@
+use crate::cpu::w65c816::instruction::AddressingMode;
+use crate::cpu::w65c816::model::CPU65816;
+use crate::interfaces::bus::IBus;
+
+/** Addressing */
+impl<B: IBus> CPU65816<B> {
+    /*
+       Shared operand resolver used by every op code.
+       Consumes the operand bytes after the op code, leaves PC on the next instruction and
+       stores the 24-bit effective address in the address register.
+       Returns true when an indexed read pays the extra cycle : the index crosses a page
+       or the index registers are 16-bit.
+    */
+    pub fn resolve_address(&mut self, mode: &AddressingMode) -> bool {
+        // PC + 1 : skip the op code
+        self.pc = self.pc.wrapping_add(1);
+
+        // Direct page not aligned on a page costs one cycle
+        if self.is_direct_mode(mode) && self.direct_page & 0x00FF != 0 {
+            self.cycles += 1;
+        }
+
+        match mode {
+            AddressingMode::Implied | AddressingMode::Accumulator => false,
+            AddressingMode::Immediate => {
+                let wide = self.memory_16bit();
+                self.immediate_address(wide);
+                false
+            }
+            AddressingMode::ImmediateIndex => {
+                let wide = self.index_16bit();
+                self.immediate_address(wide);
+                false
+            }
+            AddressingMode::ImmediateByte => {
+                self.immediate_address(false);
+                false
+            }
+            AddressingMode::Direct => {
+                // D + offset in bank $00
+                let offset = self.fetch_operand_byte() as u16;
+                self.address = self.direct_address(offset) as u32;
+                false
+            }
+            AddressingMode::DirectX => {
+                let offset = self.fetch_operand_byte() as u16;
+                self.address = self.direct_address(offset.wrapping_add(self.x_register)) as u32;
+                false
+            }
+            AddressingMode::DirectY => {
+                let offset = self.fetch_operand_byte() as u16;
+                self.address = self.direct_address(offset.wrapping_add(self.y_register)) as u32;
+                false
+            }
+            AddressingMode::DirectIndirect => {
+                // Fetch 16-bit pointer from the direct page, data bank supplies bits 16-23
+                let offset = self.fetch_operand_byte() as u16;
+                let pointer = self.direct_address(offset);
+                self.address = self.data_bank_address(self.read_pointer(pointer));
+                false
+            }
+            AddressingMode::DirectIndirectX => {
+                let offset = self.fetch_operand_byte() as u16;
+                let pointer = self.direct_address(offset.wrapping_add(self.x_register));
+                self.address = self.data_bank_address(self.read_pointer(pointer));
+                false
+            }
+            AddressingMode::DirectIndirectY => {
+                let offset = self.fetch_operand_byte() as u16;
+                let pointer = self.direct_address(offset);
+                let base = self.data_bank_address(self.read_pointer(pointer));
+                self.index_address(base, self.y_register)
+            }
+            AddressingMode::DirectIndirectLong => {
+                // Fetch 24-bit pointer from the direct page
+                let offset = self.fetch_operand_byte() as u16;
+                let pointer = self.direct_address(offset);
+                self.address = self.read_long_pointer(pointer);
+                false
+            }
+            AddressingMode::DirectIndirectLongY => {
+                let offset = self.fetch_operand_byte() as u16;
+                let pointer = self.direct_address(offset);
+                let base = self.read_long_pointer(pointer);
+                self.address = base.wrapping_add(self.y_register as u32) & 0x00FF_FFFF;
+                false
+            }
+            AddressingMode::Absolute => {
+                let absolute = self.fetch_operand_word();
+                self.address = self.data_bank_address(absolute);
+                false
+            }
+            AddressingMode::AbsoluteX => {
+                let absolute = self.fetch_operand_word();
+                let base = self.data_bank_address(absolute);
+                self.index_address(base, self.x_register)
+            }
+            AddressingMode::AbsoluteY => {
+                let absolute = self.fetch_operand_word();
+                let base = self.data_bank_address(absolute);
+                self.index_address(base, self.y_register)
+            }
+            AddressingMode::AbsoluteLong => {
+                self.address = self.fetch_operand_long();
+                false
+            }
+            AddressingMode::AbsoluteLongX => {
+                let base = self.fetch_operand_long();
+                self.address = base.wrapping_add(self.x_register as u32) & 0x00FF_FFFF;
+                false
+            }
+            AddressingMode::AbsoluteIndirect => {
+                // Jump target read from bank $00
+                let pointer = self.fetch_operand_word();
+                self.address = self.read_pointer(pointer) as u32;
+                false
+            }
+            AddressingMode::AbsoluteIndirectX => {
+                // Jump target read from the program bank
+                let pointer = self.fetch_operand_word().wrapping_add(self.x_register);
+                let bank = (self.program_bank as u32) << 16;
+                let low = self.read_long(bank | pointer as u32) as u16;
+                let high = self.read_long(bank | pointer.wrapping_add(1) as u32) as u16;
+                self.address = (high << 8 | low) as u32;
+                false
+            }
+            AddressingMode::AbsoluteIndirectLong => {
+                let pointer = self.fetch_operand_word();
+                self.address = self.read_long_pointer(pointer);
+                false
+            }
+            AddressingMode::StackRelative => {
+                let offset = self.fetch_operand_byte() as u16;
+                self.address = self.sp.wrapping_add(offset) as u32;
+                false
+            }
+            AddressingMode::StackRelativeIndirectY => {
+                let offset = self.fetch_operand_byte() as u16;
+                let pointer = self.sp.wrapping_add(offset);
+                let base = self.data_bank_address(self.read_pointer(pointer));
+                self.address = base.wrapping_add(self.y_register as u32) & 0x00FF_FFFF;
+                false
+            }
+            AddressingMode::Relative => {
+                // Offset is relative to the next instruction
+                let offset = self.fetch_operand_byte() as i8 as u16;
+                let target = self.pc.wrapping_add(offset);
+                self.address = target as u32;
+                self.pc & 0xFF00 != target & 0xFF00
+            }
+            AddressingMode::RelativeLong => {
+                let offset = self.fetch_operand_word();
+                self.address = self.pc.wrapping_add(offset) as u32;
+                false
+            }
+            AddressingMode::BlockMove => {
+                // Destination bank then source bank
+                let destination = self.fetch_operand_byte() as u16;
+                let source = self.fetch_operand_byte() as u16;
+                self.data = destination << 8 | source;
+                false
+            }
+        }
+    }
+
+    /*
+       Operand for read instructions (LDA, ADC, CMP ...).
+       Loads 8 or 16 bits into the data register, charging the indexed read cycle.
+    */
+    pub fn fetch_operand(&mut self, mode: &AddressingMode, wide: bool) {
+        if self.fetch_modify_operand(mode, wide) {
+            // Page crossed or 16-bit index : add one cycle
+            self.cycles += 1;
+        }
+    }
+
+    /*
+       Operand for read-modify-write instructions (ASL, INC ...).
+       Accumulator mode reads C instead of memory.
+    */
+    pub fn fetch_modify_operand(&mut self, mode: &AddressingMode, wide: bool) -> bool {
+        let page_cross = self.resolve_address(mode);
+
+        if *mode == AddressingMode::Accumulator {
+            self.data = if wide {
+                self.accumulator
+            } else {
+                self.accumulator & 0x00FF
+            };
+        } else {
+            self.data = self.read_data(self.address, wide);
+        }
+
+        page_cross
+    }
+
+    /* Write back the result of a read-modify-write instruction */
+    pub fn store_modified(&mut self, mode: &AddressingMode, data: u16, wide: bool) {
+        if *mode == AddressingMode::Accumulator {
+            self.set_accumulator(data, wide);
+        } else {
+            self.write_data(self.address, data, wide);
+        }
+    }
+
+    /* Read 8 or 16 bits, low order byte first. The second byte costs one cycle. */
+    pub fn read_data(&mut self, address: u32, wide: bool) -> u16 {
+        let low = self.read_long(address) as u16;
+        if !wide {
+            return low;
+        }
+
+        self.cycles += 1;
+        let high = self.read_long(address.wrapping_add(1) & 0x00FF_FFFF) as u16;
+        high << 8 | low
+    }
+
+    /* Write 8 or 16 bits, low order byte first. The second byte costs one cycle. */
+    pub fn write_data(&mut self, address: u32, data: u16, wide: bool) {
+        self.write_long(address, (data & 0x00FF) as u8);
+        if wide {
+            self.cycles += 1;
+            self.write_long(address.wrapping_add(1) & 0x00FF_FFFF, (data >> 8) as u8);
+        }
+    }
+
+    pub fn read_long(&self, address: u32) -> u8 {
+        self.bus.read_long(address & 0x00FF_FFFF)
+    }
+
+    pub fn write_long(&mut self, address: u32, data: u8) {
+        self.bus.write_long(address & 0x00FF_FFFF, data);
+    }
+
+    /* Read without side effects, used by tracing */
+    pub fn peek_long(&self, address: u32) -> u8 {
+        self.bus.peek_long(address & 0x00FF_FFFF)
+    }
+
+    /* Immediate operand : 1 or 2 bytes in the instruction stream */
+    fn immediate_address(&mut self, wide: bool) {
+        self.address = self.program_address(self.pc);
+        self.pc = self.pc.wrapping_add(if wide { 2 } else { 1 });
+    }
+
+    /* Fetch the byte at PC in the program bank. PC is left on the next byte. */
+    fn fetch_operand_byte(&mut self) -> u8 {
+        let data = self.read_long(self.program_address(self.pc));
+        self.pc = self.pc.wrapping_add(1);
+        data
+    }
+
+    /* Fetch two bytes, low order byte first */
+    fn fetch_operand_word(&mut self) -> u16 {
+        let low = self.fetch_operand_byte() as u16;
+        let high = self.fetch_operand_byte() as u16;
+        high << 8 | low
+    }
+
+    /* Fetch three bytes, low order byte first */
+    fn fetch_operand_long(&mut self) -> u32 {
+        let word = self.fetch_operand_word() as u32;
+        let bank = self.fetch_operand_byte() as u32;
+        bank << 16 | word
+    }
+
+    /* 16-bit pointer in bank $00 */
+    fn read_pointer(&self, pointer: u16) -> u16 {
+        let low = self.read_long(pointer as u32) as u16;
+        let high = self.read_long(pointer.wrapping_add(1) as u32) as u16;
+        high << 8 | low
+    }
+
+    /* 24-bit pointer in bank $00 */
+    fn read_long_pointer(&self, pointer: u16) -> u32 {
+        let word = self.read_pointer(pointer) as u32;
+        let bank = self.read_long(pointer.wrapping_add(2) as u32) as u32;
+        bank << 16 | word
+    }
+
+    /*
+       Direct page address in bank $00.
+       In emulation mode with the direct page on a page boundary, indexing wraps inside the page like the 6502 zero page.
+    */
+    fn direct_address(&self, offset: u16) -> u16 {
+        if self.emulation && self.direct_page & 0x00FF == 0 {
+            return self.direct_page | (offset & 0x00FF);
+        }
+        self.direct_page.wrapping_add(offset)
+    }
+
+    fn data_bank_address(&self, address: u16) -> u32 {
+        (self.data_bank as u32) << 16 | address as u32
+    }
+
+    pub fn program_address(&self, address: u16) -> u32 {
+        (self.program_bank as u32) << 16 | address as u32
+    }
+
+    fn index_address(&mut self, base: u32, index: u16) -> bool {
+        self.address = base.wrapping_add(index as u32) & 0x00FF_FFFF;
+
+        // Calculate Page Cross, 16-bit index registers always pay the cycle
+        base & 0xFFFF00 != self.address & 0xFFFF00 || self.index_16bit()
+    }
+
+    fn is_direct_mode(&self, mode: &AddressingMode) -> bool {
+        matches!(
+            mode,
+            AddressingMode::Direct
+                | AddressingMode::DirectX
+                | AddressingMode::DirectY
+                | AddressingMode::DirectIndirect
+                | AddressingMode::DirectIndirectX
+                | AddressingMode::DirectIndirectY
+                | AddressingMode::DirectIndirectLong
+                | AddressingMode::DirectIndirectLongY
+        )
+    }
+}
@@ -0,0 +1,453 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+use AddressingMode::*;
+
+/**
+ * Ref : https://www.westerndesigncenter.com/wdc/documentation/w65c816s.pdf
+ * 65C816 addressing modes. "Direct" is the relocatable zero page, based on the D register.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressingMode {
+    /* No operand : CLC, TAX, RTS */
+    Implied,
+    /* Operates on the accumulator : ASL A */
+    Accumulator,
+    /* One or two bytes following M : LDA #$44 */
+    Immediate,
+    /* One or two bytes following X : LDX #$44 */
+    ImmediateIndex,
+    /* Always one byte : REP #$30, COP $00 */
+    ImmediateByte,
+    /* D + $nn in bank $00 : LDA $44 */
+    Direct,
+    /* D + $nn + X : LDA $44,X */
+    DirectX,
+    /* D + $nn + Y : LDX $44,Y */
+    DirectY,
+    /* Address read from D + $nn in the data bank : LDA ($44) */
+    DirectIndirect,
+    /* Address read from D + $nn + X in the data bank : LDA ($44,X) */
+    DirectIndirectX,
+    /* Address read from D + $nn in the data bank, then + Y : LDA ($44),Y */
+    DirectIndirectY,
+    /* 24-bit address read from D + $nn : LDA [$44] */
+    DirectIndirectLong,
+    /* 24-bit address read from D + $nn, then + Y : LDA [$44],Y */
+    DirectIndirectLongY,
+    /* $nnnn in the data bank (program bank for JMP / JSR) : LDA $4400 */
+    Absolute,
+    /* $nnnn + X : LDA $4400,X */
+    AbsoluteX,
+    /* $nnnn + Y : LDA $4400,Y */
+    AbsoluteY,
+    /* $nnnnnn : LDA $7E4400 */
+    AbsoluteLong,
+    /* $nnnnnn + X : LDA $7E4400,X */
+    AbsoluteLongX,
+    /* Address read from $nnnn in bank $00 : JMP ($4400) */
+    AbsoluteIndirect,
+    /* Address read from $nnnn + X in the program bank : JMP ($4400,X) */
+    AbsoluteIndirectX,
+    /* 24-bit address read from $nnnn in bank $00 : JML [$4400] */
+    AbsoluteIndirectLong,
+    /* S + $nn in bank $00 : LDA $03,S */
+    StackRelative,
+    /* Address read from S + $nn in the data bank, then + Y : LDA ($03,S),Y */
+    StackRelativeIndirectY,
+    /* Signed 8-bit offset from the next instruction : BNE $nn */
+    Relative,
+    /* Signed 16-bit offset from the next instruction : BRL $nnnn */
+    RelativeLong,
+    /* Destination bank then source bank : MVN $7E,$7F */
+    BlockMove,
+}
+
+/*
+   Length and cycles are the 8-bit (M = 1, X = 1) figures. The operation adds the
+   cycles for 16-bit data, a direct page not aligned on a page and page crossing.
+*/
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub code: u8,
+    pub name: &'static str,
+    pub length: u8,
+    pub cycle: u8,
+    pub mode: AddressingMode,
+}
+
+impl Instruction {
+    pub fn new(code: u8, name: &'static str, length: u8, cycle: u8, mode: AddressingMode) -> Self {
+        Instruction {
+            code,
+            name,
+            length,
+            cycle,
+            mode,
+        }
+    }
+}
+
+lazy_static! {
+    /**
+     * Ref : http://6502.org/tutorials/65c816opcodes.html
+     * All 256 op codes are defined on the 65C816.
+     */
+    pub static ref CPU_65C816_OPERATION_CODES: Vec<Instruction> = vec![
+            // ADC (ADd with Carry)
+            Instruction::new(0x61, "ADC", 2, 6, DirectIndirectX),
+            Instruction::new(0x63, "ADC", 2, 4, StackRelative),
+            Instruction::new(0x65, "ADC", 2, 3, Direct),
+            Instruction::new(0x67, "ADC", 2, 6, DirectIndirectLong),
+            Instruction::new(0x69, "ADC", 2, 2, Immediate),
+            Instruction::new(0x6D, "ADC", 3, 4, Absolute),
+            Instruction::new(0x6F, "ADC", 4, 5, AbsoluteLong),
+            Instruction::new(0x71, "ADC", 2, 5, DirectIndirectY),
+            Instruction::new(0x72, "ADC", 2, 5, DirectIndirect),
+            Instruction::new(0x73, "ADC", 2, 7, StackRelativeIndirectY),
+            Instruction::new(0x75, "ADC", 2, 4, DirectX),
+            Instruction::new(0x77, "ADC", 2, 6, DirectIndirectLongY),
+            Instruction::new(0x79, "ADC", 3, 4, AbsoluteY),
+            Instruction::new(0x7D, "ADC", 3, 4, AbsoluteX),
+            Instruction::new(0x7F, "ADC", 4, 5, AbsoluteLongX),
+            // AND (bitwise AND with accumulator)
+            Instruction::new(0x21, "AND", 2, 6, DirectIndirectX),
+            Instruction::new(0x23, "AND", 2, 4, StackRelative),
+            Instruction::new(0x25, "AND", 2, 3, Direct),
+            Instruction::new(0x27, "AND", 2, 6, DirectIndirectLong),
+            Instruction::new(0x29, "AND", 2, 2, Immediate),
+            Instruction::new(0x2D, "AND", 3, 4, Absolute),
+            Instruction::new(0x2F, "AND", 4, 5, AbsoluteLong),
+            Instruction::new(0x31, "AND", 2, 5, DirectIndirectY),
+            Instruction::new(0x32, "AND", 2, 5, DirectIndirect),
+            Instruction::new(0x33, "AND", 2, 7, StackRelativeIndirectY),
+            Instruction::new(0x35, "AND", 2, 4, DirectX),
+            Instruction::new(0x37, "AND", 2, 6, DirectIndirectLongY),
+            Instruction::new(0x39, "AND", 3, 4, AbsoluteY),
+            Instruction::new(0x3D, "AND", 3, 4, AbsoluteX),
+            Instruction::new(0x3F, "AND", 4, 5, AbsoluteLongX),
+            // ASL (Arithmetic Shift Left)
+            Instruction::new(0x06, "ASL", 2, 5, Direct),
+            Instruction::new(0x0A, "ASL", 1, 2, Accumulator),
+            Instruction::new(0x0E, "ASL", 3, 6, Absolute),
+            Instruction::new(0x16, "ASL", 2, 6, DirectX),
+            Instruction::new(0x1E, "ASL", 3, 7, AbsoluteX),
+            // BCC (Branch on Carry Clear)
+            Instruction::new(0x90, "BCC", 2, 2, Relative),
+            // BCS (Branch on Carry Set)
+            Instruction::new(0xB0, "BCS", 2, 2, Relative),
+            // BEQ (Branch on EQual)
+            Instruction::new(0xF0, "BEQ", 2, 2, Relative),
+            // BIT (test BITs)
+            Instruction::new(0x24, "BIT", 2, 3, Direct),
+            Instruction::new(0x2C, "BIT", 3, 4, Absolute),
+            Instruction::new(0x34, "BIT", 2, 4, DirectX),
+            Instruction::new(0x3C, "BIT", 3, 4, AbsoluteX),
+            Instruction::new(0x89, "BIT", 2, 2, Immediate),
+            // BMI (Branch on MInus)
+            Instruction::new(0x30, "BMI", 2, 2, Relative),
+            // BNE (Branch on Not Equal)
+            Instruction::new(0xD0, "BNE", 2, 2, Relative),
+            // BPL (Branch on PLus)
+            Instruction::new(0x10, "BPL", 2, 2, Relative),
+            // BRA (BRanch Always)
+            Instruction::new(0x80, "BRA", 2, 2, Relative),
+            // BRK (BReaK)
+            Instruction::new(0x00, "BRK", 2, 7, ImmediateByte),
+            // BRL (BRanch Long)
+            Instruction::new(0x82, "BRL", 3, 4, RelativeLong),
+            // BVC (Branch on oVerflow Clear)
+            Instruction::new(0x50, "BVC", 2, 2, Relative),
+            // BVS (Branch on oVerflow Set)
+            Instruction::new(0x70, "BVS", 2, 2, Relative),
+            // CLC (CLear Carry)
+            Instruction::new(0x18, "CLC", 1, 2, Implied),
+            // CLD (CLear Decimal)
+            Instruction::new(0xD8, "CLD", 1, 2, Implied),
+            // CLI (CLear Interrupt disable)
+            Instruction::new(0x58, "CLI", 1, 2, Implied),
+            // CLV (CLear oVerflow)
+            Instruction::new(0xB8, "CLV", 1, 2, Implied),
+            // CMP (CoMPare accumulator)
+            Instruction::new(0xC1, "CMP", 2, 6, DirectIndirectX),
+            Instruction::new(0xC3, "CMP", 2, 4, StackRelative),
+            Instruction::new(0xC5, "CMP", 2, 3, Direct),
+            Instruction::new(0xC7, "CMP", 2, 6, DirectIndirectLong),
+            Instruction::new(0xC9, "CMP", 2, 2, Immediate),
+            Instruction::new(0xCD, "CMP", 3, 4, Absolute),
+            Instruction::new(0xCF, "CMP", 4, 5, AbsoluteLong),
+            Instruction::new(0xD1, "CMP", 2, 5, DirectIndirectY),
+            Instruction::new(0xD2, "CMP", 2, 5, DirectIndirect),
+            Instruction::new(0xD3, "CMP", 2, 7, StackRelativeIndirectY),
+            Instruction::new(0xD5, "CMP", 2, 4, DirectX),
+            Instruction::new(0xD7, "CMP", 2, 6, DirectIndirectLongY),
+            Instruction::new(0xD9, "CMP", 3, 4, AbsoluteY),
+            Instruction::new(0xDD, "CMP", 3, 4, AbsoluteX),
+            Instruction::new(0xDF, "CMP", 4, 5, AbsoluteLongX),
+            // COP (COProcessor interrupt)
+            Instruction::new(0x02, "COP", 2, 7, ImmediateByte),
+            // CPX (ComPare X register)
+            Instruction::new(0xE0, "CPX", 2, 2, ImmediateIndex),
+            Instruction::new(0xE4, "CPX", 2, 3, Direct),
+            Instruction::new(0xEC, "CPX", 3, 4, Absolute),
+            // CPY (ComPare Y register)
+            Instruction::new(0xC0, "CPY", 2, 2, ImmediateIndex),
+            Instruction::new(0xC4, "CPY", 2, 3, Direct),
+            Instruction::new(0xCC, "CPY", 3, 4, Absolute),
+            // DEC (DECrement)
+            Instruction::new(0x3A, "DEC", 1, 2, Accumulator),
+            Instruction::new(0xC6, "DEC", 2, 5, Direct),
+            Instruction::new(0xCE, "DEC", 3, 6, Absolute),
+            Instruction::new(0xD6, "DEC", 2, 6, DirectX),
+            Instruction::new(0xDE, "DEC", 3, 7, AbsoluteX),
+            // DEX (DEcrement X)
+            Instruction::new(0xCA, "DEX", 1, 2, Implied),
+            // DEY (DEcrement Y)
+            Instruction::new(0x88, "DEY", 1, 2, Implied),
+            // EOR (bitwise Exclusive OR)
+            Instruction::new(0x41, "EOR", 2, 6, DirectIndirectX),
+            Instruction::new(0x43, "EOR", 2, 4, StackRelative),
+            Instruction::new(0x45, "EOR", 2, 3, Direct),
+            Instruction::new(0x47, "EOR", 2, 6, DirectIndirectLong),
+            Instruction::new(0x49, "EOR", 2, 2, Immediate),
+            Instruction::new(0x4D, "EOR", 3, 4, Absolute),
+            Instruction::new(0x4F, "EOR", 4, 5, AbsoluteLong),
+            Instruction::new(0x51, "EOR", 2, 5, DirectIndirectY),
+            Instruction::new(0x52, "EOR", 2, 5, DirectIndirect),
+            Instruction::new(0x53, "EOR", 2, 7, StackRelativeIndirectY),
+            Instruction::new(0x55, "EOR", 2, 4, DirectX),
+            Instruction::new(0x57, "EOR", 2, 6, DirectIndirectLongY),
+            Instruction::new(0x59, "EOR", 3, 4, AbsoluteY),
+            Instruction::new(0x5D, "EOR", 3, 4, AbsoluteX),
+            Instruction::new(0x5F, "EOR", 4, 5, AbsoluteLongX),
+            // INC (INCrement)
+            Instruction::new(0x1A, "INC", 1, 2, Accumulator),
+            Instruction::new(0xE6, "INC", 2, 5, Direct),
+            Instruction::new(0xEE, "INC", 3, 6, Absolute),
+            Instruction::new(0xF6, "INC", 2, 6, DirectX),
+            Instruction::new(0xFE, "INC", 3, 7, AbsoluteX),
+            // INX (INcrement X)
+            Instruction::new(0xE8, "INX", 1, 2, Implied),
+            // INY (INcrement Y)
+            Instruction::new(0xC8, "INY", 1, 2, Implied),
+            // JML (JuMp Long)
+            Instruction::new(0x5C, "JML", 4, 4, AbsoluteLong),
+            Instruction::new(0xDC, "JML", 3, 6, AbsoluteIndirectLong),
+            // JMP (JuMP)
+            Instruction::new(0x4C, "JMP", 3, 3, Absolute),
+            Instruction::new(0x6C, "JMP", 3, 5, AbsoluteIndirect),
+            Instruction::new(0x7C, "JMP", 3, 6, AbsoluteIndirectX),
+            // JSL (Jump to Subroutine Long)
+            Instruction::new(0x22, "JSL", 4, 8, AbsoluteLong),
+            // JSR (Jump to SubRoutine)
+            Instruction::new(0x20, "JSR", 3, 6, Absolute),
+            Instruction::new(0xFC, "JSR", 3, 8, AbsoluteIndirectX),
+            // LDA (LoaD Accumulator)
+            Instruction::new(0xA1, "LDA", 2, 6, DirectIndirectX),
+            Instruction::new(0xA3, "LDA", 2, 4, StackRelative),
+            Instruction::new(0xA5, "LDA", 2, 3, Direct),
+            Instruction::new(0xA7, "LDA", 2, 6, DirectIndirectLong),
+            Instruction::new(0xA9, "LDA", 2, 2, Immediate),
+            Instruction::new(0xAD, "LDA", 3, 4, Absolute),
+            Instruction::new(0xAF, "LDA", 4, 5, AbsoluteLong),
+            Instruction::new(0xB1, "LDA", 2, 5, DirectIndirectY),
+            Instruction::new(0xB2, "LDA", 2, 5, DirectIndirect),
+            Instruction::new(0xB3, "LDA", 2, 7, StackRelativeIndirectY),
+            Instruction::new(0xB5, "LDA", 2, 4, DirectX),
+            Instruction::new(0xB7, "LDA", 2, 6, DirectIndirectLongY),
+            Instruction::new(0xB9, "LDA", 3, 4, AbsoluteY),
+            Instruction::new(0xBD, "LDA", 3, 4, AbsoluteX),
+            Instruction::new(0xBF, "LDA", 4, 5, AbsoluteLongX),
+            // LDX (LoaD X register)
+            Instruction::new(0xA2, "LDX", 2, 2, ImmediateIndex),
+            Instruction::new(0xA6, "LDX", 2, 3, Direct),
+            Instruction::new(0xAE, "LDX", 3, 4, Absolute),
+            Instruction::new(0xB6, "LDX", 2, 4, DirectY),
+            Instruction::new(0xBE, "LDX", 3, 4, AbsoluteY),
+            // LDY (LoaD Y register)
+            Instruction::new(0xA0, "LDY", 2, 2, ImmediateIndex),
+            Instruction::new(0xA4, "LDY", 2, 3, Direct),
+            Instruction::new(0xAC, "LDY", 3, 4, Absolute),
+            Instruction::new(0xB4, "LDY", 2, 4, DirectX),
+            Instruction::new(0xBC, "LDY", 3, 4, AbsoluteX),
+            // LSR (Logical Shift Right)
+            Instruction::new(0x46, "LSR", 2, 5, Direct),
+            Instruction::new(0x4A, "LSR", 1, 2, Accumulator),
+            Instruction::new(0x4E, "LSR", 3, 6, Absolute),
+            Instruction::new(0x56, "LSR", 2, 6, DirectX),
+            Instruction::new(0x5E, "LSR", 3, 7, AbsoluteX),
+            // MVN (MoVe block Negative (incrementing))
+            Instruction::new(0x54, "MVN", 3, 7, BlockMove),
+            // MVP (MoVe block Positive (decrementing))
+            Instruction::new(0x44, "MVP", 3, 7, BlockMove),
+            // NOP (No OPeration)
+            Instruction::new(0xEA, "NOP", 1, 2, Implied),
+            // ORA (bitwise OR with Accumulator)
+            Instruction::new(0x01, "ORA", 2, 6, DirectIndirectX),
+            Instruction::new(0x03, "ORA", 2, 4, StackRelative),
+            Instruction::new(0x05, "ORA", 2, 3, Direct),
+            Instruction::new(0x07, "ORA", 2, 6, DirectIndirectLong),
+            Instruction::new(0x09, "ORA", 2, 2, Immediate),
+            Instruction::new(0x0D, "ORA", 3, 4, Absolute),
+            Instruction::new(0x0F, "ORA", 4, 5, AbsoluteLong),
+            Instruction::new(0x11, "ORA", 2, 5, DirectIndirectY),
+            Instruction::new(0x12, "ORA", 2, 5, DirectIndirect),
+            Instruction::new(0x13, "ORA", 2, 7, StackRelativeIndirectY),
+            Instruction::new(0x15, "ORA", 2, 4, DirectX),
+            Instruction::new(0x17, "ORA", 2, 6, DirectIndirectLongY),
+            Instruction::new(0x19, "ORA", 3, 4, AbsoluteY),
+            Instruction::new(0x1D, "ORA", 3, 4, AbsoluteX),
+            Instruction::new(0x1F, "ORA", 4, 5, AbsoluteLongX),
+            // PEA (Push Effective Absolute address)
+            Instruction::new(0xF4, "PEA", 3, 5, Absolute),
+            // PEI (Push Effective Indirect address)
+            Instruction::new(0xD4, "PEI", 2, 6, DirectIndirect),
+            // PER (Push Effective Relative address)
+            Instruction::new(0x62, "PER", 3, 6, RelativeLong),
+            // PHA (PusH Accumulator)
+            Instruction::new(0x48, "PHA", 1, 3, Implied),
+            // PHB (PusH data Bank register)
+            Instruction::new(0x8B, "PHB", 1, 3, Implied),
+            // PHD (PusH Direct page register)
+            Instruction::new(0x0B, "PHD", 1, 4, Implied),
+            // PHK (PusH program banK register)
+            Instruction::new(0x4B, "PHK", 1, 3, Implied),
+            // PHP (PusH Processor status)
+            Instruction::new(0x08, "PHP", 1, 3, Implied),
+            // PHX (PusH X register)
+            Instruction::new(0xDA, "PHX", 1, 3, Implied),
+            // PHY (PusH Y register)
+            Instruction::new(0x5A, "PHY", 1, 3, Implied),
+            // PLA (PuLl Accumulator)
+            Instruction::new(0x68, "PLA", 1, 4, Implied),
+            // PLB (PuLl data Bank register)
+            Instruction::new(0xAB, "PLB", 1, 4, Implied),
+            // PLD (PuLl Direct page register)
+            Instruction::new(0x2B, "PLD", 1, 5, Implied),
+            // PLP (PuLl Processor status)
+            Instruction::new(0x28, "PLP", 1, 4, Implied),
+            // PLX (PuLl X register)
+            Instruction::new(0xFA, "PLX", 1, 4, Implied),
+            // PLY (PuLl Y register)
+            Instruction::new(0x7A, "PLY", 1, 4, Implied),
+            // REP (REset Processor status bits)
+            Instruction::new(0xC2, "REP", 2, 3, ImmediateByte),
+            // ROL (ROtate Left)
+            Instruction::new(0x26, "ROL", 2, 5, Direct),
+            Instruction::new(0x2A, "ROL", 1, 2, Accumulator),
+            Instruction::new(0x2E, "ROL", 3, 6, Absolute),
+            Instruction::new(0x36, "ROL", 2, 6, DirectX),
+            Instruction::new(0x3E, "ROL", 3, 7, AbsoluteX),
+            // ROR (ROtate Right)
+            Instruction::new(0x66, "ROR", 2, 5, Direct),
+            Instruction::new(0x6A, "ROR", 1, 2, Accumulator),
+            Instruction::new(0x6E, "ROR", 3, 6, Absolute),
+            Instruction::new(0x76, "ROR", 2, 6, DirectX),
+            Instruction::new(0x7E, "ROR", 3, 7, AbsoluteX),
+            // RTI (ReTurn from Interrupt)
+            Instruction::new(0x40, "RTI", 1, 6, Implied),
+            // RTL (ReTurn from subroutine Long)
+            Instruction::new(0x6B, "RTL", 1, 6, Implied),
+            // RTS (ReTurn from Subroutine)
+            Instruction::new(0x60, "RTS", 1, 6, Implied),
+            // SBC (SuBtract with Carry)
+            Instruction::new(0xE1, "SBC", 2, 6, DirectIndirectX),
+            Instruction::new(0xE3, "SBC", 2, 4, StackRelative),
+            Instruction::new(0xE5, "SBC", 2, 3, Direct),
+            Instruction::new(0xE7, "SBC", 2, 6, DirectIndirectLong),
+            Instruction::new(0xE9, "SBC", 2, 2, Immediate),
+            Instruction::new(0xED, "SBC", 3, 4, Absolute),
+            Instruction::new(0xEF, "SBC", 4, 5, AbsoluteLong),
+            Instruction::new(0xF1, "SBC", 2, 5, DirectIndirectY),
+            Instruction::new(0xF2, "SBC", 2, 5, DirectIndirect),
+            Instruction::new(0xF3, "SBC", 2, 7, StackRelativeIndirectY),
+            Instruction::new(0xF5, "SBC", 2, 4, DirectX),
+            Instruction::new(0xF7, "SBC", 2, 6, DirectIndirectLongY),
+            Instruction::new(0xF9, "SBC", 3, 4, AbsoluteY),
+            Instruction::new(0xFD, "SBC", 3, 4, AbsoluteX),
+            Instruction::new(0xFF, "SBC", 4, 5, AbsoluteLongX),
+            // SEC (SEt Carry)
+            Instruction::new(0x38, "SEC", 1, 2, Implied),
+            // SED (SEt Decimal)
+            Instruction::new(0xF8, "SED", 1, 2, Implied),
+            // SEI (SEt Interrupt disable)
+            Instruction::new(0x78, "SEI", 1, 2, Implied),
+            // SEP (SEt Processor status bits)
+            Instruction::new(0xE2, "SEP", 2, 3, ImmediateByte),
+            // STA (STore Accumulator)
+            Instruction::new(0x81, "STA", 2, 6, DirectIndirectX),
+            Instruction::new(0x83, "STA", 2, 4, StackRelative),
+            Instruction::new(0x85, "STA", 2, 3, Direct),
+            Instruction::new(0x87, "STA", 2, 6, DirectIndirectLong),
+            Instruction::new(0x8D, "STA", 3, 4, Absolute),
+            Instruction::new(0x8F, "STA", 4, 5, AbsoluteLong),
+            Instruction::new(0x91, "STA", 2, 6, DirectIndirectY),
+            Instruction::new(0x92, "STA", 2, 5, DirectIndirect),
+            Instruction::new(0x93, "STA", 2, 7, StackRelativeIndirectY),
+            Instruction::new(0x95, "STA", 2, 4, DirectX),
+            Instruction::new(0x97, "STA", 2, 6, DirectIndirectLongY),
+            Instruction::new(0x99, "STA", 3, 5, AbsoluteY),
+            Instruction::new(0x9D, "STA", 3, 5, AbsoluteX),
+            Instruction::new(0x9F, "STA", 4, 5, AbsoluteLongX),
+            // STP (SToP the processor)
+            Instruction::new(0xDB, "STP", 1, 3, Implied),
+            // STX (STore X register)
+            Instruction::new(0x86, "STX", 2, 3, Direct),
+            Instruction::new(0x8E, "STX", 3, 4, Absolute),
+            Instruction::new(0x96, "STX", 2, 4, DirectY),
+            // STY (STore Y register)
+            Instruction::new(0x84, "STY", 2, 3, Direct),
+            Instruction::new(0x8C, "STY", 3, 4, Absolute),
+            Instruction::new(0x94, "STY", 2, 4, DirectX),
+            // STZ (STore Zero)
+            Instruction::new(0x64, "STZ", 2, 3, Direct),
+            Instruction::new(0x74, "STZ", 2, 4, DirectX),
+            Instruction::new(0x9C, "STZ", 3, 4, Absolute),
+            Instruction::new(0x9E, "STZ", 3, 5, AbsoluteX),
+            // TAX (Transfer A to X)
+            Instruction::new(0xAA, "TAX", 1, 2, Implied),
+            // TAY (Transfer A to Y)
+            Instruction::new(0xA8, "TAY", 1, 2, Implied),
+            // TCD (Transfer C to Direct page)
+            Instruction::new(0x5B, "TCD", 1, 2, Implied),
+            // TCS (Transfer C to Stack pointer)
+            Instruction::new(0x1B, "TCS", 1, 2, Implied),
+            // TDC (Transfer Direct page to C)
+            Instruction::new(0x7B, "TDC", 1, 2, Implied),
+            // TRB (Test and Reset Bits)
+            Instruction::new(0x14, "TRB", 2, 5, Direct),
+            Instruction::new(0x1C, "TRB", 3, 6, Absolute),
+            // TSB (Test and Set Bits)
+            Instruction::new(0x04, "TSB", 2, 5, Direct),
+            Instruction::new(0x0C, "TSB", 3, 6, Absolute),
+            // TSC (Transfer Stack pointer to C)
+            Instruction::new(0x3B, "TSC", 1, 2, Implied),
+            // TSX (Transfer Stack pointer to X)
+            Instruction::new(0xBA, "TSX", 1, 2, Implied),
+            // TXA (Transfer X to A)
+            Instruction::new(0x8A, "TXA", 1, 2, Implied),
+            // TXS (Transfer X to Stack pointer)
+            Instruction::new(0x9A, "TXS", 1, 2, Implied),
+            // TXY (Transfer X to Y)
+            Instruction::new(0x9B, "TXY", 1, 2, Implied),
+            // TYA (Transfer Y to A)
+            Instruction::new(0x98, "TYA", 1, 2, Implied),
+            // TYX (Transfer Y to X)
+            Instruction::new(0xBB, "TYX", 1, 2, Implied),
+            // WAI (WAit for Interrupt)
+            Instruction::new(0xCB, "WAI", 1, 3, Implied),
+            // WDM (reserved for future expansion)
+            Instruction::new(0x42, "WDM", 2, 2, ImmediateByte),
+            // XBA (eXchange B and A)
+            Instruction::new(0xEB, "XBA", 1, 3, Implied),
+            // XCE (eXchange Carry and Emulation)
+            Instruction::new(0xFB, "XCE", 1, 2, Implied),
+        ];
+    pub static ref CPU_65C816_OPERATION_CODES_MAP: HashMap<u8, &'static Instruction> = {
+        let mut map = HashMap::new();
+        for cpu_65c816_operation_code in &*CPU_65C816_OPERATION_CODES {
+            map.insert(cpu_65c816_operation_code.code, cpu_65c816_operation_code);
+        }
+        map
+    };
+}
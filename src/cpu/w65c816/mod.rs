@@ -0,0 +1,8 @@
+pub mod addressing;
+pub mod instruction;
+pub mod model;
+pub mod operations;
+pub mod usecase;
+
+#[cfg(test)]
+mod mod_test;
@@ -0,0 +1,429 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::bus::model::LongBus;
+    use crate::cpu::model::{CpuError, ErrorPolicy, State};
+    use crate::cpu::w65c816::model::CPU65816;
+    use crate::interfaces::bus::IBus;
+    use crate::interfaces::cpu::ICPU;
+
+    // Load a program at the given 24-bit address and point K:PC at it
+    fn load_program(cpu: &mut CPU65816, address: u32, program: &[u8]) {
+        for (offset, byte) in program.iter().enumerate() {
+            cpu.write_long(address + offset as u32, *byte);
+        }
+        cpu.program_bank = (address >> 16) as u8;
+        cpu.pc = (address & 0xFFFF) as u16;
+    }
+
+    // Fetch, decode and execute exactly one instruction
+    fn execute_one(cpu: &mut CPU65816) {
+        cpu.fetch();
+        cpu.decode();
+        cpu.execute();
+    }
+
+    // CLC ; XCE ; REP #$30 : native mode with 16-bit registers
+    fn enter_native_16bit(cpu: &mut CPU65816) {
+        load_program(cpu, 0x0000, &[0x18, 0xFB, 0xC2, 0x30]);
+        for _ in 0..3 {
+            execute_one(cpu);
+        }
+        cpu.cycles = 0;
+    }
+
+    #[test]
+    fn test_reset_enters_emulation_mode() {
+        let mut cpu = CPU65816::new();
+        cpu.write_long(0xFFFC, 0x00);
+        cpu.write_long(0xFFFD, 0x80);
+        cpu.emulation = false;
+        cpu.direct_page = 0x1234;
+        cpu.data_bank = 0x7E;
+        cpu.x_register = 0x1234;
+
+        cpu.reset();
+
+        assert!(cpu.emulation);
+        assert_eq!(cpu.pc, 0x8000);
+        assert_eq!(cpu.direct_page, 0x0000);
+        assert_eq!(cpu.data_bank, 0x00);
+        assert_eq!(cpu.sp & 0xFF00, 0x0100);
+        assert_eq!(cpu.x_register, 0x0034);
+        assert!(!cpu.memory_16bit());
+        assert!(!cpu.index_16bit());
+    }
+
+    #[test]
+    fn test_xce_rep_sixteen_bit_arithmetic() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+        assert!(!cpu.emulation);
+        assert_eq!(cpu.status.get_carry(), 1);
+        assert!(cpu.memory_16bit());
+        assert!(cpu.index_16bit());
+
+        // LDA #$1234 ; ADC #$1111
+        load_program(&mut cpu, 0x0010, &[0xA9, 0x34, 0x12, 0x69, 0x11, 0x11]);
+        cpu.status.unset_carry();
+        execute_one(&mut cpu);
+        execute_one(&mut cpu);
+
+        assert_eq!(cpu.accumulator, 0x2345);
+        assert_eq!(cpu.pc, 0x0016);
+        // Each 16-bit immediate costs one more cycle
+        assert_eq!(cpu.cycles, 3 + 3);
+    }
+
+    #[test]
+    fn test_eight_bit_accumulator_keeps_b() {
+        let mut cpu = CPU65816::new();
+        cpu.accumulator = 0xAB00;
+        // LDA #$FF ; XBA
+        load_program(&mut cpu, 0x0000, &[0xA9, 0xFF, 0xEB]);
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator, 0xABFF);
+
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator, 0xFFAB);
+        assert_eq!(cpu.status.get_negative(), 1);
+    }
+
+    #[test]
+    fn test_long_addressing() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+        cpu.write_long(0x123456, 0xCD);
+        cpu.write_long(0x123457, 0xAB);
+        cpu.x_register = 0x0010;
+
+        // LDA $123456 ; STA $02FFFF,X
+        load_program(
+            &mut cpu,
+            0x0000,
+            &[0xAF, 0x56, 0x34, 0x12, 0x9F, 0xFF, 0xFF, 0x02],
+        );
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator, 0xABCD);
+
+        execute_one(&mut cpu);
+        // Long indexing carries into the next bank
+        assert_eq!(cpu.read_long(0x03000F), 0xCD);
+        assert_eq!(cpu.read_long(0x030010), 0xAB);
+    }
+
+    #[test]
+    fn test_data_bank_absolute() {
+        let mut cpu = CPU65816::new();
+        cpu.data_bank = 0x05;
+        cpu.write_long(0x052000, 0x42);
+
+        // LDA $2000
+        load_program(&mut cpu, 0x0000, &[0xAD, 0x00, 0x20]);
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator & 0x00FF, 0x42);
+    }
+
+    #[test]
+    fn test_jsl_rtl() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+        cpu.sp = 0x1FFF;
+
+        // JSL $018000
+        load_program(&mut cpu, 0x008000, &[0x22, 0x00, 0x80, 0x01]);
+        // RTL
+        cpu.write_long(0x018000, 0x6B);
+
+        execute_one(&mut cpu);
+        assert_eq!(cpu.program_bank, 0x01);
+        assert_eq!(cpu.pc, 0x8000);
+        assert_eq!(cpu.sp, 0x1FFC);
+        assert_eq!(cpu.read_long(0x001FFF), 0x00);
+        assert_eq!(cpu.read_long(0x001FFE), 0x80);
+        assert_eq!(cpu.read_long(0x001FFD), 0x03);
+
+        execute_one(&mut cpu);
+        assert_eq!(cpu.program_bank, 0x00);
+        assert_eq!(cpu.pc, 0x8004);
+        assert_eq!(cpu.sp, 0x1FFF);
+    }
+
+    #[test]
+    fn test_mvn_block_move() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+        for offset in 0..3 {
+            cpu.write_long(0x011000 + offset, 0xA0 + offset as u8);
+        }
+        cpu.accumulator = 0x0002;
+        cpu.x_register = 0x1000;
+        cpu.y_register = 0x2000;
+
+        // MVN $02,$01
+        load_program(&mut cpu, 0x0000, &[0x54, 0x02, 0x01]);
+        while cpu.pc == 0x0000 {
+            execute_one(&mut cpu);
+        }
+
+        assert_eq!(cpu.read_long(0x022000), 0xA0);
+        assert_eq!(cpu.read_long(0x022001), 0xA1);
+        assert_eq!(cpu.read_long(0x022002), 0xA2);
+        assert_eq!(cpu.accumulator, 0xFFFF);
+        assert_eq!(cpu.x_register, 0x1003);
+        assert_eq!(cpu.y_register, 0x2003);
+        assert_eq!(cpu.data_bank, 0x02);
+        assert_eq!(cpu.cycles, 3 * 7);
+    }
+
+    #[test]
+    fn test_mvp_block_move() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+        cpu.write_long(0x001000, 0x11);
+        cpu.write_long(0x001001, 0x22);
+        cpu.accumulator = 0x0001;
+        cpu.x_register = 0x1001;
+        cpu.y_register = 0x1002;
+
+        // MVP $00,$00 : overlapping move upward
+        load_program(&mut cpu, 0x0000, &[0x44, 0x00, 0x00]);
+        while cpu.pc == 0x0000 {
+            execute_one(&mut cpu);
+        }
+
+        assert_eq!(cpu.read_long(0x001001), 0x11);
+        assert_eq!(cpu.read_long(0x001002), 0x22);
+        assert_eq!(cpu.x_register, 0x0FFF);
+    }
+
+    #[test]
+    fn test_pea_pei_per() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+        cpu.sp = 0x1FFF;
+        cpu.write_long(0x000020, 0x78);
+        cpu.write_long(0x000021, 0x56);
+
+        // PEA $1234 ; PEI ($20) ; PER $0010
+        load_program(
+            &mut cpu,
+            0x0100,
+            &[0xF4, 0x34, 0x12, 0xD4, 0x20, 0x62, 0x10, 0x00],
+        );
+        execute_one(&mut cpu);
+        execute_one(&mut cpu);
+        execute_one(&mut cpu);
+
+        assert_eq!(cpu.sp, 0x1FF9);
+        assert_eq!(cpu.pull_word(), 0x0118);
+        assert_eq!(cpu.pull_word(), 0x5678);
+        assert_eq!(cpu.pull_word(), 0x1234);
+    }
+
+    #[test]
+    fn test_direct_page_penalty() {
+        let mut cpu = CPU65816::new();
+        cpu.write_long(0x000010, 0x11);
+        cpu.write_long(0x000111, 0x22);
+
+        // LDA $10 ; LDA $10
+        load_program(&mut cpu, 0x0000, &[0xA5, 0x10, 0xA5, 0x10]);
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator & 0x00FF, 0x11);
+        assert_eq!(cpu.cycles, 3);
+
+        // Direct page not aligned on a page : one more cycle
+        cpu.direct_page = 0x0101;
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator & 0x00FF, 0x22);
+        assert_eq!(cpu.cycles, 3 + 4);
+    }
+
+    #[test]
+    fn test_emulation_direct_page_wraps() {
+        let mut cpu = CPU65816::new();
+        cpu.direct_page = 0x0200;
+        cpu.x_register = 0x20;
+        cpu.write_long(0x000210, 0x5A);
+
+        // LDA $F0,X
+        load_program(&mut cpu, 0x0000, &[0xB5, 0xF0]);
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator & 0x00FF, 0x5A);
+    }
+
+    #[test]
+    fn test_decimal_sixteen_bit() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+
+        // SED ; LDA #$1999 ; ADC #$0001 ; SEC ; SBC #$0001
+        load_program(
+            &mut cpu,
+            0x0010,
+            &[
+                0xF8, 0xA9, 0x99, 0x19, 0x69, 0x01, 0x00, 0x38, 0xE9, 0x01, 0x00,
+            ],
+        );
+        cpu.status.unset_carry();
+        execute_one(&mut cpu);
+        execute_one(&mut cpu);
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator, 0x2000);
+        assert_eq!(cpu.status.get_carry(), 0);
+
+        execute_one(&mut cpu);
+        execute_one(&mut cpu);
+        assert_eq!(cpu.accumulator, 0x1999);
+        assert_eq!(cpu.status.get_carry(), 1);
+    }
+
+    #[test]
+    fn test_sep_clears_index_high_bytes() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+        cpu.x_register = 0x1234;
+        cpu.y_register = 0x5678;
+
+        // SEP #$10
+        load_program(&mut cpu, 0x0000, &[0xE2, 0x10]);
+        execute_one(&mut cpu);
+
+        assert!(!cpu.index_16bit());
+        assert!(cpu.memory_16bit());
+        assert_eq!(cpu.x_register, 0x0034);
+        assert_eq!(cpu.y_register, 0x0078);
+    }
+
+    #[test]
+    fn test_native_interrupt_pushes_program_bank() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+        cpu.sp = 0x1FFF;
+        cpu.write_long(0x00FFEA, 0x00);
+        cpu.write_long(0x00FFEB, 0x90);
+        cpu.program_bank = 0x03;
+        cpu.pc = 0x4567;
+        cpu.status.unset_interrupt_disable();
+
+        cpu.set_nmi(true);
+        assert!(cpu.poll_interrupts());
+
+        assert_eq!(cpu.program_bank, 0x00);
+        assert_eq!(cpu.pc, 0x9000);
+        assert_eq!(cpu.sp, 0x1FFB);
+        assert_eq!(cpu.read_long(0x001FFF), 0x03);
+        assert_eq!(cpu.read_long(0x001FFE), 0x45);
+        assert_eq!(cpu.read_long(0x001FFD), 0x67);
+    }
+
+    #[test]
+    fn test_run_until_stp() {
+        let mut cpu = CPU65816::new();
+        cpu.write_long(0xFFFC, 0x00);
+        cpu.write_long(0xFFFD, 0x80);
+        // CLC ; XCE ; LDA #$01 ; STP
+        load_program(&mut cpu, 0x008000, &[0x18, 0xFB, 0xA9, 0x01, 0xDB]);
+        cpu.reset();
+        assert_eq!(cpu.run(), Ok(()));
+
+        assert!(!cpu.emulation);
+        assert_eq!(cpu.accumulator & 0x00FF, 0x01);
+        assert_eq!(cpu.pc, 0x8005);
+    }
+
+    #[test]
+    fn test_step_sixteen_bit_immediate_length() {
+        let mut cpu = CPU65816::new();
+        enter_native_16bit(&mut cpu);
+
+        // LDA #$1234 ; BRL $0010
+        load_program(&mut cpu, 0x020000, &[0xA9, 0x34, 0x12, 0x82, 0x10, 0x00]);
+        let result = cpu.step().unwrap();
+        assert_eq!(result.pc, 0x020000);
+        assert_eq!(result.mnemonic, "LDA");
+        assert_eq!(result.length, 3);
+        assert_eq!(result.operand, 0x1234);
+        assert_eq!(result.effective_address, Some(0x020001));
+        assert_eq!(result.cycles, 3);
+
+        let result = cpu.step().unwrap();
+        assert!(result.branch_taken);
+        assert_eq!(cpu.pc, 0x0016);
+        assert_eq!(cpu.program_bank, 0x02);
+    }
+
+    // Long bus counting the reads, like a device with read side effects
+    struct CountingBus {
+        memory: LongBus,
+        reads: Cell<u32>,
+    }
+
+    impl IBus for CountingBus {
+        fn read(&self, address: &u16) -> u8 {
+            self.read_long(*address as u32)
+        }
+
+        fn write(&mut self, address: &u16, data: u8) {
+            self.write_long(*address as u32, data);
+        }
+
+        fn read_long(&self, address: u32) -> u8 {
+            self.reads.set(self.reads.get() + 1);
+            self.memory.read_long(address)
+        }
+
+        fn write_long(&mut self, address: u32, data: u8) {
+            self.memory.write_long(address, data);
+        }
+
+        fn peek_long(&self, address: u32) -> u8 {
+            self.memory.peek_long(address)
+        }
+    }
+
+    #[test]
+    fn test_step_preview_does_not_read_the_bus() {
+        let mut cpu = CPU65816::with_bus(CountingBus {
+            memory: LongBus::new(),
+            reads: Cell::new(0),
+        });
+        // LDA #$12
+        cpu.bus.write_long(0x000600, 0xA9);
+        cpu.bus.write_long(0x000601, 0x12);
+        cpu.pc = 0x0600;
+
+        let result = cpu.step().unwrap();
+        assert_eq!(result.operand, 0x12);
+        // Op code and operand, each read once by the instruction itself
+        assert_eq!(cpu.bus.reads.get(), 2);
+    }
+
+    #[test]
+    fn test_error_policies() {
+        let mut cpu = CPU65816::new();
+        let error = CpuError::UnknownOpcode {
+            pc: 0x0600,
+            bytes: [0x00; 3],
+        };
+
+        // Halt : the processor stops and the error is returned
+        assert_eq!(cpu.handle_error(error), Err(error));
+        assert_eq!(cpu.state, State::Exit);
+
+        // Nop : the op code is skipped and execution goes on
+        cpu.policies.unknown_opcode = ErrorPolicy::Nop;
+        assert_eq!(cpu.handle_error(error), Ok(()));
+        assert_eq!(cpu.pc, 0x0601);
+        assert_eq!(cpu.state, State::Fetch);
+
+        // Trap : skipped like Nop, the host still sees the error
+        cpu.policies.unknown_opcode = ErrorPolicy::Trap;
+        cpu.pc = 0x0000;
+        assert_eq!(cpu.handle_error(error), Err(error));
+        assert_eq!(cpu.pc, 0x0601);
+        assert_eq!(cpu.state, State::Fetch);
+    }
+}
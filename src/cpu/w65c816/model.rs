@@ -0,0 +1,83 @@
+//   65C816 registers
+//    ___________________________________________
+//   | Data Bank (DBR) |   X Index (X)           |
+//   |_________________|_________________________|
+//   | Data Bank (DBR) |   Y Index (Y)           |
+//   |_________________|_________________________|
+//   |    00000000     |   Stack Pointer (S)     |
+//   |_________________|_________________________|
+//   |                 |  B  |  A  Accumulator (C)|
+//   |_________________|_____|___________________|
+//   | Program Bank(K) |   Program Counter (PC)  |
+//   |_________________|_________________________|
+//   |    00000000     |   Direct Page (D)       |
+//   |_________________|_________________________|
+//
+//    NVMX DIZC  E          N V M X D I Z C
+//   |____|____||_|         Emulation : M = 1, X = 1, S = $01xx
+//
+use crate::bus::model::LongBus;
+use crate::cpu::model::{CpuError, ErrorPolicies, State};
+use crate::cpu::processor_status::ProcessorStatus;
+use crate::cpu::w65c816::instruction::Instruction;
+use crate::interfaces::bus::IBus;
+
+pub struct CPU65816<B: IBus = LongBus> {
+    /*
+       Program Counter (PC) - offset of the next instruction inside the program bank.
+       It wraps inside the bank, the program bank is only changed by long jumps.
+    */
+    pub pc: u16,
+    /* Program Bank Register (K) - bank byte of every instruction fetch */
+    pub program_bank: u8,
+    /* Data Bank Register (DBR) - bank byte of absolute and indirect data addresses */
+    pub data_bank: u8,
+    /* Direct Page Register (D) - base address of the direct page in bank $00 */
+    pub direct_page: u16,
+    /*
+       Stack Pointer (S) - 16-bit in native mode.
+       In emulation mode the high byte is forced to $01.
+    */
+    pub sp: u16,
+    /*
+       Accumulator (C) - the low byte is A, the high byte is B.
+       While M is set only A is used and B is kept untouched.
+    */
+    pub accumulator: u16,
+    /*
+       Index Registers (X, Y) - 8 or 16 bits depending on the X flag.
+       The high byte is cleared whenever the X flag is set.
+    */
+    pub x_register: u16,
+    pub y_register: u16,
+    /*
+       Processor status (P) - in native mode bit 5 is M (accumulator and memory
+       width) and bit 4 is X (index width). In emulation mode they read as 1.
+    */
+    pub status: ProcessorStatus,
+    /* Emulation flag (E) - exchanged with carry by XCE, set after reset */
+    pub emulation: bool,
+    /* Effective 24-bit address of the current operand */
+    pub address: u32,
+    /* Operand fetched for the current instruction, 8 or 16 bits */
+    pub data: u16,
+    /**
+     * 65C816 instruction decoded
+     */
+    pub instruction_reg: Option<&'static Instruction>,
+    /*
+       Total machine cycles executed since power-on, including the extra cycles
+       for 16-bit data, an unaligned direct page and page crossing.
+    */
+    pub cycles: u64,
+    pub state: State,
+    pub bus: B,
+    /* Interrupt Request (IRQ) - level triggered input */
+    pub irq_line: bool,
+    /* Non-Maskable Interrupt (NMI) - edge triggered input */
+    pub nmi_line: bool,
+    pub nmi_pending: bool,
+    /* Condition raised by the current instruction, handled by the run loop */
+    pub fault: Option<CpuError>,
+    pub policies: ErrorPolicies,
+}
@@ -0,0 +1,373 @@
+use crate::cpu::w65c816::instruction::AddressingMode;
+use crate::cpu::w65c816::model::CPU65816;
+use crate::interfaces::bus::IBus;
+
+impl<B: IBus> CPU65816<B> {
+    /**
+    * ADC - Add Memory to Accumulator with Carry
+       Operation: A + M + C → A, C
+
+       8 or 16-bit depending on M. In decimal mode every nibble is adjusted and, unlike the NMOS 6502, N and Z follow the decimal result.
+
+       Processor Status register changes
+       Flag	Effect
+       Carry flag	Set if the result includes a carry bit, otherwise cleared.
+       Overflow flag	Set if the sign bit changed in a way that indicates signed overflow, otherwise cleared.
+       Zero flag	Set if the result is zero, otherwise cleared.
+       Negative flag	Updated to the value of the sign bit of the result.
+    */
+    pub fn adc(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_operand(mode, wide);
+        self.adc_run(self.data, wide);
+    }
+
+    /**
+    * SBC - Subtract Memory from Accumulator with Borrow
+       Operation: A - M - ~C → A
+
+       Subtraction is an addition of the one's complement, so it shares the ADC adder and its decimal adjust.
+    */
+    pub fn sbc(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_operand(mode, wide);
+        self.sbc_run(self.data, wide);
+    }
+
+    fn adc_run(&mut self, data: u16, wide: bool) {
+        let (result, carry, overflow) = if self.status.get_decimal_mode() == 1 {
+            self.add_decimal(data, wide, false)
+        } else {
+            self.add_binary(data, wide)
+        };
+        self.add_finish(result, carry, overflow, wide);
+    }
+
+    fn sbc_run(&mut self, data: u16, wide: bool) {
+        let mask = if wide { 0xFFFF } else { 0x00FF };
+        let (result, carry, overflow) = if self.status.get_decimal_mode() == 1 {
+            self.add_decimal(!data & mask, wide, true)
+        } else {
+            self.add_binary(!data & mask, wide)
+        };
+        self.add_finish(result, carry, overflow, wide);
+    }
+
+    fn add_binary(&self, data: u16, wide: bool) -> (u16, bool, bool) {
+        let (mask, sign) = if wide {
+            (0xFFFF, 0x8000)
+        } else {
+            (0x00FF, 0x0080)
+        };
+        let a = (self.accumulator & mask) as u32;
+        let sum = a + data as u32 + self.status.get_carry() as u32;
+        let result = (sum & mask as u32) as u16;
+
+        let overflow = !(a as u16 ^ data) & (a as u16 ^ result) & sign != 0;
+        (result, sum > mask as u32, overflow)
+    }
+
+    /*
+       Decimal adder working nibble by nibble. For subtraction the data is already
+       complemented and a digit without carry is adjusted down by 6.
+       Overflow is taken before the top digit is adjusted.
+    */
+    fn add_decimal(&self, data: u16, wide: bool, subtract: bool) -> (u16, bool, bool) {
+        let (mask, sign, digits) = if wide {
+            (0xFFFF, 0x8000, 4)
+        } else {
+            (0x00FF, 0x0080, 2)
+        };
+        let a = (self.accumulator & mask) as i32;
+        let b = data as i32;
+        let mut carry = self.status.get_carry() as i32;
+        let mut result: i32 = 0;
+        let mut overflow = false;
+
+        for digit in 0..digits {
+            let shift = digit * 4;
+            let mut sum = ((a >> shift) & 0x0F) + ((b >> shift) & 0x0F) + carry;
+
+            if digit == digits - 1 {
+                let unadjusted = (result | (sum << shift)) as u16;
+                overflow = !(a as u16 ^ b as u16) & (a as u16 ^ unadjusted) & sign != 0;
+            }
+
+            if subtract {
+                if sum <= 0x0F {
+                    sum -= 0x06;
+                }
+            } else if sum > 0x09 {
+                sum += 0x06;
+            }
+
+            carry = (sum > 0x0F) as i32;
+            result |= (sum & 0x0F) << shift;
+        }
+
+        (result as u16, carry == 1, overflow)
+    }
+
+    fn add_finish(&mut self, result: u16, carry: bool, overflow: bool, wide: bool) {
+        if carry {
+            self.status.set_carry();
+        } else {
+            self.status.unset_carry();
+        }
+
+        if overflow {
+            self.status.set_overflow();
+        } else {
+            self.status.unset_overflow();
+        }
+
+        self.set_negative_zero(result, wide);
+        self.set_accumulator(result, wide);
+    }
+
+    /**
+    * AND - "AND" Memory with Accumulator
+       Operation: A ∧ M → A
+    */
+    pub fn and(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_operand(mode, wide);
+        let result = self.accumulator & self.data;
+        self.set_negative_zero(result, wide);
+        self.set_accumulator(result, wide);
+    }
+
+    /**
+    * ORA - "OR" Memory with Accumulator
+       Operation: A ∨ M → A
+    */
+    pub fn ora(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_operand(mode, wide);
+        let result = self.accumulator | self.data;
+        self.set_negative_zero(result, wide);
+        self.set_accumulator(result, wide);
+    }
+
+    /**
+    * EOR - "Exclusive OR" Memory with Accumulator
+       Operation: A ⊻ M → A
+    */
+    pub fn eor(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_operand(mode, wide);
+        let result = self.accumulator ^ self.data;
+        self.set_negative_zero(result, wide);
+        self.set_accumulator(result, wide);
+    }
+
+    /**
+    * CMP - Compare Memory and Accumulator
+       Operation: A - M
+    */
+    pub fn cmp(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_operand(mode, wide);
+        self.compare(self.accumulator, wide);
+    }
+
+    /**
+    * CPX - Compare Index Register X To Memory
+       Operation: X - M
+    */
+    pub fn cpx(&mut self, mode: &AddressingMode) {
+        let wide = self.index_16bit();
+        self.fetch_operand(mode, wide);
+        self.compare(self.x_register, wide);
+    }
+
+    /**
+    * CPY - Compare Index Register Y To Memory
+       Operation: Y - M
+    */
+    pub fn cpy(&mut self, mode: &AddressingMode) {
+        let wide = self.index_16bit();
+        self.fetch_operand(mode, wide);
+        self.compare(self.y_register, wide);
+    }
+
+    fn compare(&mut self, register: u16, wide: bool) {
+        let mask = if wide { 0xFFFF } else { 0x00FF };
+        let register = register & mask;
+        let result = register.wrapping_sub(self.data);
+
+        // Set if the register is greater than or equal to the memory value, otherwise cleared.
+        if register >= self.data {
+            self.status.set_carry();
+        } else {
+            self.status.unset_carry();
+        }
+
+        self.set_negative_zero(result, wide);
+    }
+
+    /**
+    * BIT - Test Bits in Memory with Accumulator
+       Operation: A ∧ M, M15/M7 → N, M14/M6 → V
+
+       BIT immediate has no memory byte to test, so it only updates the Z flag.
+    */
+    pub fn bit(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_operand(mode, wide);
+        let sign = if wide { 0x8000 } else { 0x0080 };
+
+        // Set if the result of the AND operation is zero, otherwise cleared.
+        if self.accumulator & self.data & (sign | (sign - 1)) == 0 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
+
+        if *mode == AddressingMode::Immediate {
+            return;
+        }
+
+        // Updated to equal the bit below the sign bit of the memory value.
+        if self.data & (sign >> 1) > 0 {
+            self.status.set_overflow();
+        } else {
+            self.status.unset_overflow();
+        }
+
+        // Updated to equal the sign bit of the memory value.
+        if self.data & sign > 0 {
+            self.status.set_negative();
+        } else {
+            self.status.unset_negative();
+        }
+    }
+
+    /**
+    * TRB - Test And Reset Memory Bits With Accumulator
+       Operation: A ∧ M → Z, ~A ∧ M → M
+    */
+    pub fn trb(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_modify_operand(mode, wide);
+        self.test_bits(wide);
+        let result = self.data & !self.accumulator;
+        self.store_modified(mode, result, wide);
+    }
+
+    /**
+    * TSB - Test And Set Memory Bits With Accumulator
+       Operation: A ∧ M → Z, A ∨ M → M
+    */
+    pub fn tsb(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_modify_operand(mode, wide);
+        self.test_bits(wide);
+        let result = self.data | self.accumulator;
+        self.store_modified(mode, result, wide);
+    }
+
+    fn test_bits(&mut self, wide: bool) {
+        let mask = if wide { 0xFFFF } else { 0x00FF };
+
+        // Set if the accumulator AND the memory value is zero, otherwise cleared.
+        if self.accumulator & self.data & mask == 0 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
+    }
+
+    /**
+    * ASL - Arithmetic Shift Left
+       Operation: C ← /M15...M0/ ← 0
+    */
+    pub fn asl(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_modify_operand(mode, wide);
+        let sign = if wide { 0x8000 } else { 0x0080 };
+        let carry = self.data & sign > 0;
+        let result = self.data << 1;
+        self.shift_finish(mode, result, carry, wide);
+    }
+
+    /**
+    * LSR - Logical Shift Right
+       Operation: 0 → /M15...M0/ → C
+    */
+    pub fn lsr(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_modify_operand(mode, wide);
+        let carry = self.data & 0x0001 > 0;
+        let result = self.data >> 1;
+        self.shift_finish(mode, result, carry, wide);
+    }
+
+    /**
+    * ROL - Rotate Left
+       Operation: C ← /M15...M0/ ← C
+    */
+    pub fn rol(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_modify_operand(mode, wide);
+        let sign = if wide { 0x8000 } else { 0x0080 };
+        let carry = self.data & sign > 0;
+        let result = self.data << 1 | self.status.get_carry() as u16;
+        self.shift_finish(mode, result, carry, wide);
+    }
+
+    /**
+    * ROR - Rotate Right
+       Operation: C → /M15...M0/ → C
+    */
+    pub fn ror(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_modify_operand(mode, wide);
+        let sign = if wide { 0x8000 } else { 0x0080 };
+        let carry = self.data & 0x0001 > 0;
+        let mut result = self.data >> 1;
+        if self.status.get_carry() == 1 {
+            result |= sign;
+        }
+        self.shift_finish(mode, result, carry, wide);
+    }
+
+    fn shift_finish(&mut self, mode: &AddressingMode, result: u16, carry: bool, wide: bool) {
+        let result = if wide { result } else { result & 0x00FF };
+
+        if carry {
+            self.status.set_carry();
+        } else {
+            self.status.unset_carry();
+        }
+
+        self.set_negative_zero(result, wide);
+        self.store_modified(mode, result, wide);
+    }
+
+    /**
+    * INC - Increment Memory or Accumulator By One
+       Operation: M + 1 → M
+    */
+    pub fn inc(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_modify_operand(mode, wide);
+        let mask = if wide { 0xFFFF } else { 0x00FF };
+        let result = self.data.wrapping_add(1) & mask;
+        self.set_negative_zero(result, wide);
+        self.store_modified(mode, result, wide);
+    }
+
+    /**
+    * DEC - Decrement Memory or Accumulator By One
+       Operation: M - 1 → M
+    */
+    pub fn dec(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_modify_operand(mode, wide);
+        let mask = if wide { 0xFFFF } else { 0x00FF };
+        let result = self.data.wrapping_sub(1) & mask;
+        self.set_negative_zero(result, wide);
+        self.store_modified(mode, result, wide);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::cpu::w65c816::instruction::AddressingMode;
+use crate::cpu::w65c816::model::CPU65816;
+use crate::interfaces::bus::IBus;
+
+/** Block moves */
+impl<B: IBus> CPU65816<B> {
+    /**
+    * MVN - Block Move Next
+       Operation: (srcbank:X) → (destbank:Y), X + 1 → X, Y + 1 → Y, C - 1 → C
+
+       One byte is moved per execution. While C has not wrapped to $FFFF the PC is
+       moved back on the instruction, so C + 1 bytes are copied in total.
+    */
+    pub fn mvn(&mut self, mode: &AddressingMode) {
+        self.block_move(mode, 1);
+    }
+
+    /**
+    * MVP - Block Move Previous
+       Operation: (srcbank:X) → (destbank:Y), X - 1 → X, Y - 1 → Y, C - 1 → C
+    */
+    pub fn mvp(&mut self, mode: &AddressingMode) {
+        self.block_move(mode, 0xFFFF);
+    }
+
+    fn block_move(&mut self, mode: &AddressingMode, step: u16) {
+        self.resolve_address(mode);
+        let destination = (self.data >> 8) as u8;
+        let source = (self.data & 0x00FF) as u8;
+
+        let data = self.read_long((source as u32) << 16 | self.x_register as u32);
+        self.write_long((destination as u32) << 16 | self.y_register as u32, data);
+
+        // The data bank follows the destination
+        self.data_bank = destination;
+        self.x_register = self.index_value(self.x_register.wrapping_add(step));
+        self.y_register = self.index_value(self.y_register.wrapping_add(step));
+        self.accumulator = self.accumulator.wrapping_sub(1);
+
+        if self.accumulator != 0xFFFF {
+            // Repeat the instruction
+            self.pc = self.pc.wrapping_sub(3);
+        }
+    }
+}
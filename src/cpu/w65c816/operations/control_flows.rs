@@ -0,0 +1,259 @@
+use crate::constants::{EMULATION_COP_VECTOR, IRQ_VECTOR, NATIVE_BRK_VECTOR, NATIVE_COP_VECTOR};
+use crate::cpu::model::State;
+use crate::cpu::w65c816::instruction::AddressingMode;
+use crate::cpu::w65c816::model::CPU65816;
+use crate::interfaces::bus::IBus;
+
+/** Branches, jumps, returns and interrupts */
+impl<B: IBus> CPU65816<B> {
+    /**
+    * BCC - Branch on Carry Clear
+       Operation: Branch on C = 0
+    */
+    pub fn bcc(&mut self, mode: &AddressingMode) {
+        let condition = self.status.get_carry() == 0;
+        self.branch(mode, condition);
+    }
+
+    /**
+    * BCS - Branch on Carry Set
+       Operation: Branch on C = 1
+    */
+    pub fn bcs(&mut self, mode: &AddressingMode) {
+        let condition = self.status.get_carry() == 1;
+        self.branch(mode, condition);
+    }
+
+    /**
+    * BEQ - Branch on Result Zero
+       Operation: Branch on Z = 1
+    */
+    pub fn beq(&mut self, mode: &AddressingMode) {
+        let condition = self.status.get_zero() == 1;
+        self.branch(mode, condition);
+    }
+
+    /**
+    * BNE - Branch on Result Not Zero
+       Operation: Branch on Z = 0
+    */
+    pub fn bne(&mut self, mode: &AddressingMode) {
+        let condition = self.status.get_zero() == 0;
+        self.branch(mode, condition);
+    }
+
+    /**
+    * BMI - Branch on Result Minus
+       Operation: Branch on N = 1
+    */
+    pub fn bmi(&mut self, mode: &AddressingMode) {
+        let condition = self.status.get_negative() == 1;
+        self.branch(mode, condition);
+    }
+
+    /**
+    * BPL - Branch on Result Plus
+       Operation: Branch on N = 0
+    */
+    pub fn bpl(&mut self, mode: &AddressingMode) {
+        let condition = self.status.get_negative() == 0;
+        self.branch(mode, condition);
+    }
+
+    /**
+    * BVC - Branch on Overflow Clear
+       Operation: Branch on V = 0
+    */
+    pub fn bvc(&mut self, mode: &AddressingMode) {
+        let condition = self.status.get_overflow() == 0;
+        self.branch(mode, condition);
+    }
+
+    /**
+    * BVS - Branch on Overflow Set
+       Operation: Branch on V = 1
+    */
+    pub fn bvs(&mut self, mode: &AddressingMode) {
+        let condition = self.status.get_overflow() == 1;
+        self.branch(mode, condition);
+    }
+
+    /**
+    * BRA - Branch Always
+       Operation: PC + offset → PC
+    */
+    pub fn bra(&mut self, mode: &AddressingMode) {
+        self.branch(mode, true);
+    }
+
+    /**
+    * BRL - Branch Always Long
+       Operation: PC + 16-bit offset → PC, inside the program bank
+    */
+    pub fn brl(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.pc = (self.address & 0xFFFF) as u16;
+    }
+
+    /*
+       Taken branches add one cycle.
+       Crossing a page adds another one in emulation mode only.
+    */
+    fn branch(&mut self, mode: &AddressingMode, condition: bool) {
+        let page_cross = self.resolve_address(mode);
+        if !condition {
+            return;
+        }
+
+        self.cycles += 1;
+        if self.emulation && page_cross {
+            self.cycles += 1;
+        }
+        self.pc = (self.address & 0xFFFF) as u16;
+    }
+
+    /**
+    * JMP - JMP Indirect
+       Operation: operand → PC, the program bank is unchanged
+    */
+    pub fn jmp(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.pc = (self.address & 0xFFFF) as u16;
+    }
+
+    /**
+    * JML - Jump Long
+       Operation: operand → K, PC
+    */
+    pub fn jml(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.jump_long(self.address);
+    }
+
+    /**
+    * JSR - Jump To Subroutine
+       Operation: PC + 2↓, operand → PC
+    */
+    pub fn jsr(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push_word(self.pc.wrapping_sub(1));
+        self.pc = (self.address & 0xFFFF) as u16;
+    }
+
+    /**
+    * JSL - Jump To Subroutine Long
+       Operation: K↓, PC + 3↓, operand → K, PC
+    */
+    pub fn jsl(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push(self.program_bank);
+        self.push_word(self.pc.wrapping_sub(1));
+        self.jump_long(self.address);
+    }
+
+    fn jump_long(&mut self, address: u32) {
+        self.program_bank = (address >> 16) as u8;
+        self.pc = (address & 0xFFFF) as u16;
+    }
+
+    /**
+    * RTS - Return From Subroutine
+       Operation: PC↑, PC + 1 → PC
+    */
+    pub fn rts(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.pc = self.pull_word().wrapping_add(1);
+    }
+
+    /**
+    * RTL - Return From Subroutine Long
+       Operation: PC↑, K↑, PC + 1 → PC
+    */
+    pub fn rtl(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.pc = self.pull_word().wrapping_add(1);
+        self.program_bank = self.pull();
+    }
+
+    /**
+    * RTI - Return From Interrupt
+       Operation: P↑, PC↑, and K↑ in native mode
+    */
+    pub fn rti(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let status = self.pull();
+        self.status.set_status(status);
+        self.update_register_widths();
+        self.pc = self.pull_word();
+
+        if !self.emulation {
+            // One more cycle to pull the program bank
+            self.program_bank = self.pull();
+            self.cycles += 1;
+        }
+    }
+
+    /**
+    * BRK - Break Command
+       Operation: forced interrupt, PC + 2↓, P↓
+
+       The byte after the op code is a signature and is skipped.
+    */
+    pub fn brk(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let vector = if self.emulation {
+            IRQ_VECTOR
+        } else {
+            NATIVE_BRK_VECTOR
+        };
+        self.interrupt(vector, true);
+    }
+
+    /**
+    * COP - Co-Processor Enable
+       Operation: software interrupt through the COP vector, PC + 2↓, P↓
+    */
+    pub fn cop(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let vector = if self.emulation {
+            EMULATION_COP_VECTOR
+        } else {
+            NATIVE_COP_VECTOR
+        };
+        self.interrupt(vector, false);
+    }
+
+    /**
+    * WAI - Wait For Interrupt
+       Operation: halt until NMI or IRQ is asserted
+    */
+    pub fn wai(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.state = State::Wait;
+    }
+
+    /**
+    * STP - Stop The Clock
+       Operation: halt until reset
+    */
+    pub fn stp(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.state = State::Stop;
+    }
+
+    /**
+    * NOP - No Operation
+       Operation: No operation
+    */
+    pub fn nop(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+    }
+
+    /**
+    * WDM - Reserved For Future Expansion
+       Operation: two byte no operation
+    */
+    pub fn wdm(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod arithmetic_and_logic;
+pub mod block_moves;
+pub mod control_flows;
+pub mod registers;
+pub mod stack;
+pub mod status_registers;
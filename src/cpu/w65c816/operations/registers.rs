@@ -0,0 +1,261 @@
+use crate::cpu::w65c816::instruction::AddressingMode;
+use crate::cpu::w65c816::model::CPU65816;
+use crate::interfaces::bus::IBus;
+
+/** Loads, stores and register transfers */
+impl<B: IBus> CPU65816<B> {
+    /**
+    * LDA - Load Accumulator with Memory
+       Operation: M → A
+    */
+    pub fn lda(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.fetch_operand(mode, wide);
+        self.set_negative_zero(self.data, wide);
+        self.set_accumulator(self.data, wide);
+    }
+
+    /**
+    * LDX - Load Index Register X From Memory
+       Operation: M → X
+    */
+    pub fn ldx(&mut self, mode: &AddressingMode) {
+        let wide = self.index_16bit();
+        self.fetch_operand(mode, wide);
+        self.set_negative_zero(self.data, wide);
+        self.x_register = self.data;
+    }
+
+    /**
+    * LDY - Load Index Register Y From Memory
+       Operation: M → Y
+    */
+    pub fn ldy(&mut self, mode: &AddressingMode) {
+        let wide = self.index_16bit();
+        self.fetch_operand(mode, wide);
+        self.set_negative_zero(self.data, wide);
+        self.y_register = self.data;
+    }
+
+    /**
+    * STA - Store Accumulator in Memory
+       Operation: A → M
+    */
+    pub fn sta(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.resolve_address(mode);
+        self.write_data(self.address, self.accumulator, wide);
+    }
+
+    /**
+    * STX - Store Index Register X In Memory
+       Operation: X → M
+    */
+    pub fn stx(&mut self, mode: &AddressingMode) {
+        let wide = self.index_16bit();
+        self.resolve_address(mode);
+        self.write_data(self.address, self.x_register, wide);
+    }
+
+    /**
+    * STY - Store Index Register Y In Memory
+       Operation: Y → M
+    */
+    pub fn sty(&mut self, mode: &AddressingMode) {
+        let wide = self.index_16bit();
+        self.resolve_address(mode);
+        self.write_data(self.address, self.y_register, wide);
+    }
+
+    /**
+    * STZ - Store Zero In Memory
+       Operation: 0 → M
+    */
+    pub fn stz(&mut self, mode: &AddressingMode) {
+        let wide = self.memory_16bit();
+        self.resolve_address(mode);
+        self.write_data(self.address, 0x0000, wide);
+    }
+
+    /**
+    * TAX - Transfer Accumulator To Index X
+       Operation: C → X, width follows the X flag
+    */
+    pub fn tax(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.x_register = self.index_value(self.accumulator);
+        self.set_negative_zero(self.x_register, self.index_16bit());
+    }
+
+    /**
+    * TAY - Transfer Accumulator To Index Y
+       Operation: C → Y, width follows the X flag
+    */
+    pub fn tay(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.y_register = self.index_value(self.accumulator);
+        self.set_negative_zero(self.y_register, self.index_16bit());
+    }
+
+    /**
+    * TXA - Transfer Index X To Accumulator
+       Operation: X → A, width follows the M flag
+    */
+    pub fn txa(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let wide = self.memory_16bit();
+        self.set_accumulator(self.x_register, wide);
+        self.set_negative_zero(self.accumulator, wide);
+    }
+
+    /**
+    * TYA - Transfer Index Y To Accumulator
+       Operation: Y → A, width follows the M flag
+    */
+    pub fn tya(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let wide = self.memory_16bit();
+        self.set_accumulator(self.y_register, wide);
+        self.set_negative_zero(self.accumulator, wide);
+    }
+
+    /**
+    * TXY - Transfer Index X To Index Y
+       Operation: X → Y
+    */
+    pub fn txy(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.y_register = self.x_register;
+        self.set_negative_zero(self.y_register, self.index_16bit());
+    }
+
+    /**
+    * TYX - Transfer Index Y To Index X
+       Operation: Y → X
+    */
+    pub fn tyx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.x_register = self.y_register;
+        self.set_negative_zero(self.x_register, self.index_16bit());
+    }
+
+    /**
+    * TSX - Transfer Stack Pointer To Index X
+       Operation: S → X
+    */
+    pub fn tsx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.x_register = self.index_value(self.sp);
+        self.set_negative_zero(self.x_register, self.index_16bit());
+    }
+
+    /**
+    * TXS - Transfer Index X To Stack Pointer
+       Operation: X → S
+
+       The stack stays on page one in emulation mode. No flags are changed.
+    */
+    pub fn txs(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.sp = if self.emulation {
+            0x0100 | (self.x_register & 0x00FF)
+        } else {
+            self.x_register
+        };
+    }
+
+    /**
+    * TCS - Transfer C Accumulator To Stack Pointer
+       Operation: C → S
+    */
+    pub fn tcs(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.sp = if self.emulation {
+            0x0100 | (self.accumulator & 0x00FF)
+        } else {
+            self.accumulator
+        };
+    }
+
+    /**
+    * TSC - Transfer Stack Pointer To C Accumulator
+       Operation: S → C, always 16-bit
+    */
+    pub fn tsc(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.accumulator = self.sp;
+        self.set_negative_zero(self.accumulator, true);
+    }
+
+    /**
+    * TCD - Transfer C Accumulator To Direct Page Register
+       Operation: C → D, always 16-bit
+    */
+    pub fn tcd(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.direct_page = self.accumulator;
+        self.set_negative_zero(self.direct_page, true);
+    }
+
+    /**
+    * TDC - Transfer Direct Page Register To C Accumulator
+       Operation: D → C, always 16-bit
+    */
+    pub fn tdc(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.accumulator = self.direct_page;
+        self.set_negative_zero(self.accumulator, true);
+    }
+
+    /**
+    * XBA - Exchange B And A Accumulators
+       Operation: B ↔ A
+
+       N and Z follow the new 8-bit A whatever the M flag.
+    */
+    pub fn xba(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.accumulator = self.accumulator.rotate_left(8);
+        self.set_negative_zero(self.accumulator, false);
+    }
+
+    /**
+    * INX - Increment Index Register X By One
+       Operation: X + 1 → X
+    */
+    pub fn inx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.x_register = self.index_value(self.x_register.wrapping_add(1));
+        self.set_negative_zero(self.x_register, self.index_16bit());
+    }
+
+    /**
+    * INY - Increment Index Register Y By One
+       Operation: Y + 1 → Y
+    */
+    pub fn iny(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.y_register = self.index_value(self.y_register.wrapping_add(1));
+        self.set_negative_zero(self.y_register, self.index_16bit());
+    }
+
+    /**
+    * DEX - Decrement Index Register X By One
+       Operation: X - 1 → X
+    */
+    pub fn dex(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.x_register = self.index_value(self.x_register.wrapping_sub(1));
+        self.set_negative_zero(self.x_register, self.index_16bit());
+    }
+
+    /**
+    * DEY - Decrement Index Register Y By One
+       Operation: Y - 1 → Y
+    */
+    pub fn dey(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.y_register = self.index_value(self.y_register.wrapping_sub(1));
+        self.set_negative_zero(self.y_register, self.index_16bit());
+    }
+}
@@ -0,0 +1,163 @@
+use crate::cpu::w65c816::instruction::AddressingMode;
+use crate::cpu::w65c816::model::CPU65816;
+use crate::interfaces::bus::IBus;
+
+/** Stack operations */
+impl<B: IBus> CPU65816<B> {
+    /**
+    * PHA - Push Accumulator On Stack
+       Operation: A↓ or C↓ depending on the M flag
+    */
+    pub fn pha(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push_data(self.accumulator, self.memory_16bit());
+    }
+
+    /**
+    * PHX - Push Index Register X On Stack
+       Operation: X↓
+    */
+    pub fn phx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push_data(self.x_register, self.index_16bit());
+    }
+
+    /**
+    * PHY - Push Index Register Y On Stack
+       Operation: Y↓
+    */
+    pub fn phy(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push_data(self.y_register, self.index_16bit());
+    }
+
+    /**
+    * PLA - Pull Accumulator From Stack
+       Operation: A↑ or C↑ depending on the M flag
+    */
+    pub fn pla(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let wide = self.memory_16bit();
+        let data = self.pull_data(wide);
+        self.set_negative_zero(data, wide);
+        self.set_accumulator(data, wide);
+    }
+
+    /**
+    * PLX - Pull Index Register X From Stack
+       Operation: X↑
+    */
+    pub fn plx(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let wide = self.index_16bit();
+        self.x_register = self.pull_data(wide);
+        self.set_negative_zero(self.x_register, wide);
+    }
+
+    /**
+    * PLY - Pull Index Register Y From Stack
+       Operation: Y↑
+    */
+    pub fn ply(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let wide = self.index_16bit();
+        self.y_register = self.pull_data(wide);
+        self.set_negative_zero(self.y_register, wide);
+    }
+
+    /**
+    * PHP - Push Processor Status On Stack
+       Operation: P↓
+    */
+    pub fn php(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push(self.status.get_status());
+    }
+
+    /**
+    * PLP - Pull Processor Status From Stack
+       Operation: P↑
+
+       M and X stay set in emulation mode.
+    */
+    pub fn plp(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let status = self.pull();
+        self.status.set_status(status);
+        self.update_register_widths();
+    }
+
+    /**
+    * PHB - Push Data Bank Register On Stack
+       Operation: DBR↓
+    */
+    pub fn phb(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push(self.data_bank);
+    }
+
+    /**
+    * PHD - Push Direct Page Register On Stack
+       Operation: D↓, always 16-bit
+    */
+    pub fn phd(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push_word(self.direct_page);
+    }
+
+    /**
+    * PHK - Push Program Bank Register On Stack
+       Operation: K↓
+    */
+    pub fn phk(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push(self.program_bank);
+    }
+
+    /**
+    * PLB - Pull Data Bank Register From Stack
+       Operation: DBR↑
+    */
+    pub fn plb(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.data_bank = self.pull();
+        self.set_negative_zero(self.data_bank as u16, false);
+    }
+
+    /**
+    * PLD - Pull Direct Page Register From Stack
+       Operation: D↑, always 16-bit
+    */
+    pub fn pld(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.direct_page = self.pull_word();
+        self.set_negative_zero(self.direct_page, true);
+    }
+
+    /**
+    * PEA - Push Effective Absolute Address
+       Operation: operand↓, the 16-bit operand is pushed as is
+    */
+    pub fn pea(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push_word((self.address & 0xFFFF) as u16);
+    }
+
+    /**
+    * PEI - Push Effective Indirect Address
+       Operation: (D + operand)↓, the 16-bit pointer read from the direct page is pushed
+    */
+    pub fn pei(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push_word((self.address & 0xFFFF) as u16);
+    }
+
+    /**
+    * PER - Push Effective PC Relative Address
+       Operation: (PC + operand)↓
+    */
+    pub fn per(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.push_word((self.address & 0xFFFF) as u16);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::cpu::w65c816::instruction::AddressingMode;
+use crate::cpu::w65c816::model::CPU65816;
+use crate::interfaces::bus::IBus;
+
+/** Status register operations */
+impl<B: IBus> CPU65816<B> {
+    /**
+    * CLC - Clear Carry Flag
+       Operation: 0 → C
+    */
+    pub fn clc(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.status.unset_carry();
+    }
+
+    /**
+    * SEC - Set Carry Flag
+       Operation: 1 → C
+    */
+    pub fn sec(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.status.set_carry();
+    }
+
+    /**
+    * CLI - Clear Interrupt Disable
+       Operation: 0 → I
+    */
+    pub fn cli(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.status.unset_interrupt_disable();
+    }
+
+    /**
+    * SEI - Set Interrupt Disable
+       Operation: 1 → I
+    */
+    pub fn sei(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.status.set_interrupt_disable();
+    }
+
+    /**
+    * CLD - Clear Decimal Mode
+       Operation: 0 → D
+    */
+    pub fn cld(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.status.unset_decimal_mode();
+    }
+
+    /**
+    * SED - Set Decimal Mode
+       Operation: 1 → D
+    */
+    pub fn sed(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.status.set_decimal_mode();
+    }
+
+    /**
+    * CLV - Clear Overflow Flag
+       Operation: 0 → V
+    */
+    pub fn clv(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        self.status.unset_overflow();
+    }
+
+    /**
+    * REP - Reset Processor Status Bits
+       Operation: P ∧ ~M → P
+
+       REP #$30 switches the accumulator and index registers to 16-bit in native mode.
+    */
+    pub fn rep(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let mask = self.read_long(self.address);
+        self.status.set_status(self.status.get_status() & !mask);
+        self.update_register_widths();
+    }
+
+    /**
+    * SEP - Set Processor Status Bits
+       Operation: P ∨ M → P
+    */
+    pub fn sep(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let mask = self.read_long(self.address);
+        self.status.set_status(self.status.get_status() | mask);
+        self.update_register_widths();
+    }
+
+    /**
+    * XCE - Exchange Carry And Emulation Flags
+       Operation: C ↔ E
+
+       Entering native mode leaves M and X set, so registers stay 8-bit until REP.
+       Entering emulation mode forces M and X and moves the stack to page one.
+    */
+    pub fn xce(&mut self, mode: &AddressingMode) {
+        self.resolve_address(mode);
+        let carry = self.status.get_carry() == 1;
+
+        if self.emulation {
+            self.status.set_carry();
+        } else {
+            self.status.unset_carry();
+        }
+
+        self.emulation = carry;
+        self.update_register_widths();
+    }
+}
@@ -0,0 +1,843 @@
+use crate::bus::model::LongBus;
+use crate::constants::{
+    IRQ_VECTOR, NATIVE_IRQ_VECTOR, NATIVE_NMI_VECTOR, NMI_VECTOR, RESET_VECTOR,
+};
+use crate::cpu::model::{CpuError, ErrorPolicies, State, StepResult};
+use crate::cpu::processor_status::ProcessorStatus;
+use crate::cpu::w65c816::instruction::{AddressingMode, CPU_65C816_OPERATION_CODES_MAP};
+use crate::cpu::w65c816::model::CPU65816;
+use crate::interfaces::bus::IBus;
+use crate::interfaces::cpu::ICPU;
+
+impl CPU65816 {
+    pub fn new() -> Self {
+        Self::with_bus(LongBus::new())
+    }
+}
+
+impl Default for CPU65816 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: IBus> CPU65816<B> {
+    /* Processor wired to an application supplied bus */
+    pub fn with_bus(bus: B) -> Self {
+        CPU65816 {
+            pc: 0x0000,
+            program_bank: 0x00,
+            data_bank: 0x00,
+            direct_page: 0x0000,
+            sp: 0x01FF,
+            accumulator: 0x0000,
+            x_register: 0x0000,
+            y_register: 0x0000,
+            status: ProcessorStatus::new(),
+            emulation: true,
+            address: 0x000000,
+            data: 0x0000,
+            instruction_reg: None,
+            cycles: 0,
+            state: State::Fetch,
+            bus,
+            irq_line: false,
+            nmi_line: false,
+            nmi_pending: false,
+            fault: None,
+            policies: ErrorPolicies::new(),
+        }
+    }
+
+    pub(crate) fn fetch(&mut self) {
+        // Load op code from the program bank
+        self.address = self.program_address(self.pc);
+        self.data = self.read_long(self.address) as u16;
+    }
+
+    pub(crate) fn decode(&mut self) {
+        // Fetch OP CODE
+        self.instruction_reg = CPU_65C816_OPERATION_CODES_MAP
+            .get(&(self.data as u8))
+            .copied();
+    }
+}
+
+/** Register widths */
+impl<B: IBus> CPU65816<B> {
+    /* M flag clear in native mode : accumulator and memory are 16-bit */
+    pub fn memory_16bit(&self) -> bool {
+        !self.emulation && self.status.get_unused() == 0
+    }
+
+    /* X flag clear in native mode : index registers are 16-bit */
+    pub fn index_16bit(&self) -> bool {
+        !self.emulation && self.status.get_break_command() == 0
+    }
+
+    /* Write A, or the whole of C when 16-bit. B is kept in 8-bit mode. */
+    pub fn set_accumulator(&mut self, data: u16, wide: bool) {
+        if wide {
+            self.accumulator = data;
+        } else {
+            self.accumulator = (self.accumulator & 0xFF00) | (data & 0x00FF);
+        }
+    }
+
+    /* Width of the index registers, the high byte is zero while X is set */
+    pub fn index_value(&self, data: u16) -> u16 {
+        if self.index_16bit() {
+            data
+        } else {
+            data & 0x00FF
+        }
+    }
+
+    /*
+       Apply the M and X flags after P or E changed.
+       Emulation mode forces M and X, a set X flag clears the index high bytes.
+    */
+    pub fn update_register_widths(&mut self) {
+        if self.emulation {
+            self.status.set_unused();
+            self.status.set_break_command();
+            self.sp = 0x0100 | (self.sp & 0x00FF);
+        }
+
+        if !self.index_16bit() {
+            self.x_register &= 0x00FF;
+            self.y_register &= 0x00FF;
+        }
+    }
+
+    /* Negative and Zero flags for an 8 or 16-bit result */
+    pub fn set_negative_zero(&mut self, data: u16, wide: bool) {
+        let (mask, sign) = if wide {
+            (0xFFFF, 0x8000)
+        } else {
+            (0x00FF, 0x0080)
+        };
+
+        // Set if the result is zero, otherwise cleared.
+        if data & mask == 0 {
+            self.status.set_zero();
+        } else {
+            self.status.unset_zero();
+        }
+
+        // Updated to the value of the sign bit of the result.
+        if data & sign > 0 {
+            self.status.set_negative();
+        } else {
+            self.status.unset_negative();
+        }
+    }
+}
+
+impl<B: IBus> ICPU for CPU65816<B> {
+    /*
+       RESET enters emulation mode : M = X = 1, D = $0000, DBR = K = $00,
+       stack on page one, I set, D cleared and PC loaded from $FFFC.
+    */
+    fn reset(&mut self) {
+        self.emulation = true;
+        self.direct_page = 0x0000;
+        self.data_bank = 0x00;
+        self.program_bank = 0x00;
+        self.update_register_widths();
+        self.status.set_interrupt_disable();
+        self.status.unset_decimal_mode();
+
+        let low = self.read_long(RESET_VECTOR as u32) as u16;
+        let high = self.read_long(RESET_VECTOR.wrapping_add(1) as u32) as u16;
+        self.pc = high << 8 | low;
+        self.instruction_reg = None;
+        self.fault = None;
+        self.state = State::Fetch;
+        self.cycles += 7;
+    }
+
+    fn run(&mut self) -> Result<(), CpuError> {
+        let mut clocked = self.cycles;
+        loop {
+            // Process Instruction With State
+            match self.state {
+                State::Fetch => {
+                    // Pending NMI / IRQ are serviced between instructions
+                    if self.poll_interrupts() {
+                        continue;
+                    }
+                    self.fetch();
+                    self.state = State::Decode;
+                }
+                State::Decode => {
+                    self.decode();
+                    self.state = State::Execute;
+                }
+                State::Execute => {
+                    self.execute();
+                    // WAI and STP leave the processor halted
+                    if self.state == State::Execute {
+                        self.state = State::Fetch;
+                    }
+                }
+                State::Wait => {
+                    // WAI sleeps until an interrupt line is asserted, the devices keep running
+                    if self.nmi_pending || self.irq_asserted() {
+                        self.state = State::Fetch;
+                    } else {
+                        self.cycles += 1;
+                    }
+                }
+                State::Exit | State::Jam | State::Stop => {
+                    break;
+                }
+            }
+
+            // Devices follow the cycles spent by the instruction or the interrupt sequence
+            if self.cycles != clocked {
+                self.bus.tick(self.cycles - clocked);
+                clocked = self.cycles;
+            }
+
+            // Conditions raised by the instruction
+            if let Some(error) = self.fault.take() {
+                self.handle_error(error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn step(&mut self) -> Result<StepResult, CpuError> {
+        let cycles = self.cycles;
+        let mut result = StepResult::new(self.program_address(self.pc), self.state);
+
+        if self.state == State::Wait {
+            // WAI sleeps until an interrupt line is asserted
+            if !self.nmi_pending && !self.irq_asserted() {
+                return Ok(result);
+            }
+            self.state = State::Fetch;
+        }
+        if matches!(self.state, State::Exit | State::Jam | State::Stop) {
+            return Ok(result);
+        }
+
+        // Pending NMI / IRQ : the step is the interrupt sequence
+        let nmi = self.nmi_pending;
+        if self.poll_interrupts() {
+            result.mnemonic = if nmi { "NMI" } else { "IRQ" };
+            result.interrupt = true;
+        } else {
+            self.fetch();
+            self.decode();
+            result.opcode = self.data as u8;
+
+            if let Some(instruction) = self.instruction_reg {
+                result.mnemonic = instruction.name;
+                result.length = instruction.length;
+                // The table holds 8-bit immediates, 16-bit registers take one more byte
+                let wide = match instruction.mode {
+                    AddressingMode::Immediate => self.memory_16bit(),
+                    AddressingMode::ImmediateIndex => self.index_16bit(),
+                    _ => false,
+                };
+                if wide {
+                    result.length += 1;
+                }
+            }
+            for offset in 1..result.length {
+                let address = self.program_address(self.pc.wrapping_add(offset as u16));
+                result.operand |= (self.peek_long(address) as u32) << (8 * (offset - 1));
+            }
+
+            self.state = State::Execute;
+            self.execute();
+            if self.state == State::Execute {
+                self.state = State::Fetch;
+            }
+
+            if let Some(instruction) = self.instruction_reg {
+                if !matches!(
+                    instruction.mode,
+                    AddressingMode::Implied | AddressingMode::Accumulator
+                ) {
+                    result.effective_address = Some(self.address);
+                }
+                // Taken branches cost more than the base cycles, BRL always branches
+                result.branch_taken = match instruction.mode {
+                    AddressingMode::Relative => self.cycles - cycles > instruction.cycle as u64,
+                    AddressingMode::RelativeLong => instruction.name == "BRL",
+                    _ => false,
+                };
+            }
+        }
+
+        result.cycles = self.cycles - cycles;
+        self.bus.tick(result.cycles);
+        if let Some(error) = self.fault.take() {
+            self.handle_error(error)?;
+        }
+        result.state = self.state;
+        Ok(result)
+    }
+
+    fn idle(&mut self) -> bool {
+        self.cycles += 1;
+        self.bus.tick(1);
+        if self.nmi_pending || self.irq_asserted() {
+            self.state = State::Fetch;
+            return true;
+        }
+        false
+    }
+}
+
+/** Errors */
+impl<B: IBus> CPU65816<B> {
+    /*
+       Apply the configured policy to a raised condition, the 65C816 only raises unknown op codes.
+       Returns the error when the host has to see it (Halt and Trap).
+    */
+    pub fn handle_error(&mut self, error: CpuError) -> Result<(), CpuError> {
+        self.policies.resolve(error, &mut self.pc, &mut self.state)
+    }
+}
+
+/** Interrupts */
+impl<B: IBus> CPU65816<B> {
+    pub fn set_irq(&mut self, active: bool) {
+        self.irq_line = active;
+    }
+
+    /* IRQ is asserted by the host (set_irq) or by a device on the bus */
+    pub fn irq_asserted(&self) -> bool {
+        self.irq_line || self.bus.irq()
+    }
+
+    /* NMI is edge triggered : a low to high transition latches a pending NMI */
+    pub fn set_nmi(&mut self, active: bool) {
+        if active && !self.nmi_line {
+            self.nmi_pending = true;
+        }
+        self.nmi_line = active;
+    }
+
+    /*
+       Check interrupt lines between instructions. NMI has priority over IRQ.
+       Returns true when an interrupt sequence was entered.
+    */
+    pub fn poll_interrupts(&mut self) -> bool {
+        if self.nmi_pending {
+            self.nmi_pending = false;
+            let vector = if self.emulation {
+                NMI_VECTOR
+            } else {
+                NATIVE_NMI_VECTOR
+            };
+            self.interrupt(vector, false);
+            self.cycles += 7;
+            return true;
+        }
+
+        if self.irq_asserted() && self.status.get_interrupt_disable() == 0 {
+            let vector = if self.emulation {
+                IRQ_VECTOR
+            } else {
+                NATIVE_IRQ_VECTOR
+            };
+            self.interrupt(vector, false);
+            self.cycles += 7;
+            return true;
+        }
+
+        false
+    }
+
+    /*
+       Interrupt sequence shared by BRK, COP, IRQ and NMI.
+       Native mode pushes K, PCH, PCL and P, emulation mode pushes PCH, PCL and P with B.
+       I is set, D is cleared and the handler runs in bank $00.
+    */
+    pub fn interrupt(&mut self, vector: u16, break_command: bool) {
+        if self.emulation {
+            self.push((self.pc >> 8) as u8);
+            self.push((self.pc & 0x00FF) as u8);
+
+            // B set for BRK only, unused bit always set
+            let mut status = (self.status.get_status() & !0x10) | 0x20;
+            if break_command {
+                status |= 0x10;
+            }
+            self.push(status);
+        } else {
+            self.push(self.program_bank);
+            self.push((self.pc >> 8) as u8);
+            self.push((self.pc & 0x00FF) as u8);
+            self.push(self.status.get_status());
+            // One more cycle to push the program bank
+            self.cycles += 1;
+        }
+
+        self.status.set_interrupt_disable();
+        self.status.unset_decimal_mode();
+
+        let low = self.read_long(vector as u32) as u16;
+        let high = self.read_long(vector.wrapping_add(1) as u32) as u16;
+        self.program_bank = 0x00;
+        self.pc = high << 8 | low;
+    }
+}
+
+/** Stack */
+impl<B: IBus> CPU65816<B> {
+    /* Push one byte, the stack stays on page one in emulation mode */
+    pub fn push(&mut self, data: u8) {
+        self.write_long(self.sp as u32, data);
+        self.sp = self.sp.wrapping_sub(1);
+        if self.emulation {
+            self.sp = 0x0100 | (self.sp & 0x00FF);
+        }
+    }
+
+    pub fn pull(&mut self) -> u8 {
+        self.sp = self.sp.wrapping_add(1);
+        if self.emulation {
+            self.sp = 0x0100 | (self.sp & 0x00FF);
+        }
+        self.read_long(self.sp as u32)
+    }
+
+    /* Push a 16-bit value, high order byte first */
+    pub fn push_word(&mut self, data: u16) {
+        self.push((data >> 8) as u8);
+        self.push((data & 0x00FF) as u8);
+    }
+
+    pub fn pull_word(&mut self) -> u16 {
+        let low = self.pull() as u16;
+        let high = self.pull() as u16;
+        high << 8 | low
+    }
+
+    /* Push 8 or 16 bits, the second byte costs one cycle */
+    pub fn push_data(&mut self, data: u16, wide: bool) {
+        if wide {
+            self.cycles += 1;
+            self.push_word(data);
+        } else {
+            self.push((data & 0x00FF) as u8);
+        }
+    }
+
+    pub fn pull_data(&mut self, wide: bool) -> u16 {
+        if wide {
+            self.cycles += 1;
+            self.pull_word()
+        } else {
+            self.pull() as u16
+        }
+    }
+}
+
+impl<B: IBus> CPU65816<B> {
+    pub(crate) fn execute(&mut self) {
+        match self.instruction_reg {
+            Some(instruction) => {
+                // Base cost, width, direct page and page-cross penalties are added by the operation
+                self.cycles += instruction.cycle as u64;
+
+                match instruction.code {
+                    /* ADC - ADd with Carry */
+                    0x61 | 0x63 | 0x65 | 0x67 | 0x69 | 0x6D | 0x6F | 0x71 | 0x72 | 0x73 | 0x75
+                    | 0x77 | 0x79 | 0x7D | 0x7F => {
+                        self.adc(&instruction.mode);
+                    }
+                    /* AND - bitwise AND with accumulator */
+                    0x21 | 0x23 | 0x25 | 0x27 | 0x29 | 0x2D | 0x2F | 0x31 | 0x32 | 0x33 | 0x35
+                    | 0x37 | 0x39 | 0x3D | 0x3F => {
+                        self.and(&instruction.mode);
+                    }
+                    /* ASL - Arithmetic Shift Left */
+                    0x06 | 0x0A | 0x0E | 0x16 | 0x1E => {
+                        self.asl(&instruction.mode);
+                    }
+                    /* BCC - Branch on Carry Clear */
+                    0x90 => {
+                        self.bcc(&instruction.mode);
+                    }
+                    /* BCS - Branch on Carry Set */
+                    0xB0 => {
+                        self.bcs(&instruction.mode);
+                    }
+                    /* BEQ - Branch on EQual */
+                    0xF0 => {
+                        self.beq(&instruction.mode);
+                    }
+                    /* BIT - test BITs */
+                    0x24 | 0x2C | 0x34 | 0x3C | 0x89 => {
+                        self.bit(&instruction.mode);
+                    }
+                    /* BMI - Branch on MInus */
+                    0x30 => {
+                        self.bmi(&instruction.mode);
+                    }
+                    /* BNE - Branch on Not Equal */
+                    0xD0 => {
+                        self.bne(&instruction.mode);
+                    }
+                    /* BPL - Branch on PLus */
+                    0x10 => {
+                        self.bpl(&instruction.mode);
+                    }
+                    /* BRA - BRanch Always */
+                    0x80 => {
+                        self.bra(&instruction.mode);
+                    }
+                    /* BRK - BReaK */
+                    0x00 => {
+                        self.brk(&instruction.mode);
+                    }
+                    /* BRL - BRanch Long */
+                    0x82 => {
+                        self.brl(&instruction.mode);
+                    }
+                    /* BVC - Branch on oVerflow Clear */
+                    0x50 => {
+                        self.bvc(&instruction.mode);
+                    }
+                    /* BVS - Branch on oVerflow Set */
+                    0x70 => {
+                        self.bvs(&instruction.mode);
+                    }
+                    /* CLC - CLear Carry */
+                    0x18 => {
+                        self.clc(&instruction.mode);
+                    }
+                    /* CLD - CLear Decimal */
+                    0xD8 => {
+                        self.cld(&instruction.mode);
+                    }
+                    /* CLI - CLear Interrupt disable */
+                    0x58 => {
+                        self.cli(&instruction.mode);
+                    }
+                    /* CLV - CLear oVerflow */
+                    0xB8 => {
+                        self.clv(&instruction.mode);
+                    }
+                    /* CMP - CoMPare accumulator */
+                    0xC1 | 0xC3 | 0xC5 | 0xC7 | 0xC9 | 0xCD | 0xCF | 0xD1 | 0xD2 | 0xD3 | 0xD5
+                    | 0xD7 | 0xD9 | 0xDD | 0xDF => {
+                        self.cmp(&instruction.mode);
+                    }
+                    /* COP - COProcessor interrupt */
+                    0x02 => {
+                        self.cop(&instruction.mode);
+                    }
+                    /* CPX - ComPare X register */
+                    0xE0 | 0xE4 | 0xEC => {
+                        self.cpx(&instruction.mode);
+                    }
+                    /* CPY - ComPare Y register */
+                    0xC0 | 0xC4 | 0xCC => {
+                        self.cpy(&instruction.mode);
+                    }
+                    /* DEC - DECrement */
+                    0x3A | 0xC6 | 0xCE | 0xD6 | 0xDE => {
+                        self.dec(&instruction.mode);
+                    }
+                    /* DEX - DEcrement X */
+                    0xCA => {
+                        self.dex(&instruction.mode);
+                    }
+                    /* DEY - DEcrement Y */
+                    0x88 => {
+                        self.dey(&instruction.mode);
+                    }
+                    /* EOR - bitwise Exclusive OR */
+                    0x41 | 0x43 | 0x45 | 0x47 | 0x49 | 0x4D | 0x4F | 0x51 | 0x52 | 0x53 | 0x55
+                    | 0x57 | 0x59 | 0x5D | 0x5F => {
+                        self.eor(&instruction.mode);
+                    }
+                    /* INC - INCrement */
+                    0x1A | 0xE6 | 0xEE | 0xF6 | 0xFE => {
+                        self.inc(&instruction.mode);
+                    }
+                    /* INX - INcrement X */
+                    0xE8 => {
+                        self.inx(&instruction.mode);
+                    }
+                    /* INY - INcrement Y */
+                    0xC8 => {
+                        self.iny(&instruction.mode);
+                    }
+                    /* JML - JuMp Long */
+                    0x5C | 0xDC => {
+                        self.jml(&instruction.mode);
+                    }
+                    /* JMP - JuMP */
+                    0x4C | 0x6C | 0x7C => {
+                        self.jmp(&instruction.mode);
+                    }
+                    /* JSL - Jump to Subroutine Long */
+                    0x22 => {
+                        self.jsl(&instruction.mode);
+                    }
+                    /* JSR - Jump to SubRoutine */
+                    0x20 | 0xFC => {
+                        self.jsr(&instruction.mode);
+                    }
+                    /* LDA - LoaD Accumulator */
+                    0xA1 | 0xA3 | 0xA5 | 0xA7 | 0xA9 | 0xAD | 0xAF | 0xB1 | 0xB2 | 0xB3 | 0xB5
+                    | 0xB7 | 0xB9 | 0xBD | 0xBF => {
+                        self.lda(&instruction.mode);
+                    }
+                    /* LDX - LoaD X register */
+                    0xA2 | 0xA6 | 0xAE | 0xB6 | 0xBE => {
+                        self.ldx(&instruction.mode);
+                    }
+                    /* LDY - LoaD Y register */
+                    0xA0 | 0xA4 | 0xAC | 0xB4 | 0xBC => {
+                        self.ldy(&instruction.mode);
+                    }
+                    /* LSR - Logical Shift Right */
+                    0x46 | 0x4A | 0x4E | 0x56 | 0x5E => {
+                        self.lsr(&instruction.mode);
+                    }
+                    /* MVN - MoVe block Negative (incrementing) */
+                    0x54 => {
+                        self.mvn(&instruction.mode);
+                    }
+                    /* MVP - MoVe block Positive (decrementing) */
+                    0x44 => {
+                        self.mvp(&instruction.mode);
+                    }
+                    /* NOP - No OPeration */
+                    0xEA => {
+                        self.nop(&instruction.mode);
+                    }
+                    /* ORA - bitwise OR with Accumulator */
+                    0x01 | 0x03 | 0x05 | 0x07 | 0x09 | 0x0D | 0x0F | 0x11 | 0x12 | 0x13 | 0x15
+                    | 0x17 | 0x19 | 0x1D | 0x1F => {
+                        self.ora(&instruction.mode);
+                    }
+                    /* PEA - Push Effective Absolute address */
+                    0xF4 => {
+                        self.pea(&instruction.mode);
+                    }
+                    /* PEI - Push Effective Indirect address */
+                    0xD4 => {
+                        self.pei(&instruction.mode);
+                    }
+                    /* PER - Push Effective Relative address */
+                    0x62 => {
+                        self.per(&instruction.mode);
+                    }
+                    /* PHA - PusH Accumulator */
+                    0x48 => {
+                        self.pha(&instruction.mode);
+                    }
+                    /* PHB - PusH data Bank register */
+                    0x8B => {
+                        self.phb(&instruction.mode);
+                    }
+                    /* PHD - PusH Direct page register */
+                    0x0B => {
+                        self.phd(&instruction.mode);
+                    }
+                    /* PHK - PusH program banK register */
+                    0x4B => {
+                        self.phk(&instruction.mode);
+                    }
+                    /* PHP - PusH Processor status */
+                    0x08 => {
+                        self.php(&instruction.mode);
+                    }
+                    /* PHX - PusH X register */
+                    0xDA => {
+                        self.phx(&instruction.mode);
+                    }
+                    /* PHY - PusH Y register */
+                    0x5A => {
+                        self.phy(&instruction.mode);
+                    }
+                    /* PLA - PuLl Accumulator */
+                    0x68 => {
+                        self.pla(&instruction.mode);
+                    }
+                    /* PLB - PuLl data Bank register */
+                    0xAB => {
+                        self.plb(&instruction.mode);
+                    }
+                    /* PLD - PuLl Direct page register */
+                    0x2B => {
+                        self.pld(&instruction.mode);
+                    }
+                    /* PLP - PuLl Processor status */
+                    0x28 => {
+                        self.plp(&instruction.mode);
+                    }
+                    /* PLX - PuLl X register */
+                    0xFA => {
+                        self.plx(&instruction.mode);
+                    }
+                    /* PLY - PuLl Y register */
+                    0x7A => {
+                        self.ply(&instruction.mode);
+                    }
+                    /* REP - REset Processor status bits */
+                    0xC2 => {
+                        self.rep(&instruction.mode);
+                    }
+                    /* ROL - ROtate Left */
+                    0x26 | 0x2A | 0x2E | 0x36 | 0x3E => {
+                        self.rol(&instruction.mode);
+                    }
+                    /* ROR - ROtate Right */
+                    0x66 | 0x6A | 0x6E | 0x76 | 0x7E => {
+                        self.ror(&instruction.mode);
+                    }
+                    /* RTI - ReTurn from Interrupt */
+                    0x40 => {
+                        self.rti(&instruction.mode);
+                    }
+                    /* RTL - ReTurn from subroutine Long */
+                    0x6B => {
+                        self.rtl(&instruction.mode);
+                    }
+                    /* RTS - ReTurn from Subroutine */
+                    0x60 => {
+                        self.rts(&instruction.mode);
+                    }
+                    /* SBC - SuBtract with Carry */
+                    0xE1 | 0xE3 | 0xE5 | 0xE7 | 0xE9 | 0xED | 0xEF | 0xF1 | 0xF2 | 0xF3 | 0xF5
+                    | 0xF7 | 0xF9 | 0xFD | 0xFF => {
+                        self.sbc(&instruction.mode);
+                    }
+                    /* SEC - SEt Carry */
+                    0x38 => {
+                        self.sec(&instruction.mode);
+                    }
+                    /* SED - SEt Decimal */
+                    0xF8 => {
+                        self.sed(&instruction.mode);
+                    }
+                    /* SEI - SEt Interrupt disable */
+                    0x78 => {
+                        self.sei(&instruction.mode);
+                    }
+                    /* SEP - SEt Processor status bits */
+                    0xE2 => {
+                        self.sep(&instruction.mode);
+                    }
+                    /* STA - STore Accumulator */
+                    0x81 | 0x83 | 0x85 | 0x87 | 0x8D | 0x8F | 0x91 | 0x92 | 0x93 | 0x95 | 0x97
+                    | 0x99 | 0x9D | 0x9F => {
+                        self.sta(&instruction.mode);
+                    }
+                    /* STP - SToP the processor */
+                    0xDB => {
+                        self.stp(&instruction.mode);
+                    }
+                    /* STX - STore X register */
+                    0x86 | 0x8E | 0x96 => {
+                        self.stx(&instruction.mode);
+                    }
+                    /* STY - STore Y register */
+                    0x84 | 0x8C | 0x94 => {
+                        self.sty(&instruction.mode);
+                    }
+                    /* STZ - STore Zero */
+                    0x64 | 0x74 | 0x9C | 0x9E => {
+                        self.stz(&instruction.mode);
+                    }
+                    /* TAX - Transfer A to X */
+                    0xAA => {
+                        self.tax(&instruction.mode);
+                    }
+                    /* TAY - Transfer A to Y */
+                    0xA8 => {
+                        self.tay(&instruction.mode);
+                    }
+                    /* TCD - Transfer C to Direct page */
+                    0x5B => {
+                        self.tcd(&instruction.mode);
+                    }
+                    /* TCS - Transfer C to Stack pointer */
+                    0x1B => {
+                        self.tcs(&instruction.mode);
+                    }
+                    /* TDC - Transfer Direct page to C */
+                    0x7B => {
+                        self.tdc(&instruction.mode);
+                    }
+                    /* TRB - Test and Reset Bits */
+                    0x14 | 0x1C => {
+                        self.trb(&instruction.mode);
+                    }
+                    /* TSB - Test and Set Bits */
+                    0x04 | 0x0C => {
+                        self.tsb(&instruction.mode);
+                    }
+                    /* TSC - Transfer Stack pointer to C */
+                    0x3B => {
+                        self.tsc(&instruction.mode);
+                    }
+                    /* TSX - Transfer Stack pointer to X */
+                    0xBA => {
+                        self.tsx(&instruction.mode);
+                    }
+                    /* TXA - Transfer X to A */
+                    0x8A => {
+                        self.txa(&instruction.mode);
+                    }
+                    /* TXS - Transfer X to Stack pointer */
+                    0x9A => {
+                        self.txs(&instruction.mode);
+                    }
+                    /* TXY - Transfer X to Y */
+                    0x9B => {
+                        self.txy(&instruction.mode);
+                    }
+                    /* TYA - Transfer Y to A */
+                    0x98 => {
+                        self.tya(&instruction.mode);
+                    }
+                    /* TYX - Transfer Y to X */
+                    0xBB => {
+                        self.tyx(&instruction.mode);
+                    }
+                    /* WAI - WAit for Interrupt */
+                    0xCB => {
+                        self.wai(&instruction.mode);
+                    }
+                    /* WDM - reserved for future expansion */
+                    0x42 => {
+                        self.wdm(&instruction.mode);
+                    }
+                    /* XBA - eXchange B and A */
+                    0xEB => {
+                        self.xba(&instruction.mode);
+                    }
+                    /* XCE - eXchange Carry and Emulation */
+                    0xFB => {
+                        self.xce(&instruction.mode);
+                    }
+                }
+            }
+            None => {
+                // The 65C816 table is complete, only a corrupted table gets here
+                let pc = self.pc;
+                let bytes = [
+                    self.peek_long(self.program_address(pc)),
+                    self.peek_long(self.program_address(pc.wrapping_add(1))),
+                    self.peek_long(self.program_address(pc.wrapping_add(2))),
+                ];
+                self.fault = Some(CpuError::UnknownOpcode { pc, bytes });
+                self.state = State::Exit;
+            }
+        }
+    }
+}
@@ -1,5 +1,82 @@
-pub trait IBus {
-    fn read(&self, address: &u16) -> u8;
-
-    fn write(&mut self, address: &u16, data: u8);
-}
+pub trait IBus {
+    fn read(&self, address: &u16) -> u8;
+
+    fn write(&mut self, address: &u16, data: u8);
+
+    /* 16-bit access, low order byte first */
+    fn read_word(&self, address: &u16) -> u16 {
+        let low = self.read(address) as u16;
+        let high = self.read(&address.wrapping_add(1)) as u16;
+        (high << 8) | low
+    }
+
+    fn write_word(&mut self, address: &u16, data: u16) {
+        self.write(address, (data & 0x00FF) as u8);
+        self.write(&address.wrapping_add(1), (data >> 8) as u8);
+    }
+
+    /* Pointer in page zero : the high byte of ($FF) is read from $00 */
+    fn read_word_zp(&self, address: u8) -> u16 {
+        let low = self.read(&(address as u16)) as u16;
+        let high = self.read(&(address.wrapping_add(1) as u16)) as u16;
+        (high << 8) | low
+    }
+
+    /* NMOS JMP indirect : no carry into the high byte, ($10FF) reads its high byte from $1000 */
+    fn read_word_bug(&self, address: &u16) -> u16 {
+        let next = address & 0xFF00 | address.wrapping_add(1) & 0x00FF;
+        let low = self.read(address) as u16;
+        let high = self.read(&next) as u16;
+        (high << 8) | low
+    }
+
+    /* Read without side effects, used by tracing and debuggers */
+    fn peek(&self, address: &u16) -> u8 {
+        self.read(address)
+    }
+
+    /* Write that reaches ROM too, used by loaders and debuggers */
+    fn poke(&mut self, address: &u16, data: u8) {
+        self.write(address, data);
+    }
+
+    /* Writes to ROM are ignored, the processor reports them as a bus fault */
+    fn writable(&self, _address: &u16) -> bool {
+        true
+    }
+
+    /* Op code fetches outside of code regions are reported as a bus fault */
+    fn executable(&self, _address: &u16) -> bool {
+        true
+    }
+
+    /* Clock the devices on the bus with the CPU cycles elapsed since the previous call */
+    fn tick(&mut self, _cycles: u64) {}
+
+    /* IRQ asserted by a device on the bus */
+    fn irq(&self) -> bool {
+        false
+    }
+
+    /*
+       24-bit access used by the 65C816 : bank byte in bits 16-23.
+       Buses with a 16-bit address space ignore the bank byte.
+    */
+    fn read_long(&self, address: u32) -> u8 {
+        self.read(&(address as u16))
+    }
+
+    fn write_long(&mut self, address: u32, data: u8) {
+        self.write(&(address as u16), data);
+    }
+
+    /* 24-bit read without side effects, buses overriding read_long override it too */
+    fn peek_long(&self, address: u32) -> u8 {
+        self.peek(&(address as u16))
+    }
+}
+
+/* 16-bit address passed by value or by reference */
+pub trait BusAddress {
+    fn address(self) -> u16;
+}
@@ -1,5 +1,80 @@
+use crate::cpu::model::{CpuError, State, StepResult, StopReason};
+
 pub trait ICPU {
     fn reset(&mut self);
 
-    fn run(&mut self);
+    /*
+       Run until the processor halts or a condition is returned by its policy.
+       After WAI the devices keep being clocked until one of them or a pending NMI wakes it,
+       use run_for_cycles or run_until when only the host can raise the interrupt.
+    */
+    fn run(&mut self) -> Result<(), CpuError>;
+
+    /*
+       Execute exactly one instruction, or service one pending interrupt.
+       A halted or sleeping processor returns a result with no cycles.
+    */
+    fn step(&mut self) -> Result<StepResult, CpuError>;
+
+    /*
+       One clock of the Wait state : the devices are ticked and an asserted interrupt line
+       wakes the processor. Returns true once it is awake.
+       Processors without devices to clock keep sleeping through the budget of run_for_cycles.
+    */
+    fn idle(&mut self) -> bool {
+        false
+    }
+
+    /*
+       Run for a budget of cycles, e.g. one NES frame of 29780 cycles.
+       Instructions are never split, so the budget may be overshot by the last one.
+       After WAI the rest of the budget is spent idle until a device or the host raises an interrupt.
+    */
+    fn run_for_cycles(&mut self, budget: u64) -> Result<StopReason, CpuError> {
+        let mut spent = 0;
+        while spent < budget {
+            let result = self.step()?;
+            spent += result.cycles;
+            if result.state == State::Wait {
+                loop {
+                    if spent >= budget {
+                        return Ok(StopReason::CyclesElapsed(spent));
+                    }
+                    spent += 1;
+                    if self.idle() {
+                        break;
+                    }
+                }
+                continue;
+            }
+            if is_halted(result.state) {
+                return Ok(StopReason::Halted(result.state));
+            }
+        }
+        Ok(StopReason::CyclesElapsed(spent))
+    }
+
+    /*
+       Run until the condition holds, checked after every step.
+       e.g. |cpu| cpu.pc == 0x8000, or a counter captured by the closure for an instruction count.
+    */
+    fn run_until<F>(&mut self, mut condition: F) -> Result<StopReason, CpuError>
+    where
+        F: FnMut(&Self) -> bool,
+        Self: Sized,
+    {
+        loop {
+            let result = self.step()?;
+            if condition(self) {
+                return Ok(StopReason::Condition);
+            }
+            if is_halted(result.state) {
+                return Ok(StopReason::Halted(result.state));
+            }
+        }
+    }
+}
+
+fn is_halted(state: State) -> bool {
+    matches!(state, State::Wait | State::Stop | State::Jam | State::Exit)
 }
@@ -0,0 +1,32 @@
+/*
+   Peripheral mapped over a range of the address bus : RAM, ROM, video or sound chips, timers ...
+   Addresses are relative to the start of the range, after the mirroring mask.
+*/
+pub trait Device {
+    fn read(&mut self, address: &u16) -> u8;
+
+    fn write(&mut self, address: &u16, data: u8);
+
+    /* Read without side effects, used by tracing and debuggers */
+    fn peek(&self, _address: &u16) -> u8 {
+        0x00
+    }
+
+    /* Write that reaches ROM too, used by loaders and debuggers */
+    fn poke(&mut self, address: &u16, data: u8) {
+        self.write(address, data);
+    }
+
+    /* False where writes are ignored, ROM for instance */
+    fn writable(&self, _address: &u16) -> bool {
+        true
+    }
+
+    /* Called with the CPU cycles elapsed since the previous call */
+    fn tick(&mut self, _cycles: u64) {}
+
+    /* Level of the IRQ output, ORed into the CPU IRQ line */
+    fn irq(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,55 @@
+use crate::mapper::model::{BankWindow, Mirroring};
+
+/*
+   Cartridge or memory board switching banks of a larger memory into fixed CPU windows.
+   Addresses are absolute CPU addresses, the mapper decodes its own registers.
+*/
+pub trait Mapper {
+    fn cpu_read(&mut self, address: u16) -> u8;
+
+    fn cpu_write(&mut self, address: u16, data: u8);
+
+    /* Read without side effects : no hotspot is triggered */
+    fn cpu_peek(&self, address: u16) -> u8;
+
+    /* Store into the memory mapped at the address, ROM included, without touching registers */
+    fn cpu_poke(&mut self, address: u16, data: u8);
+
+    /* False where writes are ignored, reported by the processor as a bus fault */
+    fn cpu_writable(&self, _address: u16) -> bool {
+        true
+    }
+
+    /* Video chip side, the NES pattern tables */
+    fn chr_read(&self, _address: u16) -> u8 {
+        0x00
+    }
+
+    fn chr_write(&mut self, _address: u16, _data: u8) {}
+
+    /* NES nametable arrangement, fixed by the board or switched by the mapper */
+    fn mirroring(&self) -> Mirroring {
+        Mirroring::Horizontal
+    }
+
+    /* Video chip side, clocked once per rendered scanline (rising edge of PPU A12) */
+    fn scanline(&mut self) {}
+
+    /* Banks currently mapped in each window, for debuggers */
+    fn layout(&self) -> Vec<BankWindow>;
+
+    /* Register state of the board, restored by load_state */
+    fn save_state(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    /* Missing bytes read as zero, the bank windows follow the restored registers */
+    fn load_state(&mut self, _state: &[u8]) {}
+
+    /* Called with the CPU cycles elapsed since the previous call */
+    fn tick(&mut self, _cycles: u64) {}
+
+    fn irq(&self) -> bool {
+        false
+    }
+}
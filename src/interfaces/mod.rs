@@ -1,2 +1,4 @@
 pub mod bus;
 pub mod cpu;
+pub mod device;
+pub mod mapper;
@@ -1,5 +1,8 @@
 pub mod bus;
+pub mod constants;
 pub mod cpu;
 pub mod datas;
 pub mod interfaces;
+pub mod loader;
+pub mod mapper;
 pub mod ram;
@@ -0,0 +1,74 @@
+use std::fs;
+use std::path::Path;
+
+use crate::bus::model::Bus;
+use crate::constants::RESET_VECTOR;
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+use crate::loader::model::{EntryPoint, LoadError};
+
+impl Bus {
+    /* Copy a raw image at load_address, ROM included */
+    pub fn load_binary(&mut self, bytes: &[u8], load_address: u16) -> Result<(), LoadError> {
+        copy_image(self, bytes, load_address)
+    }
+
+    pub fn load_binary_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        load_address: u16,
+    ) -> Result<(), LoadError> {
+        self.load_binary(&fs::read(path)?, load_address)
+    }
+}
+
+impl<B: IBus> CPU<B> {
+    /* Copy a raw image at load_address, then set PC or the reset vector */
+    pub fn load_binary(
+        &mut self,
+        bytes: &[u8],
+        load_address: u16,
+        entry: EntryPoint,
+    ) -> Result<(), LoadError> {
+        copy_image(&mut self.bus, bytes, load_address)?;
+        match entry {
+            EntryPoint::Keep => {}
+            EntryPoint::SetPc(address) => self.pc = address,
+            EntryPoint::ResetVector(address) => {
+                // Low order byte first
+                self.bus.poke(&RESET_VECTOR, (address & 0x00FF) as u8);
+                self.bus
+                    .poke(&RESET_VECTOR.wrapping_add(1), (address >> 8) as u8);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn load_binary_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        load_address: u16,
+        entry: EntryPoint,
+    ) -> Result<(), LoadError> {
+        self.load_binary(&fs::read(path)?, load_address, entry)
+    }
+}
+
+/* The whole image is checked before the first byte is written */
+pub(crate) fn copy_image<B: IBus>(
+    bus: &mut B,
+    bytes: &[u8],
+    load_address: u16,
+) -> Result<(), LoadError> {
+    if load_address as usize + bytes.len() > 0x10000 {
+        return Err(LoadError::Overflow {
+            address: load_address,
+            size: bytes.len(),
+        });
+    }
+
+    for (offset, byte) in bytes.iter().enumerate() {
+        bus.poke(&(load_address + offset as u16), *byte);
+    }
+    Ok(())
+}
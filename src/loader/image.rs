@@ -0,0 +1,119 @@
+use std::fs;
+use std::path::Path;
+
+use crate::cpu::model::CPU;
+use crate::interfaces::bus::IBus;
+use crate::loader::binary::copy_image;
+use crate::loader::model::{Chunk, Image, LoadError};
+
+impl Image {
+    pub fn new() -> Self {
+        Image {
+            chunks: Vec::new(),
+            entry: None,
+        }
+    }
+
+    /* Copy of $start-$end, read without side effects */
+    pub fn dump<B: IBus>(bus: &B, start: u16, end: u16, entry: Option<u16>) -> Self {
+        Image {
+            chunks: vec![Chunk {
+                address: start,
+                data: (start..=end).map(|address| bus.peek(&address)).collect(),
+            }],
+            entry,
+        }
+    }
+
+    /* Append a data record, extending the last chunk when the record follows it */
+    pub fn push(&mut self, address: u32, data: &[u8]) -> Result<(), LoadError> {
+        if address > 0xFFFF {
+            return Err(LoadError::OutOfRange(address));
+        }
+        if address as usize + data.len() > 0x10000 {
+            return Err(LoadError::Overflow {
+                address: address as u16,
+                size: data.len(),
+            });
+        }
+
+        let address = address as u16;
+        match self.chunks.last_mut() {
+            Some(chunk) if chunk.address as usize + chunk.data.len() == address as usize => {
+                chunk.data.extend_from_slice(data)
+            }
+            _ => self.chunks.push(Chunk {
+                address,
+                data: data.to_vec(),
+            }),
+        }
+        Ok(())
+    }
+
+    pub fn set_entry(&mut self, entry: u32) -> Result<(), LoadError> {
+        if entry > 0xFFFF {
+            return Err(LoadError::OutOfRange(entry));
+        }
+        self.entry = Some(entry as u16);
+        Ok(())
+    }
+
+    /* Store every chunk, ROM included */
+    pub fn write_to<B: IBus>(&self, bus: &mut B) -> Result<(), LoadError> {
+        for chunk in self.chunks.iter() {
+            copy_image(bus, &chunk.data, chunk.address)?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: IBus> CPU<B> {
+    /* Store the image and jump to its entry point when it has one */
+    pub fn load_image(&mut self, image: &Image) -> Result<Option<u16>, LoadError> {
+        image.write_to(&mut self.bus)?;
+        if let Some(entry) = image.entry {
+            self.pc = entry;
+        }
+        Ok(image.entry)
+    }
+
+    pub fn load_intel_hex(&mut self, text: &str) -> Result<Option<u16>, LoadError> {
+        self.load_image(&Image::parse_intel_hex(text)?)
+    }
+
+    pub fn load_intel_hex_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Option<u16>, LoadError> {
+        self.load_intel_hex(&fs::read_to_string(path)?)
+    }
+
+    pub fn load_srec(&mut self, text: &str) -> Result<Option<u16>, LoadError> {
+        self.load_image(&Image::parse_srec(text)?)
+    }
+
+    pub fn load_srec_file<P: AsRef<Path>>(&mut self, path: P) -> Result<Option<u16>, LoadError> {
+        self.load_srec(&fs::read_to_string(path)?)
+    }
+}
+
+/* Hex pairs of a record, without its start code */
+pub(crate) fn decode_hex(digits: &str, line: usize) -> Result<Vec<u8>, LoadError> {
+    if digits.is_empty() || !digits.len().is_multiple_of(2) || !digits.is_ascii() {
+        return Err(LoadError::BadRecord { line });
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|index| {
+            u8::from_str_radix(&digits[index..index + 2], 16)
+                .map_err(|_| LoadError::BadRecord { line })
+        })
+        .collect()
+}
+
+/* Big endian value of the address bytes */
+pub(crate) fn address_of(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0, |address, byte| (address << 8) | *byte as u32)
+}
@@ -0,0 +1,231 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::rc::Rc;
+
+use crate::constants::RESET_VECTOR;
+use crate::cpu::model::CPU;
+use crate::interfaces::mapper::Mapper;
+use crate::loader::model::{INes, INesHeader, LoadError};
+use crate::mapper::model::{AxRom, CnRom, Mirroring, Mmc1, Mmc3, Nrom, UxRom};
+
+const HEADER_SIZE: usize = 16;
+const TRAINER_SIZE: usize = 512;
+
+impl INesHeader {
+    pub fn parse(bytes: &[u8]) -> Result<Self, LoadError> {
+        if bytes.len() < HEADER_SIZE || &bytes[0..4] != b"NES\x1A" {
+            return Err(LoadError::BadMagic);
+        }
+
+        let flags6 = bytes[6];
+        let flags7 = bytes[7];
+        let nes2 = flags7 & 0x0C == 0x08;
+        let mirroring = if flags6 & 0x08 != 0 {
+            Mirroring::FourScreen
+        } else if flags6 & 0x01 != 0 {
+            Mirroring::Vertical
+        } else {
+            Mirroring::Horizontal
+        };
+
+        let header = if nes2 {
+            INesHeader {
+                prg_rom_size: nes2_rom_size(bytes[4], bytes[9] & 0x0F, 0x4000)?,
+                chr_rom_size: nes2_rom_size(bytes[5], bytes[9] >> 4, 0x2000)?,
+                prg_ram_size: nes2_ram_size(bytes[10] & 0x0F) + nes2_ram_size(bytes[10] >> 4),
+                mapper: (flags6 >> 4) as u16
+                    | (flags7 & 0xF0) as u16
+                    | ((bytes[8] & 0x0F) as u16) << 8,
+                submapper: bytes[8] >> 4,
+                mirroring,
+                battery: flags6 & 0x02 != 0,
+                trainer: flags6 & 0x04 != 0,
+                nes2,
+            }
+        } else {
+            // Old dumping tools wrote a signature in bytes 7-15 : ignore the upper nibble then
+            let upper = if bytes[12..16].iter().all(|byte| *byte == 0x00) {
+                flags7 & 0xF0
+            } else {
+                0x00
+            };
+            INesHeader {
+                prg_rom_size: bytes[4] as usize * 0x4000,
+                chr_rom_size: bytes[5] as usize * 0x2000,
+                // 0 means 8K for compatibility
+                prg_ram_size: (bytes[8].max(1)) as usize * 0x2000,
+                mapper: (flags6 >> 4 | upper) as u16,
+                submapper: 0,
+                mirroring,
+                battery: flags6 & 0x02 != 0,
+                trainer: flags6 & 0x04 != 0,
+                nes2,
+            }
+        };
+
+        // The reset vector lives in PRG-ROM
+        if header.prg_rom_size == 0 {
+            return Err(LoadError::InvalidHeader("no PRG-ROM"));
+        }
+        Ok(header)
+    }
+}
+
+/*
+   NES 2.0 ROM size : the MSB nibble extends the unit count,
+   $F selects the exponent-multiplier form 2^E * (MM * 2 + 1).
+*/
+fn nes2_rom_size(lsb: u8, msb: u8, unit: usize) -> Result<usize, LoadError> {
+    let size = if msb == 0x0F {
+        let exponent = (lsb >> 2) as u32;
+        let multiplier = (lsb & 0x03) as usize * 2 + 1;
+        1usize
+            .checked_shl(exponent)
+            .and_then(|size| size.checked_mul(multiplier))
+    } else {
+        ((msb as usize) << 8 | lsb as usize).checked_mul(unit)
+    };
+    size.ok_or(LoadError::InvalidHeader("ROM size out of range"))
+}
+
+/* NES 2.0 RAM size : 64 << shift bytes, 0 means none */
+fn nes2_ram_size(shift: u8) -> usize {
+    if shift == 0 {
+        0
+    } else {
+        64 << shift
+    }
+}
+
+impl INes {
+    pub fn parse(bytes: &[u8]) -> Result<Self, LoadError> {
+        let header = INesHeader::parse(bytes)?;
+
+        let mut offset = HEADER_SIZE;
+        let trainer_size = if header.trainer { TRAINER_SIZE } else { 0 };
+        // Sizes come from the file : a sum past usize can never be satisfied
+        let expected = offset
+            .checked_add(trainer_size)
+            .and_then(|size| size.checked_add(header.prg_rom_size))
+            .and_then(|size| size.checked_add(header.chr_rom_size))
+            .unwrap_or(usize::MAX);
+        if bytes.len() < expected {
+            return Err(LoadError::Truncated {
+                expected,
+                found: bytes.len(),
+            });
+        }
+
+        let trainer = if header.trainer {
+            offset += TRAINER_SIZE;
+            Some(bytes[HEADER_SIZE..offset].to_vec())
+        } else {
+            None
+        };
+        let prg_rom = bytes[offset..offset + header.prg_rom_size].to_vec();
+        offset += header.prg_rom_size;
+        let chr_rom = bytes[offset..offset + header.chr_rom_size].to_vec();
+
+        Ok(INes {
+            header,
+            trainer,
+            prg_rom,
+            chr_rom,
+        })
+    }
+
+    /* Board for the mapper number of the header */
+    pub fn mapper(&self) -> Result<Rc<RefCell<dyn Mapper>>, LoadError> {
+        let header = &self.header;
+        // The trainer sits at $7000 in PRG-RAM : UxROM, CNROM and AxROM have none
+        if self.trainer.is_some() && matches!(header.mapper, 2 | 3 | 7) {
+            return Err(LoadError::InvalidHeader(
+                "trainer on a board without PRG-RAM",
+            ));
+        }
+        let prg_rom = self.prg_rom.clone();
+        let chr_rom = self.chr_rom.clone();
+
+        let mapper: Rc<RefCell<dyn Mapper>> = match header.mapper {
+            0 => Rc::new(RefCell::new(Nrom::new(
+                prg_rom,
+                chr_rom,
+                header.prg_ram_size,
+                header.mirroring,
+            ))),
+            1 => Rc::new(RefCell::new(Mmc1::new(
+                prg_rom,
+                chr_rom,
+                header.prg_ram_size,
+            ))),
+            2 => Rc::new(RefCell::new(UxRom::new(prg_rom, chr_rom, header.mirroring))),
+            3 => Rc::new(RefCell::new(CnRom::new(prg_rom, chr_rom, header.mirroring))),
+            4 => Rc::new(RefCell::new(Mmc3::new(
+                prg_rom,
+                chr_rom,
+                header.prg_ram_size,
+                header.mirroring == Mirroring::FourScreen,
+            ))),
+            7 => Rc::new(RefCell::new(AxRom::new(prg_rom, chr_rom))),
+            number => return Err(LoadError::UnsupportedMapper(number)),
+        };
+
+        // Poked past the registers and the PRG-RAM protection
+        if let Some(trainer) = &self.trainer {
+            let mut board = mapper.borrow_mut();
+            for (offset, byte) in trainer.iter().enumerate() {
+                board.cpu_poke(0x7000 + offset as u16, *byte);
+            }
+        }
+        Ok(mapper)
+    }
+}
+
+impl CPU {
+    /*
+       Plug the cartridge of a .nes image over $6000-$FFFF and jump to its reset vector.
+       The mapper is returned for the video chip (CHR, mirroring) and debuggers.
+    */
+    pub fn load_nes(&mut self, bytes: &[u8]) -> Result<Rc<RefCell<dyn Mapper>>, LoadError> {
+        let mapper = INes::parse(bytes)?.mapper()?;
+        self.bus.attach_mapper(0x6000, 0xFFFF, mapper.clone());
+        self.pc = self.bus.read_word(RESET_VECTOR);
+        Ok(mapper)
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::BadMagic => write!(f, "not an iNES image"),
+            LoadError::Truncated { expected, found } => {
+                write!(
+                    f,
+                    "image truncated : {} bytes expected, {} found",
+                    expected, found
+                )
+            }
+            LoadError::InvalidHeader(reason) => write!(f, "invalid iNES header : {}", reason),
+            LoadError::UnsupportedMapper(number) => write!(f, "unsupported mapper {}", number),
+            LoadError::Overflow { address, size } => write!(
+                f,
+                "image of {} bytes at ${:04X} overflows $FFFF",
+                size, address
+            ),
+            LoadError::OutOfRange(address) => write!(f, "address ${:X} is past $FFFF", address),
+            LoadError::BadRecord { line } => write!(f, "malformed record on line {}", line),
+            LoadError::BadChecksum { line } => write!(f, "bad checksum on line {}", line),
+            LoadError::Io(message) => write!(f, "cannot read image : {}", message),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+impl From<io::Error> for LoadError {
+    fn from(error: io::Error) -> Self {
+        LoadError::Io(error.to_string())
+    }
+}
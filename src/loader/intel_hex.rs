@@ -0,0 +1,91 @@
+use crate::loader::image::{address_of, decode_hex};
+use crate::loader::model::{Image, LoadError};
+
+const DATA: u8 = 0x00;
+const END_OF_FILE: u8 = 0x01;
+const EXTENDED_SEGMENT_ADDRESS: u8 = 0x02;
+const START_SEGMENT_ADDRESS: u8 = 0x03;
+const EXTENDED_LINEAR_ADDRESS: u8 = 0x04;
+const START_LINEAR_ADDRESS: u8 = 0x05;
+
+/* Data bytes per record written */
+const RECORD_SIZE: usize = 16;
+
+/*
+   Intel HEX : ":" count, address (2 bytes), type, data, checksum.
+   The checksum is the two's complement of the sum of the other bytes.
+   Ref : https://en.wikipedia.org/wiki/Intel_HEX
+*/
+impl Image {
+    pub fn parse_intel_hex(text: &str) -> Result<Self, LoadError> {
+        let mut image = Image::new();
+        // Upper address bits set by the extended address records
+        let mut base: u32 = 0;
+
+        for (index, record) in text.lines().enumerate() {
+            let line = index + 1;
+            let record = record.trim();
+            if record.is_empty() {
+                continue;
+            }
+            let digits = record
+                .strip_prefix(':')
+                .ok_or(LoadError::BadRecord { line })?;
+            let bytes = decode_hex(digits, line)?;
+            if bytes.len() < 5 || bytes.len() != bytes[0] as usize + 5 {
+                return Err(LoadError::BadRecord { line });
+            }
+            if bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) != 0 {
+                return Err(LoadError::BadChecksum { line });
+            }
+
+            let offset = address_of(&bytes[1..3]);
+            let data = &bytes[4..bytes.len() - 1];
+            match bytes[3] {
+                DATA => image.push(base + offset, data)?,
+                END_OF_FILE => break,
+                EXTENDED_SEGMENT_ADDRESS if data.len() == 2 => base = address_of(data) << 4,
+                EXTENDED_LINEAR_ADDRESS if data.len() == 2 => base = address_of(data) << 16,
+                // CS:IP
+                START_SEGMENT_ADDRESS if data.len() == 4 => {
+                    image.set_entry((address_of(&data[0..2]) << 4) + address_of(&data[2..4]))?
+                }
+                START_LINEAR_ADDRESS if data.len() == 4 => image.set_entry(address_of(data))?,
+                _ => return Err(LoadError::BadRecord { line }),
+            }
+        }
+        Ok(image)
+    }
+
+    /* 16 bytes per data record, the entry point as a start linear address record */
+    pub fn to_intel_hex(&self) -> String {
+        let mut text = String::new();
+        for chunk in self.chunks.iter() {
+            for (index, data) in chunk.data.chunks(RECORD_SIZE).enumerate() {
+                let address = chunk.address.wrapping_add((index * RECORD_SIZE) as u16);
+                text.push_str(&intel_hex_record(address, DATA, data));
+            }
+        }
+        if let Some(entry) = self.entry {
+            let data = [0x00, 0x00, (entry >> 8) as u8, (entry & 0x00FF) as u8];
+            text.push_str(&intel_hex_record(0x0000, START_LINEAR_ADDRESS, &data));
+        }
+        text.push_str(&intel_hex_record(0x0000, END_OF_FILE, &[]));
+        text
+    }
+}
+
+fn intel_hex_record(address: u16, kind: u8, data: &[u8]) -> String {
+    let mut bytes = vec![
+        data.len() as u8,
+        (address >> 8) as u8,
+        (address & 0x00FF) as u8,
+        kind,
+    ];
+    bytes.extend_from_slice(data);
+    let sum = bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    bytes.push(sum.wrapping_neg());
+
+    let digits: String = bytes.iter().map(|byte| format!("{:02X}", byte)).collect();
+    format!(":{}\n", digits)
+}
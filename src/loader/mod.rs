@@ -0,0 +1,9 @@
+pub mod binary;
+pub mod image;
+pub mod ines;
+pub mod intel_hex;
+pub mod model;
+pub mod srec;
+
+#[cfg(test)]
+mod mod_test;
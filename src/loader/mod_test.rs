@@ -0,0 +1,477 @@
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::bus::model::Bus;
+    use crate::cpu::model::{Variant, CPU};
+    use crate::interfaces::bus::IBus;
+    use crate::interfaces::cpu::ICPU;
+    use crate::loader::model::{Chunk, EntryPoint, INes, INesHeader, Image, LoadError};
+    use crate::mapper::model::Mirroring;
+
+    // iNES image with the reset vector pointing at $C000 and a marker byte at the start of each 16K bank
+    fn ines_image(header: [u8; 16], trainer: bool) -> Vec<u8> {
+        let mut image = header.to_vec();
+        if trainer {
+            image.extend((0..512).map(|index| index as u8));
+        }
+        let prg_banks = header[4] as usize;
+        for bank in 0..prg_banks {
+            let mut prg = vec![0xEA; 0x4000];
+            prg[0] = 0xA0 + bank as u8;
+            if bank == prg_banks - 1 {
+                prg[0x3FFC] = 0x00;
+                prg[0x3FFD] = 0xC0;
+            }
+            image.extend(prg);
+        }
+        image.extend(vec![0x55; header[5] as usize * 0x2000]);
+        image
+    }
+
+    fn header(prg: u8, chr: u8, flags6: u8, flags7: u8) -> [u8; 16] {
+        let mut header = [0x00; 16];
+        header[0..4].copy_from_slice(b"NES\x1A");
+        header[4] = prg;
+        header[5] = chr;
+        header[6] = flags6;
+        header[7] = flags7;
+        header
+    }
+
+    #[test]
+    fn test_load_nrom_128() {
+        let image = ines_image(header(1, 1, 0x01, 0x00), false);
+        let mut cpu = CPU::new();
+        let mapper = cpu.load_nes(&image).unwrap();
+
+        assert_eq!(cpu.pc, 0xC000);
+        // 16K PRG-ROM is mirrored at $8000 and $C000
+        assert_eq!(cpu.bus.read(&0x8000), 0xA0);
+        assert_eq!(cpu.bus.read(&0xC000), 0xA0);
+        assert!(!cpu.bus.writable(&0x8000));
+
+        // PRG-RAM at $6000
+        cpu.bus.write(&0x6000, 0x42);
+        assert_eq!(cpu.bus.read(&0x6000), 0x42);
+
+        let board = mapper.borrow();
+        assert_eq!(board.mirroring(), Mirroring::Vertical);
+        assert_eq!(board.chr_read(0x0000), 0x55);
+    }
+
+    #[test]
+    fn test_load_nrom_256() {
+        let image = ines_image(header(2, 0, 0x00, 0x00), false);
+        let mut cpu = CPU::new();
+        let mapper = cpu.load_nes(&image).unwrap();
+
+        assert_eq!(cpu.bus.read(&0x8000), 0xA0);
+        assert_eq!(cpu.bus.read(&0xC000), 0xA1);
+
+        // No CHR-ROM : 8K of CHR-RAM
+        let mut board = mapper.borrow_mut();
+        assert_eq!(board.mirroring(), Mirroring::Horizontal);
+        board.chr_write(0x1FFF, 0x99);
+        assert_eq!(board.chr_read(0x1FFF), 0x99);
+    }
+
+    #[test]
+    fn test_parse_ines_header() {
+        let header = INesHeader::parse(&header(2, 1, 0x1E, 0x40)).unwrap();
+        assert_eq!(header.prg_rom_size, 0x8000);
+        assert_eq!(header.chr_rom_size, 0x2000);
+        assert_eq!(header.prg_ram_size, 0x2000);
+        assert_eq!(header.mapper, 0x41);
+        assert_eq!(header.mirroring, Mirroring::FourScreen);
+        assert!(header.battery);
+        assert!(header.trainer);
+        assert!(!header.nes2);
+
+        // A signature in the padding hides the upper mapper nibble
+        let mut bytes = self::header(1, 1, 0x10, 0x40);
+        bytes[12..16].copy_from_slice(b"Dude");
+        assert_eq!(INesHeader::parse(&bytes).unwrap().mapper, 0x01);
+    }
+
+    #[test]
+    fn test_parse_nes2_header() {
+        let mut bytes = header(2, 1, 0x00, 0x48);
+        bytes[8] = 0x31;
+        bytes[9] = 0x10;
+        bytes[10] = 0x07;
+        let header = INesHeader::parse(&bytes).unwrap();
+        assert!(header.nes2);
+        assert_eq!(header.mapper, 0x140);
+        assert_eq!(header.submapper, 3);
+        assert_eq!(header.prg_rom_size, 0x8000);
+        // Size MSB nibble 1 : 257 x 8K
+        assert_eq!(header.chr_rom_size, 0x101 * 0x2000);
+        assert_eq!(header.prg_ram_size, 0x2000);
+
+        // Exponent-multiplier notation : 2^4 * 3
+        bytes[4] = 0x11;
+        bytes[9] = 0x0F;
+        assert_eq!(INesHeader::parse(&bytes).unwrap().prg_rom_size, 48);
+    }
+
+    #[test]
+    fn test_trainer_is_loaded_at_7000() {
+        let image = ines_image(header(1, 1, 0x04, 0x00), true);
+        let ines = INes::parse(&image).unwrap();
+        assert_eq!(
+            ines.trainer.as_ref().map(|trainer| trainer.len()),
+            Some(512)
+        );
+        assert_eq!(ines.prg_rom[0], 0xA0);
+
+        let mut cpu = CPU::new();
+        cpu.load_nes(&image).unwrap();
+        assert_eq!(cpu.bus.read(&0x7000), 0x00);
+        assert_eq!(cpu.bus.read(&0x7001), 0x01);
+        assert_eq!(cpu.bus.read(&0x71FF), 0xFF);
+        assert_eq!(cpu.pc, 0xC000);
+
+        // MMC3 : poked into PRG-RAM like NROM
+        let image = ines_image(header(2, 1, 0x44, 0x00), true);
+        let mapper = INes::parse(&image).unwrap().mapper().unwrap();
+        assert_eq!(mapper.borrow().cpu_peek(0x7001), 0x01);
+
+        // UxROM has no PRG-RAM to hold it
+        let image = ines_image(header(2, 0, 0x24, 0x00), true);
+        let error = INes::parse(&image).unwrap().mapper().err().unwrap();
+        assert_eq!(
+            error,
+            LoadError::InvalidHeader("trainer on a board without PRG-RAM")
+        );
+        assert_eq!(
+            error.to_string(),
+            "invalid iNES header : trainer on a board without PRG-RAM"
+        );
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let mut cpu = CPU::new();
+        assert_eq!(cpu.load_nes(b"NES").err(), Some(LoadError::BadMagic));
+        assert_eq!(cpu.load_nes(&[0x00; 32]).err(), Some(LoadError::BadMagic));
+
+        let mut image = ines_image(header(1, 1, 0x00, 0x00), false);
+        image.truncate(0x3000);
+        assert_eq!(
+            cpu.load_nes(&image).err(),
+            Some(LoadError::Truncated {
+                expected: 16 + 0x4000 + 0x2000,
+                found: 0x3000
+            })
+        );
+
+        let image = ines_image(header(1, 1, 0x50, 0x00), false);
+        let error = cpu.load_nes(&image).err().unwrap();
+        assert_eq!(error, LoadError::UnsupportedMapper(5));
+        assert_eq!(error.to_string(), "unsupported mapper 5");
+    }
+
+    #[test]
+    fn test_malformed_header_sizes() {
+        // Exponent-multiplier form : 2^63 * 7 does not fit
+        let mut bytes = header(0xFF, 1, 0x00, 0x08);
+        bytes[9] = 0x0F;
+        assert_eq!(
+            INes::parse(&bytes).err(),
+            Some(LoadError::InvalidHeader("ROM size out of range"))
+        );
+
+        // 2^63 of PRG-ROM plus 2^63 of CHR-ROM
+        let mut bytes = header(0xFC, 0xFC, 0x00, 0x08);
+        bytes[9] = 0xFF;
+        assert_eq!(
+            INes::parse(&bytes).err(),
+            Some(LoadError::Truncated {
+                expected: usize::MAX,
+                found: 16
+            })
+        );
+
+        let mut image = header(0, 1, 0x00, 0x00).to_vec();
+        image.extend(vec![0x00; 0x2000]);
+        let error = INes::parse(&image).err().unwrap();
+        assert_eq!(error, LoadError::InvalidHeader("no PRG-ROM"));
+        assert_eq!(error.to_string(), "invalid iNES header : no PRG-ROM");
+    }
+
+    #[test]
+    fn test_load_banked_mappers() {
+        // MMC1, UxROM, CNROM, MMC3 and AxROM all power up with the reset vector in the last bank
+        for flags6 in [0x10, 0x20, 0x30, 0x40, 0x70] {
+            let image = ines_image(header(2, 1, flags6, 0x00), false);
+            let mut cpu = CPU::new();
+            let mapper = cpu.load_nes(&image).unwrap();
+            assert_eq!(cpu.pc, 0xC000, "mapper {}", flags6 >> 4);
+            assert!(!mapper.borrow().layout().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_load_binary_into_rom() {
+        let mut bus = Bus::new();
+        bus.load_binary(&[0xA9, 0x42, 0x00], 0xFFFD).unwrap();
+        assert_eq!(bus.read(&0xFFFD), 0xA9);
+        assert_eq!(bus.read(&0xFFFF), 0x00);
+        // Still read only for the processor
+        bus.write(&0xFFFD, 0x00);
+        assert_eq!(bus.read(&0xFFFD), 0xA9);
+    }
+
+    #[test]
+    fn test_load_binary_over_mapper() {
+        // UxROM : 8 banks of 16K, bank 0 at $8000
+        let image = ines_image(header(8, 0, 0x20, 0x00), false);
+        let mut cpu = CPU::new();
+        let mapper = cpu.load_nes(&image).unwrap();
+
+        // ROM windows take the bytes, the bank register is untouched
+        cpu.load_binary(&[0x07, 0x07], 0x8000, EntryPoint::Keep)
+            .unwrap();
+        cpu.load_binary(&[0x4C, 0x00, 0x80], 0xC000, EntryPoint::Keep)
+            .unwrap();
+        assert_eq!(mapper.borrow().save_state(), vec![0x00]);
+        assert_eq!(cpu.bus.read(&0x8000), 0x07);
+        assert_eq!(cpu.bus.read(&0xC002), 0x80);
+
+        // NROM PRG-ROM and PRG-RAM
+        let image = ines_image(header(1, 1, 0x00, 0x00), false);
+        let mut cpu = CPU::new();
+        cpu.load_nes(&image).unwrap();
+        cpu.load_binary(&[0xEA, 0x60], 0xFFFE, EntryPoint::Keep)
+            .unwrap();
+        cpu.load_binary(&[0x42], 0x6000, EntryPoint::Keep).unwrap();
+        assert_eq!(cpu.bus.read(&0xFFFF), 0x60);
+        // 16K mirrored at $8000
+        assert_eq!(cpu.bus.read(&0xBFFE), 0xEA);
+        assert_eq!(cpu.bus.read(&0x6000), 0x42);
+    }
+
+    #[test]
+    fn test_load_binary_entry_points() {
+        let program = [0xA9, 0x42, 0x85, 0x10];
+        let mut cpu = CPU::new();
+        cpu.load_binary(&program, 0x0600, EntryPoint::SetPc(0x0600))
+            .unwrap();
+        assert_eq!(cpu.pc, 0x0600);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+        assert_eq!(cpu.bus.read(&0x0010), 0x42);
+
+        cpu.load_binary(&program, 0xC000, EntryPoint::ResetVector(0xC000))
+            .unwrap();
+        assert_eq!(cpu.pc, 0x0604);
+        assert_eq!(cpu.bus.read_word(&0xFFFC), 0xC000);
+        cpu.reset();
+        assert_eq!(cpu.pc, 0xC000);
+
+        cpu.load_binary(&[0xEA], 0x0200, EntryPoint::Keep).unwrap();
+        assert_eq!(cpu.pc, 0xC000);
+    }
+
+    #[test]
+    fn test_load_binary_overflow() {
+        let mut cpu = CPU::new();
+        // The last byte fits
+        cpu.load_binary(&[0xEA; 0x10], 0xFFF0, EntryPoint::Keep)
+            .unwrap();
+
+        let error = cpu
+            .load_binary(&[0x00; 0x11], 0xFFF0, EntryPoint::SetPc(0xFFF0))
+            .unwrap_err();
+        assert_eq!(
+            error,
+            LoadError::Overflow {
+                address: 0xFFF0,
+                size: 0x11
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "image of 17 bytes at $FFF0 overflows $FFFF"
+        );
+        // Nothing was written, PC is unchanged
+        assert_eq!(cpu.bus.read(&0xFFF0), 0xEA);
+        assert_ne!(cpu.pc, 0xFFF0);
+    }
+
+    #[test]
+    fn test_load_binary_file() {
+        let path = env::temp_dir().join(format!("load_binary_{}.bin", std::process::id()));
+        fs::write(&path, [0xA2, 0x07]).unwrap();
+
+        let mut cpu = CPU::new();
+        cpu.load_binary_file(&path, 0x0300, EntryPoint::SetPc(0x0300))
+            .unwrap();
+        cpu.step().unwrap();
+        assert_eq!(cpu.x_register, 0x07);
+
+        let mut bus = Bus::flat();
+        bus.load_binary_file(&path, 0x1000).unwrap();
+        assert_eq!(bus.read(&0x1001), 0x07);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            bus.load_binary_file(&path, 0x1000),
+            Err(LoadError::Io(_))
+        ));
+    }
+
+    const INTEL_HEX: &str = "\
+:10010000214601360121470136007EFE09D2190140
+:100110002146017E17C20001FF5F16002148011928
+:10012000194E79234623965778239EDA3F01B2CAA7
+:100130003F0156702B5E712B722B732146013421C7
+:00000001FF
+";
+
+    const SREC: &str = "\
+S00F000068656C6C6F202020202000003C
+S11F00007C0802A6900100049421FFF07C6C1B787C8C23783C6000003863000026
+S11F001C4BFFFFE5398000007D83637880010014382100107C0803A64E800020E9
+S111003848656C6C6F20776F726C642E0A0042
+S5030003F9
+S9030000FC
+";
+
+    #[test]
+    fn test_parse_intel_hex() {
+        let image = Image::parse_intel_hex(INTEL_HEX).unwrap();
+        assert_eq!(image.chunks.len(), 1);
+        assert_eq!(image.chunks[0].address, 0x0100);
+        assert_eq!(image.chunks[0].data.len(), 64);
+        assert_eq!(image.entry, None);
+
+        let mut cpu = CPU::new();
+        cpu.pc = 0x0200;
+        assert_eq!(cpu.load_intel_hex(INTEL_HEX), Ok(None));
+        assert_eq!(cpu.pc, 0x0200);
+        assert_eq!(cpu.bus.read(&0x0100), 0x21);
+        assert_eq!(cpu.bus.read(&0x013F), 0x21);
+    }
+
+    #[test]
+    fn test_intel_hex_errors() {
+        let bad_checksum = INTEL_HEX.replacen("0140", "0141", 1);
+        assert_eq!(
+            Image::parse_intel_hex(&bad_checksum),
+            Err(LoadError::BadChecksum { line: 1 })
+        );
+        assert_eq!(
+            Image::parse_intel_hex("\n10010000").unwrap_err(),
+            LoadError::BadRecord { line: 2 }
+        );
+        // Count does not match the data
+        assert_eq!(
+            Image::parse_intel_hex(":0300000000FD").unwrap_err(),
+            LoadError::BadRecord { line: 1 }
+        );
+        // Extended linear address $0001xxxx
+        let high = ":020000040001F9\n:02060000A9420D\n";
+        assert_eq!(
+            Image::parse_intel_hex(high).unwrap_err(),
+            LoadError::OutOfRange(0x10600)
+        );
+    }
+
+    #[test]
+    fn test_parse_srec() {
+        let image = Image::parse_srec(SREC).unwrap();
+        assert_eq!(image.chunks.len(), 1);
+        assert_eq!(image.chunks[0].address, 0x0000);
+        assert_eq!(image.chunks[0].data.len(), 70);
+        assert_eq!(&image.chunks[0].data[0x38..0x44], b"Hello world.");
+        // S9 $0000 : no entry point
+        assert_eq!(image.entry, None);
+
+        // S28 : 24 bit addresses, entry in the S8 record
+        let mut cpu = CPU::new();
+        assert_eq!(
+            cpu.load_srec("S20600C000EA60EF\nS80400C0003B\n"),
+            Ok(Some(0xC000))
+        );
+        assert_eq!(cpu.pc, 0xC000);
+        assert_eq!(cpu.bus.read(&0xC001), 0x60);
+
+        assert_eq!(
+            Image::parse_srec(&SREC.replacen("003C", "003D", 1)),
+            Err(LoadError::BadChecksum { line: 1 })
+        );
+        assert_eq!(
+            Image::parse_srec("S4030000FC"),
+            Err(LoadError::BadRecord { line: 1 })
+        );
+        assert_eq!(
+            Image::parse_srec("S30700010000EA60AD"),
+            Err(LoadError::OutOfRange(0x10000))
+        );
+    }
+
+    #[test]
+    fn test_write_records() {
+        let image = Image {
+            chunks: vec![Chunk {
+                address: 0x0600,
+                data: vec![0xA9, 0x42],
+            }],
+            entry: Some(0x0600),
+        };
+        assert_eq!(
+            image.to_intel_hex(),
+            ":02060000A9420D\n:0400000500000600F1\n:00000001FF\n"
+        );
+        assert_eq!(
+            image.to_srec(),
+            "S0030000FC\nS1050600A94209\nS5030001FB\nS9030600F6\n"
+        );
+    }
+
+    #[test]
+    fn test_srec_round_trip_without_entry() {
+        let image = Image {
+            chunks: vec![Chunk {
+                address: 0x0300,
+                data: vec![0xE8, 0x60],
+            }],
+            entry: None,
+        };
+        let parsed = Image::parse_srec(&image.to_srec()).unwrap();
+        assert_eq!(parsed, image);
+
+        let mut cpu = CPU::new();
+        cpu.pc = 0x0600;
+        assert_eq!(cpu.load_image(&parsed), Ok(None));
+        assert_eq!(cpu.pc, 0x0600);
+    }
+
+    #[test]
+    fn test_round_trip_patched_firmware() {
+        let mut bus = Bus::new();
+        let firmware: Vec<u8> = (0..0x40).collect();
+        bus.load_binary(&firmware, 0x8000).unwrap();
+        // Patch one byte of ROM
+        bus.poke(&0x8010, 0xEA);
+
+        let image = Image::dump(&bus, 0x8000, 0x803F, Some(0x8000));
+        for text in [image.to_intel_hex(), image.to_srec()] {
+            let parsed = if text.starts_with(':') {
+                Image::parse_intel_hex(&text)
+            } else {
+                Image::parse_srec(&text)
+            };
+            assert_eq!(parsed.as_ref(), Ok(&image));
+
+            let mut cpu = CPU::with_bus(Bus::new(), Variant::Nmos);
+            assert_eq!(cpu.load_image(&parsed.unwrap()), Ok(Some(0x8000)));
+            assert_eq!(cpu.pc, 0x8000);
+            assert_eq!(cpu.bus.read(&0x8010), 0xEA);
+            assert_eq!(cpu.bus.read(&0x803F), 0x3F);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::mapper::model::Mirroring;
+
+/* Image could not be loaded */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    /* The file does not start with the expected signature */
+    BadMagic,
+    /* The file is shorter than its header announces */
+    Truncated { expected: usize, found: usize },
+    /* The header describes an image that cannot exist */
+    InvalidHeader(&'static str),
+    /* No implementation for this iNES mapper number */
+    UnsupportedMapper(u16),
+    /* The image does not fit between its load address and $FFFF */
+    Overflow { address: u16, size: usize },
+    /* An address of a HEX or S-record file is past $FFFF */
+    OutOfRange(u32),
+    /* Malformed record, lines count from 1 */
+    BadRecord { line: usize },
+    BadChecksum { line: usize },
+    /* The file could not be read */
+    Io(String),
+}
+
+/* What the loader does with the entry point of a binary image */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryPoint {
+    /* Leave PC and the vectors alone */
+    Keep,
+    /* Jump there right away */
+    SetPc(u16),
+    /* Patch the reset vector, the next reset starts there */
+    ResetVector(u16),
+}
+
+/*
+   iNES / NES 2.0 header, 16 bytes.
+   Ref : https://www.nesdev.org/wiki/INES and https://www.nesdev.org/wiki/NES_2.0
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct INesHeader {
+    pub prg_rom_size: usize,
+    pub chr_rom_size: usize,
+    pub prg_ram_size: usize,
+    pub mapper: u16,
+    pub submapper: u8,
+    pub mirroring: Mirroring,
+    /* Battery backed PRG-RAM at $6000-$7FFF */
+    pub battery: bool,
+    /* 512 bytes to load at $7000 before PRG-ROM */
+    pub trainer: bool,
+    pub nes2: bool,
+}
+
+/* .nes file split in its parts */
+pub struct INes {
+    pub header: INesHeader,
+    pub trainer: Option<Vec<u8>>,
+    pub prg_rom: Vec<u8>,
+    pub chr_rom: Vec<u8>,
+}
+
+/* Bytes stored from address on */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    pub address: u16,
+    pub data: Vec<u8>,
+}
+
+/*
+   Memory image of an Intel HEX or Motorola S-record file.
+   Consecutive records are merged into one chunk, entry comes from the start / termination record.
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Image {
+    pub chunks: Vec<Chunk>,
+    pub entry: Option<u16>,
+}
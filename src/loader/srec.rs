@@ -0,0 +1,91 @@
+use crate::loader::image::{address_of, decode_hex};
+use crate::loader::model::{Image, LoadError};
+
+/* Data bytes per record written */
+const RECORD_SIZE: usize = 16;
+
+/*
+   Motorola S-record : "S" type, count, address, data, checksum.
+   The count covers address, data and checksum, the checksum is the one's complement of the sum
+   of count, address and data. S1 / S2 / S3 hold data with 2 / 3 / 4 address bytes,
+   S9 / S8 / S7 terminate with the entry point, $0000 when the file has none.
+   Ref : https://en.wikipedia.org/wiki/SREC_(file_format)
+*/
+impl Image {
+    pub fn parse_srec(text: &str) -> Result<Self, LoadError> {
+        let mut image = Image::new();
+
+        for (index, record) in text.lines().enumerate() {
+            let line = index + 1;
+            let record = record.trim();
+            if record.is_empty() {
+                continue;
+            }
+            let (kind, digits) = match record.strip_prefix('S') {
+                Some(rest) if !rest.is_empty() && rest.is_char_boundary(1) => rest.split_at(1),
+                _ => return Err(LoadError::BadRecord { line }),
+            };
+            let bytes = decode_hex(digits, line)?;
+            if bytes.len() != bytes[0] as usize + 1 {
+                return Err(LoadError::BadRecord { line });
+            }
+            if bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) != 0xFF {
+                return Err(LoadError::BadChecksum { line });
+            }
+
+            let address_size = match kind {
+                // Header and record counts
+                "0" | "1" | "5" | "9" => 2,
+                "2" | "6" | "8" => 3,
+                "3" | "7" => 4,
+                _ => return Err(LoadError::BadRecord { line }),
+            };
+            if bytes.len() < address_size + 2 {
+                return Err(LoadError::BadRecord { line });
+            }
+            let address = address_of(&bytes[1..1 + address_size]);
+            let data = &bytes[1 + address_size..bytes.len() - 1];
+            match kind {
+                "1" | "2" | "3" => image.push(address, data)?,
+                "7" | "8" | "9" => {
+                    if address != 0 {
+                        image.set_entry(address)?;
+                    }
+                    break;
+                }
+                _ => {}
+            }
+        }
+        Ok(image)
+    }
+
+    /* S19 : header, S1 data records of 16 bytes, S5 record count and S9 with the entry point or $0000 */
+    pub fn to_srec(&self) -> String {
+        let mut text = srec_record('0', 0x0000, &[]);
+        let mut records = 0;
+        for chunk in self.chunks.iter() {
+            for (index, data) in chunk.data.chunks(RECORD_SIZE).enumerate() {
+                let address = chunk.address.wrapping_add((index * RECORD_SIZE) as u16);
+                text.push_str(&srec_record('1', address, data));
+                records += 1;
+            }
+        }
+        text.push_str(&srec_record('5', records as u16, &[]));
+        text.push_str(&srec_record('9', self.entry.unwrap_or(0x0000), &[]));
+        text
+    }
+}
+
+fn srec_record(kind: char, address: u16, data: &[u8]) -> String {
+    let mut bytes = vec![
+        (data.len() + 3) as u8,
+        (address >> 8) as u8,
+        (address & 0x00FF) as u8,
+    ];
+    bytes.extend_from_slice(data);
+    let sum = bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    bytes.push(!sum);
+
+    let digits: String = bytes.iter().map(|byte| format!("{:02X}", byte)).collect();
+    format!("S{}{}\n", kind, digits)
+}
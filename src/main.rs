@@ -3,15 +3,46 @@ mod constants;
 mod cpu;
 mod datas;
 mod interfaces;
+mod loader;
+mod mapper;
 mod ram;
 
-use crate::datas::snakes::SNAKE_MEMORY_MAPS;
+use std::env;
+use std::process;
 
+use crate::cpu::model::CPU;
+use crate::interfaces::cpu::ICPU;
+use crate::loader::model::EntryPoint;
+
+/* emulator_cpu6502 <binary> [load address in hex, $0600 by default] */
 fn main() {
-    //let rom = Rom::new(*SNAKE_MEMORY_MAPS);
-    //let mut cpu = CPU::new(rom);
-    //cpu.reset();
-    //cpu.run();
-    ////cpu.display_rom();
+    let args: Vec<String> = env::args().collect();
+    let path = match args.get(1) {
+        Some(path) => path,
+        None => {
+            println!("Usage : {} <binary> [load address]", args[0]);
+            return;
+        }
+    };
+    let load_address = match args.get(2) {
+        Some(address) => {
+            let digits = address.trim_start_matches('$').trim_start_matches("0x");
+            u16::from_str_radix(digits, 16).unwrap_or_else(|_| {
+                eprintln!("Invalid load address {}", address);
+                process::exit(1);
+            })
+        }
+        None => 0x0600,
+    };
+
+    let mut cpu = CPU::new();
+    if let Err(error) = cpu.load_binary_file(path, load_address, EntryPoint::SetPc(load_address)) {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+    if let Err(error) = cpu.run() {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
     println!("End Program..");
 }
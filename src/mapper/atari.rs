@@ -0,0 +1,61 @@
+use crate::interfaces::mapper::Mapper;
+use crate::mapper::model::{Atari, BankWindow, Banks};
+
+impl Atari {
+    /* F8 : 8K in two banks, hotspots $1FF8-$1FF9 */
+    pub fn f8(rom: Vec<u8>) -> Self {
+        Self::new(rom, 0x1FF8)
+    }
+
+    /* F6 : 16K in four banks, hotspots $1FF6-$1FF9 */
+    pub fn f6(rom: Vec<u8>) -> Self {
+        Self::new(rom, 0x1FF6)
+    }
+
+    /* The cartridge powers up on its last bank, where the reset vector is */
+    fn new(rom: Vec<u8>, first_hotspot: u16) -> Self {
+        let mut rom = Banks::new(rom, 0x1000, 1, false);
+        rom.select_last(0);
+        Atari { rom, first_hotspot }
+    }
+
+    /* The 6507 only has 13 address lines : the cartridge sees $1000-$1FFF */
+    fn access(&mut self, address: u16) {
+        let address = address & 0x1FFF;
+        if (self.first_hotspot..=0x1FF9).contains(&address) {
+            self.rom.select(0, (address - self.first_hotspot) as usize);
+        }
+    }
+}
+
+impl Mapper for Atari {
+    fn cpu_read(&mut self, address: u16) -> u8 {
+        self.access(address);
+        self.cpu_peek(address)
+    }
+
+    fn cpu_write(&mut self, address: u16, _data: u8) {
+        self.access(address);
+    }
+
+    fn cpu_peek(&self, address: u16) -> u8 {
+        self.rom.read((address & 0x0FFF) as usize)
+    }
+
+    fn cpu_poke(&mut self, address: u16, data: u8) {
+        self.rom.poke((address & 0x0FFF) as usize, data);
+    }
+
+    fn layout(&self) -> Vec<BankWindow> {
+        self.rom.layout("ROM", 0x1000)
+    }
+
+    fn save_state(&self) -> Vec<u8> {
+        vec![self.rom.windows[0] as u8]
+    }
+
+    fn load_state(&mut self, state: &[u8]) {
+        self.rom
+            .select(0, state.first().copied().unwrap_or(0x00) as usize);
+    }
+}
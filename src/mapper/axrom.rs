@@ -0,0 +1,72 @@
+use crate::interfaces::mapper::Mapper;
+use crate::mapper::model::{AxRom, BankWindow, Banks, Mirroring};
+use crate::mapper::nrom::chr_banks;
+
+impl AxRom {
+    pub fn new(prg_rom: Vec<u8>, chr_rom: Vec<u8>) -> Self {
+        AxRom {
+            prg_rom: Banks::new(prg_rom, 0x8000, 1, false),
+            chr: chr_banks(chr_rom, 0x2000, 1),
+            bank: 0x00,
+        }
+    }
+}
+
+impl Mapper for AxRom {
+    fn cpu_read(&mut self, address: u16) -> u8 {
+        self.cpu_peek(address)
+    }
+
+    /* Bits 0-2 select the 32K bank, bit 4 the nametable */
+    fn cpu_write(&mut self, address: u16, data: u8) {
+        if address >= 0x8000 {
+            self.bank = data;
+            self.prg_rom.select(0, (data & 0x07) as usize);
+        }
+    }
+
+    fn cpu_peek(&self, address: u16) -> u8 {
+        if address >= 0x8000 {
+            self.prg_rom.read((address - 0x8000) as usize)
+        } else {
+            0x00
+        }
+    }
+
+    fn cpu_poke(&mut self, address: u16, data: u8) {
+        if address >= 0x8000 {
+            self.prg_rom.poke((address - 0x8000) as usize, data);
+        }
+    }
+
+    fn chr_read(&self, address: u16) -> u8 {
+        self.chr.read((address & 0x1FFF) as usize)
+    }
+
+    fn chr_write(&mut self, address: u16, data: u8) {
+        self.chr.write((address & 0x1FFF) as usize, data);
+    }
+
+    fn mirroring(&self) -> Mirroring {
+        if self.bank & 0x10 == 0 {
+            Mirroring::SingleScreenLower
+        } else {
+            Mirroring::SingleScreenUpper
+        }
+    }
+
+    fn layout(&self) -> Vec<BankWindow> {
+        let mut layout = self.prg_rom.layout("PRG", 0x8000);
+        layout.extend(self.chr.layout("CHR", 0x0000));
+        layout
+    }
+
+    fn save_state(&self) -> Vec<u8> {
+        vec![self.bank]
+    }
+
+    fn load_state(&mut self, state: &[u8]) {
+        self.bank = state.first().copied().unwrap_or(0x00);
+        self.prg_rom.select(0, (self.bank & 0x07) as usize);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::interfaces::mapper::Mapper;
+use crate::mapper::model::{BankWindow, BankedRam, Banks};
+
+impl BankedRam {
+    /* banks x bank_size bytes of RAM seen through $window, the bank is selected at $register */
+    pub fn new(banks: usize, bank_size: usize, window: u16, register: u16) -> Self {
+        BankedRam {
+            ram: Banks::new(vec![0x00; banks * bank_size], bank_size, 1, true),
+            window,
+            register,
+        }
+    }
+
+    /* Offset inside the window, None outside of it */
+    fn offset(&self, address: u16) -> Option<usize> {
+        let offset = address.wrapping_sub(self.window) as usize;
+        if offset < self.ram.bank_size {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+}
+
+impl Mapper for BankedRam {
+    fn cpu_read(&mut self, address: u16) -> u8 {
+        self.cpu_peek(address)
+    }
+
+    fn cpu_write(&mut self, address: u16, data: u8) {
+        if address == self.register {
+            self.ram.select(0, data as usize);
+        } else if let Some(offset) = self.offset(address) {
+            self.ram.write(offset, data);
+        }
+    }
+
+    fn cpu_peek(&self, address: u16) -> u8 {
+        if address == self.register {
+            return self.ram.windows[0] as u8;
+        }
+        match self.offset(address) {
+            Some(offset) => self.ram.read(offset),
+            None => 0x00,
+        }
+    }
+
+    /* RAM and the bank register : a poke is a plain write */
+    fn cpu_poke(&mut self, address: u16, data: u8) {
+        self.cpu_write(address, data);
+    }
+
+    fn layout(&self) -> Vec<BankWindow> {
+        self.ram.layout("RAM", self.window)
+    }
+
+    fn save_state(&self) -> Vec<u8> {
+        vec![self.ram.windows[0] as u8]
+    }
+
+    fn load_state(&mut self, state: &[u8]) {
+        self.ram
+            .select(0, state.first().copied().unwrap_or(0x00) as usize);
+    }
+}
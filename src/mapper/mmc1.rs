@@ -0,0 +1,193 @@
+use crate::interfaces::mapper::Mapper;
+use crate::mapper::model::{BankWindow, Banks, Mirroring, Mmc1};
+use crate::mapper::nrom::chr_banks;
+
+impl Mmc1 {
+    /* Powers up in 16K mode with the last bank fixed at $C000 */
+    pub fn new(prg_rom: Vec<u8>, chr_rom: Vec<u8>, prg_ram_size: usize) -> Self {
+        let mut mmc1 = Mmc1 {
+            prg_rom: Banks::new(prg_rom, 0x4000, 2, false),
+            chr: chr_banks(chr_rom, 0x1000, 2),
+            prg_ram: Banks::new(vec![0x00; prg_ram_size.max(0x2000)], 0x2000, 1, true),
+            shift: 0x10,
+            control: 0x0C,
+            chr_bank0: 0x00,
+            chr_bank1: 0x00,
+            prg_bank: 0x00,
+            cycle: 0,
+            last_write: None,
+        };
+        mmc1.update_banks();
+        mmc1
+    }
+
+    /*
+       Serial load : bit 7 resets the shift register, otherwise bit 0 is shifted in from the top.
+       The fifth write copies the value to the register selected by address bits 13-14.
+       Writes on consecutive cycles only count once : read-modify-write instructions shift one bit.
+       Writes with no clock in between (host writes, step and run clock once per instruction)
+       all count, only tick shows the two writes of an instruction one cycle apart.
+    */
+    fn load(&mut self, address: u16, data: u8) {
+        let consecutive = matches!(self.last_write, Some(cycle) if self.cycle == cycle + 1);
+        self.last_write = Some(self.cycle);
+        if consecutive {
+            return;
+        }
+
+        if data & 0x80 != 0 {
+            self.shift = 0x10;
+            self.control |= 0x0C;
+            self.update_banks();
+            return;
+        }
+
+        let complete = self.shift & 0x01 != 0;
+        self.shift = (self.shift >> 1) | ((data & 0x01) << 4);
+        if complete {
+            let value = self.shift;
+            match address & 0x6000 {
+                0x0000 => self.control = value,
+                0x2000 => self.chr_bank0 = value,
+                0x4000 => self.chr_bank1 = value,
+                _ => self.prg_bank = value,
+            }
+            self.shift = 0x10;
+            self.update_banks();
+        }
+    }
+
+    fn update_banks(&mut self) {
+        let prg_bank = (self.prg_bank & 0x0F) as usize;
+        match (self.control >> 2) & 0x03 {
+            // 32K : the low bit is ignored
+            0 | 1 => {
+                self.prg_rom.select(0, prg_bank & !0x01);
+                self.prg_rom.select(1, prg_bank | 0x01);
+            }
+            // First bank fixed at $8000
+            2 => {
+                self.prg_rom.select(0, 0);
+                self.prg_rom.select(1, prg_bank);
+            }
+            // Last bank fixed at $C000
+            _ => {
+                self.prg_rom.select(0, prg_bank);
+                self.prg_rom.select_last(1);
+            }
+        }
+
+        if self.control & 0x10 == 0 {
+            // 8K : the low bit is ignored
+            let chr_bank = (self.chr_bank0 & !0x01) as usize;
+            self.chr.select(0, chr_bank);
+            self.chr.select(1, chr_bank | 0x01);
+        } else {
+            self.chr.select(0, self.chr_bank0 as usize);
+            self.chr.select(1, self.chr_bank1 as usize);
+        }
+    }
+
+    /* MMC1B : bit 4 of the PRG bank register disables PRG-RAM */
+    fn prg_ram_enabled(&self) -> bool {
+        self.prg_bank & 0x10 == 0
+    }
+}
+
+impl Mapper for Mmc1 {
+    fn cpu_read(&mut self, address: u16) -> u8 {
+        self.cpu_peek(address)
+    }
+
+    fn cpu_write(&mut self, address: u16, data: u8) {
+        match address {
+            0x6000..=0x7FFF if self.prg_ram_enabled() => {
+                self.prg_ram.write((address - 0x6000) as usize, data)
+            }
+            0x8000..=0xFFFF => self.load(address, data),
+            _ => {}
+        }
+    }
+
+    fn cpu_peek(&self, address: u16) -> u8 {
+        match address {
+            0x6000..=0x7FFF if self.prg_ram_enabled() => {
+                self.prg_ram.read((address - 0x6000) as usize)
+            }
+            0x8000..=0xFFFF => self.prg_rom.read((address - 0x8000) as usize),
+            _ => 0x00,
+        }
+    }
+
+    /* PRG-RAM writes are dropped while it is disabled */
+    fn cpu_writable(&self, address: u16) -> bool {
+        match address {
+            0x6000..=0x7FFF => self.prg_ram_enabled(),
+            _ => true,
+        }
+    }
+
+    fn cpu_poke(&mut self, address: u16, data: u8) {
+        match address {
+            0x6000..=0x7FFF => self.prg_ram.poke((address - 0x6000) as usize, data),
+            0x8000..=0xFFFF => self.prg_rom.poke((address - 0x8000) as usize, data),
+            _ => {}
+        }
+    }
+
+    fn chr_read(&self, address: u16) -> u8 {
+        self.chr.read((address & 0x1FFF) as usize)
+    }
+
+    fn chr_write(&mut self, address: u16, data: u8) {
+        self.chr.write((address & 0x1FFF) as usize, data);
+    }
+
+    fn tick(&mut self, cycles: u64) {
+        self.cycle += cycles;
+    }
+
+    fn mirroring(&self) -> Mirroring {
+        match self.control & 0x03 {
+            0 => Mirroring::SingleScreenLower,
+            1 => Mirroring::SingleScreenUpper,
+            2 => Mirroring::Vertical,
+            _ => Mirroring::Horizontal,
+        }
+    }
+
+    fn layout(&self) -> Vec<BankWindow> {
+        let mut layout = self.prg_rom.layout("PRG", 0x8000);
+        layout.extend(self.chr.layout("CHR", 0x0000));
+        layout
+    }
+
+    /* Registers, then the cycle and the last write cycle that decide if the next write counts */
+    fn save_state(&self) -> Vec<u8> {
+        let mut state = vec![
+            self.shift,
+            self.control,
+            self.chr_bank0,
+            self.chr_bank1,
+            self.prg_bank,
+            self.last_write.is_some() as u8,
+        ];
+        state.extend(self.cycle.to_le_bytes());
+        state.extend(self.last_write.unwrap_or(0).to_le_bytes());
+        state
+    }
+
+    fn load_state(&mut self, state: &[u8]) {
+        let byte = |index: usize| state.get(index).copied().unwrap_or(0x00);
+        let word =
+            |index: usize| u64::from_le_bytes(std::array::from_fn(|offset| byte(index + offset)));
+        self.shift = byte(0);
+        self.control = byte(1);
+        self.chr_bank0 = byte(2);
+        self.chr_bank1 = byte(3);
+        self.prg_bank = byte(4);
+        self.cycle = word(6);
+        self.last_write = if byte(5) != 0 { Some(word(14)) } else { None };
+        self.update_banks();
+    }
+}
@@ -0,0 +1,202 @@
+use crate::interfaces::mapper::Mapper;
+use crate::mapper::model::{BankWindow, Banks, Mirroring, Mmc3};
+use crate::mapper::nrom::chr_banks;
+
+impl Mmc3 {
+    pub fn new(prg_rom: Vec<u8>, chr_rom: Vec<u8>, prg_ram_size: usize, four_screen: bool) -> Self {
+        let mut mmc3 = Mmc3 {
+            prg_rom: Banks::new(prg_rom, 0x2000, 4, false),
+            chr: chr_banks(chr_rom, 0x0400, 8),
+            prg_ram: Banks::new(vec![0x00; prg_ram_size.max(0x2000)], 0x2000, 1, true),
+            bank_select: 0x00,
+            bank_registers: [0x00, 0x02, 0x04, 0x05, 0x06, 0x07, 0x00, 0x01],
+            mirroring: 0x00,
+            four_screen,
+            prg_ram_protect: 0x80,
+            irq_latch: 0x00,
+            irq_counter: 0x00,
+            irq_reload: false,
+            irq_enabled: false,
+            irq_pending: false,
+        };
+        mmc3.update_banks();
+        mmc3
+    }
+
+    /*
+       Registers are decoded by address bits 13-14 and bit 0 :
+       $8000 bank select, $8001 bank data, $A000 mirroring, $A001 PRG-RAM protect,
+       $C000 IRQ latch, $C001 IRQ reload, $E000 IRQ disable, $E001 IRQ enable.
+    */
+    fn write_register(&mut self, address: u16, data: u8) {
+        match address & 0xE001 {
+            0x8000 => self.bank_select = data,
+            0x8001 => self.bank_registers[(self.bank_select & 0x07) as usize] = data,
+            0xA000 => self.mirroring = data & 0x01,
+            0xA001 => self.prg_ram_protect = data & 0xC0,
+            0xC000 => self.irq_latch = data,
+            0xC001 => {
+                self.irq_counter = 0x00;
+                self.irq_reload = true;
+            }
+            // Disabling also acknowledges a pending IRQ
+            0xE000 => {
+                self.irq_enabled = false;
+                self.irq_pending = false;
+            }
+            _ => self.irq_enabled = true,
+        }
+        self.update_banks();
+    }
+
+    fn update_banks(&mut self) {
+        let registers = self.bank_registers.map(|bank| bank as usize);
+        let second_last = self.prg_rom.count().saturating_sub(2);
+
+        // Bit 6 swaps $8000 and $C000, the second last bank takes the other window
+        let (first, third) = if self.bank_select & 0x40 == 0 {
+            (registers[6], second_last)
+        } else {
+            (second_last, registers[6])
+        };
+        self.prg_rom.select(0, first);
+        self.prg_rom.select(1, registers[7]);
+        self.prg_rom.select(2, third);
+        self.prg_rom.select_last(3);
+
+        // Two 2K banks (R0, R1) and four 1K banks (R2-R5), bit 7 swaps the pattern tables
+        let banks = [
+            registers[0] & !0x01,
+            registers[0] | 0x01,
+            registers[1] & !0x01,
+            registers[1] | 0x01,
+            registers[2],
+            registers[3],
+            registers[4],
+            registers[5],
+        ];
+        let inversion = if self.bank_select & 0x80 == 0 { 0 } else { 4 };
+        for (window, bank) in banks.iter().enumerate() {
+            self.chr.select((window + inversion) % 8, *bank);
+        }
+    }
+
+    fn prg_ram_enabled(&self) -> bool {
+        self.prg_ram_protect & 0x80 != 0
+    }
+}
+
+impl Mapper for Mmc3 {
+    fn cpu_read(&mut self, address: u16) -> u8 {
+        self.cpu_peek(address)
+    }
+
+    fn cpu_write(&mut self, address: u16, data: u8) {
+        match address {
+            0x6000..=0x7FFF if self.cpu_writable(address) => {
+                self.prg_ram.write((address - 0x6000) as usize, data)
+            }
+            0x8000..=0xFFFF => self.write_register(address, data),
+            _ => {}
+        }
+    }
+
+    fn cpu_peek(&self, address: u16) -> u8 {
+        match address {
+            0x6000..=0x7FFF if self.prg_ram_enabled() => {
+                self.prg_ram.read((address - 0x6000) as usize)
+            }
+            0x8000..=0xFFFF => self.prg_rom.read((address - 0x8000) as usize),
+            _ => 0x00,
+        }
+    }
+
+    /* PRG-RAM writes are dropped while it is disabled or protected */
+    fn cpu_writable(&self, address: u16) -> bool {
+        match address {
+            0x6000..=0x7FFF => self.prg_ram_protect & 0xC0 == 0x80,
+            _ => true,
+        }
+    }
+
+    fn cpu_poke(&mut self, address: u16, data: u8) {
+        match address {
+            0x6000..=0x7FFF => self.prg_ram.poke((address - 0x6000) as usize, data),
+            0x8000..=0xFFFF => self.prg_rom.poke((address - 0x8000) as usize, data),
+            _ => {}
+        }
+    }
+
+    fn chr_read(&self, address: u16) -> u8 {
+        self.chr.read((address & 0x1FFF) as usize)
+    }
+
+    fn chr_write(&mut self, address: u16, data: u8) {
+        self.chr.write((address & 0x1FFF) as usize, data);
+    }
+
+    fn mirroring(&self) -> Mirroring {
+        if self.four_screen {
+            Mirroring::FourScreen
+        } else if self.mirroring == 0 {
+            Mirroring::Vertical
+        } else {
+            Mirroring::Horizontal
+        }
+    }
+
+    /* The counter reloads from the latch when it is zero, IRQ is raised when it reaches zero */
+    fn scanline(&mut self) {
+        if self.irq_counter == 0 || self.irq_reload {
+            self.irq_counter = self.irq_latch;
+            self.irq_reload = false;
+        } else {
+            self.irq_counter -= 1;
+        }
+
+        if self.irq_counter == 0 && self.irq_enabled {
+            self.irq_pending = true;
+        }
+    }
+
+    fn layout(&self) -> Vec<BankWindow> {
+        let mut layout = self.prg_rom.layout("PRG", 0x8000);
+        layout.extend(self.chr.layout("CHR", 0x0000));
+        layout
+    }
+
+    fn irq(&self) -> bool {
+        self.irq_pending
+    }
+
+    fn save_state(&self) -> Vec<u8> {
+        let mut state = vec![self.bank_select];
+        state.extend(self.bank_registers);
+        state.extend([
+            self.mirroring,
+            self.prg_ram_protect,
+            self.irq_latch,
+            self.irq_counter,
+            self.irq_reload as u8,
+            self.irq_enabled as u8,
+            self.irq_pending as u8,
+        ]);
+        state
+    }
+
+    fn load_state(&mut self, state: &[u8]) {
+        let byte = |index: usize| state.get(index).copied().unwrap_or(0x00);
+        self.bank_select = byte(0);
+        for (index, bank) in self.bank_registers.iter_mut().enumerate() {
+            *bank = byte(1 + index);
+        }
+        self.mirroring = byte(9);
+        self.prg_ram_protect = byte(10);
+        self.irq_latch = byte(11);
+        self.irq_counter = byte(12);
+        self.irq_reload = byte(13) != 0;
+        self.irq_enabled = byte(14) != 0;
+        self.irq_pending = byte(15) != 0;
+        self.update_banks();
+    }
+}
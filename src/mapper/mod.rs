@@ -0,0 +1,13 @@
+pub mod atari;
+pub mod axrom;
+pub mod banked_ram;
+pub mod cnrom;
+pub mod mmc1;
+pub mod mmc3;
+pub mod model;
+pub mod nrom;
+pub mod usecase;
+pub mod uxrom;
+
+#[cfg(test)]
+mod mod_test;
@@ -0,0 +1,376 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::bus::model::Bus;
+    use crate::cpu::model::{Variant, CPU};
+    use crate::interfaces::bus::IBus;
+    use crate::interfaces::cpu::ICPU;
+    use crate::interfaces::mapper::Mapper;
+    use crate::mapper::model::{
+        Atari, AxRom, BankWindow, BankedRam, Banks, CnRom, Mirroring, Mmc1, Mmc3, UxRom,
+    };
+
+    // ROM where every byte holds its bank number
+    fn numbered_rom(banks: usize, bank_size: usize) -> Vec<u8> {
+        (0..banks * bank_size)
+            .map(|index| (index / bank_size) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn test_banks_select_and_wrap() {
+        let mut banks = Banks::new(numbered_rom(4, 0x4000), 0x4000, 2, false);
+        assert_eq!(banks.read(0x0000), 0);
+        assert_eq!(banks.read(0x4000), 1);
+
+        banks.select(0, 2);
+        banks.select(1, 7);
+        assert_eq!(banks.read(0x3FFF), 2);
+        // Bank 7 of 4 wraps to bank 3
+        assert_eq!(banks.read(0x4000), 3);
+
+        // Read only
+        banks.write(0x0000, 0xFF);
+        assert_eq!(banks.read(0x0000), 2);
+        assert_eq!(
+            banks.layout("PRG", 0x8000),
+            vec![
+                BankWindow {
+                    name: "PRG",
+                    start: 0x8000,
+                    size: 0x4000,
+                    bank: 2
+                },
+                BankWindow {
+                    name: "PRG",
+                    start: 0xC000,
+                    size: 0x4000,
+                    bank: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_atari_f8_hotspots() {
+        let atari = Rc::new(RefCell::new(Atari::f8(numbered_rom(2, 0x1000))));
+        let mut bus = Bus::flat();
+        bus.attach_mapper(0x1000, 0x1FFF, atari.clone());
+
+        // Powers up on the last bank
+        assert_eq!(bus.read(&0x1000), 1);
+
+        // Reading a hotspot switches, peeking does not
+        bus.read(&0x1FF8);
+        assert_eq!(bus.read(&0x1000), 0);
+        bus.peek(&0x1FF9);
+        assert_eq!(bus.read(&0x1000), 0);
+
+        // Writes hit hotspots as well
+        bus.write(&0x1FF9, 0x00);
+        assert_eq!(bus.read(&0x1234), 1);
+        assert_eq!(atari.borrow().layout()[0].bank, 1);
+    }
+
+    #[test]
+    fn test_atari_f6_hotspots() {
+        let mut atari = Atari::f6(numbered_rom(4, 0x1000));
+        assert_eq!(atari.cpu_read(0x1000), 3);
+
+        atari.cpu_read(0x1FF7);
+        assert_eq!(atari.cpu_read(0x1000), 1);
+        // $F000 is the same cartridge address for the 6507
+        atari.cpu_read(0xFFF6);
+        assert_eq!(atari.cpu_read(0xF000), 0);
+    }
+
+    #[test]
+    fn test_banked_ram_through_cpu() {
+        let board = Rc::new(RefCell::new(BankedRam::new(8, 0x4000, 0x8000, 0xFF00)));
+        let mut bus = Bus::flat();
+        bus.attach_mapper(0x8000, 0xFFFF, board.clone());
+        let mut cpu = CPU::with_bus(bus, Variant::Cmos);
+
+        // LDX #$05 ; STX $FF00 ; STX $8000 ; STZ $FF00 ; LDA $8000 ; LDY $FF00
+        let program = [
+            0xA2, 0x05, 0x8E, 0x00, 0xFF, 0x8E, 0x00, 0x80, 0x9C, 0x00, 0xFF, 0xAD, 0x00, 0x80,
+            0xAC, 0x00, 0xFF,
+        ];
+        for (offset, byte) in program.iter().enumerate() {
+            cpu.write(&(0x0600 + offset as u16), *byte);
+        }
+        cpu.pc = 0x0600;
+        for _ in 0..6 {
+            cpu.step().unwrap();
+        }
+
+        // Bank 0 was never written, bank 5 kept the store
+        assert_eq!(cpu.accumulator, 0x00);
+        assert_eq!(cpu.y_register, 0x00);
+        assert_eq!(board.borrow().ram.read(0x0000), 0x00);
+        board.borrow_mut().cpu_write(0xFF00, 0x05);
+        assert_eq!(cpu.read(&0x8000), 0x05);
+        assert_eq!(board.borrow().layout()[0].bank, 5);
+    }
+
+    // One STA : the serial port ignores writes on consecutive cycles
+    fn mmc1_write(mmc1: &mut Mmc1, address: u16, data: u8) {
+        mmc1.cpu_write(address, data);
+        mmc1.tick(4);
+    }
+
+    // MMC1 registers take five writes, bit 0 first
+    fn mmc1_load(mmc1: &mut Mmc1, address: u16, value: u8) {
+        for bit in 0..5 {
+            mmc1_write(mmc1, address, (value >> bit) & 0x01);
+        }
+    }
+
+    #[test]
+    fn test_mmc1_serial_registers() {
+        let mut mmc1 = Mmc1::new(numbered_rom(8, 0x4000), numbered_rom(8, 0x1000), 0x2000);
+        // 16K mode, last bank fixed at $C000
+        assert_eq!(mmc1.cpu_read(0x8000), 0);
+        assert_eq!(mmc1.cpu_read(0xC000), 7);
+
+        mmc1_load(&mut mmc1, 0xE000, 0x03);
+        assert_eq!(mmc1.cpu_read(0x8000), 3);
+
+        // Bit 7 resets an incomplete load
+        mmc1_write(&mut mmc1, 0xE000, 0x01);
+        mmc1_write(&mut mmc1, 0xE000, 0x80);
+        mmc1_load(&mut mmc1, 0xE000, 0x05);
+        assert_eq!(mmc1.cpu_read(0x8000), 5);
+
+        // Control : 32K mode, 4K CHR, vertical mirroring
+        mmc1_load(&mut mmc1, 0x8000, 0x12);
+        assert_eq!(mmc1.mirroring(), Mirroring::Vertical);
+        assert_eq!(mmc1.cpu_read(0x8000), 4);
+        assert_eq!(mmc1.cpu_read(0xC000), 5);
+
+        mmc1_load(&mut mmc1, 0xA000, 0x06);
+        mmc1_load(&mut mmc1, 0xC000, 0x03);
+        assert_eq!(mmc1.chr_read(0x0000), 6);
+        assert_eq!(mmc1.chr_read(0x1000), 3);
+
+        // First bank fixed at $8000
+        mmc1_load(&mut mmc1, 0x8000, 0x08);
+        assert_eq!(mmc1.mirroring(), Mirroring::SingleScreenLower);
+        assert_eq!(mmc1.cpu_read(0x8000), 0);
+        assert_eq!(mmc1.cpu_read(0xC000), 5);
+        // 8K CHR ignores the low bit
+        assert_eq!(mmc1.chr_read(0x0000), 6);
+        assert_eq!(mmc1.chr_read(0x1000), 7);
+
+        mmc1.cpu_write(0x6000, 0x42);
+        assert_eq!(mmc1.cpu_read(0x6000), 0x42);
+    }
+
+    #[test]
+    fn test_mmc1_save_state() {
+        let mut mmc1 = Mmc1::new(numbered_rom(8, 0x4000), Vec::new(), 0x2000);
+        mmc1_load(&mut mmc1, 0xE000, 0x02);
+        mmc1_write(&mut mmc1, 0x8000, 0x01);
+        let state = mmc1.save_state();
+
+        let mut restored = Mmc1::new(numbered_rom(8, 0x4000), Vec::new(), 0x2000);
+        restored.load_state(&state);
+        assert_eq!(restored.save_state(), state);
+        assert_eq!(restored.cpu_read(0x8000), 2);
+        assert_eq!(restored.layout(), mmc1.layout());
+
+        // Mid-sequence, one cycle after a write : both ignore the next write the same way
+        mmc1.cpu_write(0x8000, 0x01);
+        mmc1.tick(1);
+        restored.load_state(&mmc1.save_state());
+        mmc1.cpu_write(0x8000, 0x01);
+        restored.cpu_write(0x8000, 0x01);
+        assert_eq!(restored.save_state(), mmc1.save_state());
+        assert_eq!(restored.shift, mmc1.shift);
+    }
+
+    #[test]
+    fn test_mmc1_host_writes_without_ticks() {
+        let mut mmc1 = Mmc1::new(numbered_rom(8, 0x4000), Vec::new(), 0x2000);
+        // Nothing clocks the mapper : every write counts
+        for bit in 0..5 {
+            mmc1.cpu_write(0xE000, (0x03 >> bit) & 0x01);
+        }
+        assert_eq!(mmc1.shift, 0x10);
+        assert_eq!(mmc1.cpu_read(0x8000), 3);
+    }
+
+    #[test]
+    fn test_mmc1_prg_ram_enable() {
+        let mut mmc1 = Mmc1::new(numbered_rom(8, 0x4000), Vec::new(), 0x2000);
+        mmc1.cpu_write(0x6000, 0x55);
+        assert_eq!(mmc1.cpu_read(0x6000), 0x55);
+
+        // PRG bank bit 4 disables PRG-RAM : reads are open and writes dropped
+        mmc1_load(&mut mmc1, 0xE000, 0x10);
+        assert!(!mmc1.cpu_writable(0x6000));
+        assert_eq!(mmc1.cpu_read(0x6000), 0x00);
+        mmc1.cpu_write(0x6000, 0xAA);
+
+        mmc1_load(&mut mmc1, 0xE000, 0x00);
+        assert!(mmc1.cpu_writable(0x6000));
+        assert_eq!(mmc1.cpu_read(0x6000), 0x55);
+    }
+
+    #[test]
+    fn test_mmc1_ignores_consecutive_writes() {
+        // LDA #$01 ; STA $8000 ; INC $8000
+        let program = [0xA9, 0x01, 0x8D, 0x00, 0x80, 0xEE, 0x00, 0x80];
+        let mmc1 = Rc::new(RefCell::new(Mmc1::new(
+            numbered_rom(8, 0x4000),
+            Vec::new(),
+            0x2000,
+        )));
+        let mut bus = Bus::flat();
+        bus.attach_mapper(0x6000, 0xFFFF, mmc1.clone());
+        let mut cpu = CPU::with_bus(bus, Variant::Nmos);
+        for (offset, byte) in program.iter().enumerate() {
+            cpu.write(&(0x0600 + offset as u16), *byte);
+        }
+        cpu.pc = 0x0600;
+        // Cycle stepped : the mapper is clocked between the two writes of INC
+        for _ in 0..3 {
+            while cpu.tick().unwrap().is_none() {}
+        }
+
+        // INC writes $00 then $01 on back to back cycles : only the first bit is shifted in
+        assert_eq!(mmc1.borrow().shift, 0x0C);
+    }
+
+    #[test]
+    fn test_uxrom_cnrom_axrom_latches() {
+        let mut uxrom = UxRom::new(numbered_rom(8, 0x4000), Vec::new(), Mirroring::Vertical);
+        assert_eq!(uxrom.cpu_read(0xC000), 7);
+        uxrom.cpu_write(0x8000, 0x03);
+        assert_eq!(uxrom.cpu_read(0x8000), 3);
+        assert_eq!(uxrom.cpu_read(0xFFFF), 7);
+        assert_eq!(uxrom.save_state(), vec![0x03]);
+        // CHR-RAM
+        uxrom.chr_write(0x0010, 0x99);
+        assert_eq!(uxrom.chr_read(0x0010), 0x99);
+
+        let mut cnrom = CnRom::new(
+            numbered_rom(1, 0x4000),
+            numbered_rom(4, 0x2000),
+            Mirroring::Horizontal,
+        );
+        cnrom.cpu_write(0xFFFF, 0x02);
+        assert_eq!(cnrom.chr_read(0x1FFF), 2);
+        assert_eq!(cnrom.cpu_read(0xC000), 0);
+        cnrom.load_state(&[0x01]);
+        assert_eq!(cnrom.chr_read(0x0000), 1);
+
+        let mut axrom = AxRom::new(numbered_rom(4, 0x8000), Vec::new());
+        axrom.cpu_write(0x8000, 0x12);
+        assert_eq!(axrom.cpu_read(0x8000), 2);
+        assert_eq!(axrom.cpu_read(0xFFFF), 2);
+        assert_eq!(axrom.mirroring(), Mirroring::SingleScreenUpper);
+        axrom.cpu_write(0x8000, 0x01);
+        assert_eq!(axrom.mirroring(), Mirroring::SingleScreenLower);
+    }
+
+    #[test]
+    fn test_mmc3_banks() {
+        let mut mmc3 = Mmc3::new(
+            numbered_rom(16, 0x2000),
+            numbered_rom(32, 0x0400),
+            0x2000,
+            false,
+        );
+        // R6 at $8000, second last and last banks fixed
+        mmc3.cpu_write(0x8000, 0x06);
+        mmc3.cpu_write(0x8001, 0x03);
+        mmc3.cpu_write(0x8000, 0x07);
+        mmc3.cpu_write(0x8001, 0x05);
+        assert_eq!(mmc3.cpu_read(0x8000), 3);
+        assert_eq!(mmc3.cpu_read(0xA000), 5);
+        assert_eq!(mmc3.cpu_read(0xC000), 14);
+        assert_eq!(mmc3.cpu_read(0xE000), 15);
+
+        // PRG mode 1 swaps $8000 and $C000
+        mmc3.cpu_write(0x8000, 0x40);
+        assert_eq!(mmc3.cpu_read(0x8000), 14);
+        assert_eq!(mmc3.cpu_read(0xC000), 3);
+
+        // R0 is a 2K bank, the low bit is ignored
+        mmc3.cpu_write(0x8000, 0x00);
+        mmc3.cpu_write(0x8001, 0x09);
+        mmc3.cpu_write(0x8000, 0x02);
+        mmc3.cpu_write(0x8001, 0x11);
+        assert_eq!(mmc3.chr_read(0x0000), 8);
+        assert_eq!(mmc3.chr_read(0x0400), 9);
+        assert_eq!(mmc3.chr_read(0x1000), 17);
+
+        // CHR inversion
+        mmc3.cpu_write(0x8000, 0x80);
+        assert_eq!(mmc3.chr_read(0x0000), 17);
+        assert_eq!(mmc3.chr_read(0x1000), 8);
+
+        mmc3.cpu_write(0xA000, 0x01);
+        assert_eq!(mmc3.mirroring(), Mirroring::Horizontal);
+
+        // Write protected PRG-RAM
+        mmc3.cpu_write(0x6000, 0x42);
+        mmc3.cpu_write(0xA001, 0xC0);
+        assert!(!mmc3.cpu_writable(0x6000));
+        mmc3.cpu_write(0x6000, 0x24);
+        assert_eq!(mmc3.cpu_read(0x6000), 0x42);
+
+        let mut restored = Mmc3::new(
+            numbered_rom(16, 0x2000),
+            numbered_rom(32, 0x0400),
+            0x2000,
+            false,
+        );
+        restored.load_state(&mmc3.save_state());
+        assert_eq!(restored.layout(), mmc3.layout());
+        assert_eq!(restored.mirroring(), Mirroring::Horizontal);
+    }
+
+    #[test]
+    fn test_mmc3_scanline_irq_drives_cpu() {
+        let mut prg = vec![0xEA; 0x8000];
+        // IRQ handler at $E000 : LDA #$01 ; STA $E000 (acknowledge)
+        prg[0x6000..0x6005].copy_from_slice(&[0xA9, 0x01, 0x8D, 0x00, 0xE0]);
+        prg[0x7FFE] = 0x00;
+        prg[0x7FFF] = 0xE0;
+        let mmc3 = Rc::new(RefCell::new(Mmc3::new(prg, Vec::new(), 0x2000, false)));
+        let mut bus = Bus::flat();
+        bus.attach_mapper(0x6000, 0xFFFF, mmc3.clone());
+        let mut cpu = CPU::with_bus(bus, Variant::Nmos);
+
+        // LDA #$02 ; STA $C000 ; STA $C001 ; STA $E001 ; CLI ; NOP
+        let program = [
+            0xA9, 0x02, 0x8D, 0x00, 0xC0, 0x8D, 0x01, 0xC0, 0x8D, 0x01, 0xE0, 0x58, 0xEA,
+        ];
+        for (offset, byte) in program.iter().enumerate() {
+            cpu.write(&(0x0600 + offset as u16), *byte);
+        }
+        cpu.pc = 0x0600;
+        for _ in 0..5 {
+            cpu.step().unwrap();
+        }
+
+        // Reload from the latch, then count down to zero on the third scanline
+        mmc3.borrow_mut().scanline();
+        mmc3.borrow_mut().scanline();
+        cpu.step().unwrap();
+        assert_eq!(cpu.pc, 0x060D);
+        mmc3.borrow_mut().scanline();
+        assert!(mmc3.borrow().irq());
+
+        cpu.step().unwrap();
+        assert_eq!(cpu.pc, 0xE000);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+        assert!(!mmc3.borrow().irq());
+        assert_eq!(mmc3.borrow().save_state()[12], 0x00);
+    }
+}
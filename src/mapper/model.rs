@@ -0,0 +1,137 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::interfaces::mapper::Mapper;
+
+/*
+   Memory split in banks of the same size : PRG-ROM, CHR-ROM, banked RAM ...
+   Window i covers offsets i * bank_size to (i + 1) * bank_size - 1 and shows windows[i].
+*/
+pub struct Banks {
+    pub data: Vec<u8>,
+    pub bank_size: usize,
+    pub windows: Vec<usize>,
+    pub writable: bool,
+}
+
+/* NES nametable mirroring */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirroring {
+    Horizontal,
+    Vertical,
+    FourScreen,
+    SingleScreenLower,
+    SingleScreenUpper,
+}
+
+/* One window of the bank layout */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BankWindow {
+    pub name: &'static str,
+    pub start: u16,
+    pub size: usize,
+    pub bank: usize,
+}
+
+/* Device attaching a mapper to the bus, the mapper stays shared with the video chip */
+pub struct Slot {
+    pub mapper: Rc<RefCell<dyn Mapper>>,
+    pub start: u16,
+}
+
+/*
+   Atari 2600 F8 / F6 scheme : one 4K window at $1000-$1FFF.
+   Any access to a hotspot ($1FF8-$1FF9 for F8, $1FF6-$1FF9 for F6) selects a bank.
+*/
+pub struct Atari {
+    pub rom: Banks,
+    pub first_hotspot: u16,
+}
+
+/*
+   Homebrew board : a RAM window switched by a bank register.
+   The register reads back the selected bank.
+*/
+pub struct BankedRam {
+    pub ram: Banks,
+    pub window: u16,
+    pub register: u16,
+}
+
+/*
+   NES mapper 0 : 16K or 32K of PRG-ROM at $8000, 8K of CHR-ROM (or CHR-RAM) and
+   the optional PRG-RAM at $6000. No register, 16K of PRG is mirrored at $C000.
+*/
+pub struct Nrom {
+    pub prg_rom: Banks,
+    pub chr: Banks,
+    pub prg_ram: Banks,
+    pub mirroring: Mirroring,
+}
+
+/*
+   NES mapper 1 (SxROM) : registers are loaded one bit at a time through a serial shift register.
+   Control selects mirroring and the PRG (32K or 16K with one fixed bank) and CHR (8K or 4K) modes.
+*/
+pub struct Mmc1 {
+    pub prg_rom: Banks,
+    pub chr: Banks,
+    pub prg_ram: Banks,
+    /* Bit 4 marks the end : the fifth write completes the value */
+    pub shift: u8,
+    pub control: u8,
+    pub chr_bank0: u8,
+    pub chr_bank1: u8,
+    pub prg_bank: u8,
+    /*
+       CPU cycles counted by tick, a write on the cycle after the previous one is ignored.
+       Bit 4 of prg_bank disables PRG-RAM.
+    */
+    pub cycle: u64,
+    pub last_write: Option<u64>,
+}
+
+/* NES mapper 2 : switchable 16K at $8000, last 16K fixed at $C000 */
+pub struct UxRom {
+    pub prg_rom: Banks,
+    pub chr: Banks,
+    pub mirroring: Mirroring,
+    pub bank: u8,
+}
+
+/* NES mapper 3 : fixed PRG-ROM, switchable 8K of CHR-ROM */
+pub struct CnRom {
+    pub prg_rom: Banks,
+    pub chr: Banks,
+    pub mirroring: Mirroring,
+    pub bank: u8,
+}
+
+/* NES mapper 7 : switchable 32K of PRG-ROM and single screen mirroring, bit 4 selects the nametable */
+pub struct AxRom {
+    pub prg_rom: Banks,
+    pub chr: Banks,
+    pub bank: u8,
+}
+
+/*
+   NES mapper 4 (TxROM) : four 8K PRG windows and eight 1K CHR windows fed by eight bank registers.
+   The scanline counter raises IRQ when it reaches zero.
+*/
+pub struct Mmc3 {
+    pub prg_rom: Banks,
+    pub chr: Banks,
+    pub prg_ram: Banks,
+    pub bank_select: u8,
+    pub bank_registers: [u8; 8],
+    /* Mirroring register, ignored when the board hardwires four screens */
+    pub mirroring: u8,
+    pub four_screen: bool,
+    /* Bit 7 enables PRG-RAM, bit 6 protects it from writes */
+    pub prg_ram_protect: u8,
+    pub irq_latch: u8,
+    pub irq_counter: u8,
+    pub irq_reload: bool,
+    pub irq_enabled: bool,
+    pub irq_pending: bool,
+}
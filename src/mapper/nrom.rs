@@ -0,0 +1,78 @@
+use crate::interfaces::mapper::Mapper;
+use crate::mapper::model::{BankWindow, Banks, Mirroring, Nrom};
+
+impl Nrom {
+    /* An empty CHR-ROM means 8K of CHR-RAM on the board */
+    pub fn new(
+        prg_rom: Vec<u8>,
+        chr_rom: Vec<u8>,
+        prg_ram_size: usize,
+        mirroring: Mirroring,
+    ) -> Self {
+        Nrom {
+            prg_rom: Banks::new(prg_rom, 0x4000, 2, false),
+            chr: chr_banks(chr_rom, 0x2000, 1),
+            prg_ram: Banks::new(vec![0x00; prg_ram_size.max(0x2000)], 0x2000, 1, true),
+            mirroring,
+        }
+    }
+}
+
+/* CHR-ROM split in banks, or CHR-RAM when the image has none */
+pub(crate) fn chr_banks(chr_rom: Vec<u8>, bank_size: usize, windows: usize) -> Banks {
+    if chr_rom.is_empty() {
+        Banks::new(vec![0x00; 0x2000], bank_size, windows, true)
+    } else {
+        Banks::new(chr_rom, bank_size, windows, false)
+    }
+}
+
+impl Mapper for Nrom {
+    fn cpu_read(&mut self, address: u16) -> u8 {
+        self.cpu_peek(address)
+    }
+
+    fn cpu_write(&mut self, address: u16, data: u8) {
+        if (0x6000..0x8000).contains(&address) {
+            self.prg_ram.write((address - 0x6000) as usize, data);
+        }
+    }
+
+    fn cpu_peek(&self, address: u16) -> u8 {
+        match address {
+            0x6000..=0x7FFF => self.prg_ram.read((address - 0x6000) as usize),
+            0x8000..=0xFFFF => self.prg_rom.read((address - 0x8000) as usize),
+            _ => 0x00,
+        }
+    }
+
+    fn cpu_writable(&self, address: u16) -> bool {
+        address < 0x8000
+    }
+
+    fn cpu_poke(&mut self, address: u16, data: u8) {
+        match address {
+            0x6000..=0x7FFF => self.prg_ram.poke((address - 0x6000) as usize, data),
+            0x8000..=0xFFFF => self.prg_rom.poke((address - 0x8000) as usize, data),
+            _ => {}
+        }
+    }
+
+    fn chr_read(&self, address: u16) -> u8 {
+        self.chr.read((address & 0x1FFF) as usize)
+    }
+
+    fn chr_write(&mut self, address: u16, data: u8) {
+        self.chr.write((address & 0x1FFF) as usize, data);
+    }
+
+    fn mirroring(&self) -> Mirroring {
+        self.mirroring
+    }
+
+    fn layout(&self) -> Vec<BankWindow> {
+        let mut layout = self.prg_rom.layout("PRG", 0x8000);
+        layout.extend(self.chr.layout("CHR", 0x0000));
+        layout
+    }
+}
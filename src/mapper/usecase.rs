@@ -0,0 +1,109 @@
+use crate::interfaces::device::Device;
+use crate::mapper::model::{BankWindow, Banks, Slot};
+
+impl Banks {
+    /* Window i starts on bank i */
+    pub fn new(data: Vec<u8>, bank_size: usize, windows: usize, writable: bool) -> Self {
+        let mut banks = Banks {
+            data,
+            bank_size,
+            windows: vec![0; windows],
+            writable,
+        };
+        for window in 0..windows {
+            banks.select(window, window);
+        }
+        banks
+    }
+
+    pub fn count(&self) -> usize {
+        (self.data.len() / self.bank_size).max(1)
+    }
+
+    /* Bank numbers past the end wrap, like the unused high bits of a bank register */
+    pub fn select(&mut self, window: usize, bank: usize) {
+        self.windows[window] = bank % self.count();
+    }
+
+    pub fn select_last(&mut self, window: usize) {
+        self.select(window, self.count() - 1);
+    }
+
+    /* Offset from the start of the first window */
+    pub fn read(&self, offset: usize) -> u8 {
+        self.data.get(self.index(offset)).copied().unwrap_or(0x00)
+    }
+
+    pub fn write(&mut self, offset: usize, data: u8) {
+        if self.writable {
+            self.poke(offset, data);
+        }
+    }
+
+    /* Write that reaches ROM too, used by loaders */
+    pub fn poke(&mut self, offset: usize, data: u8) {
+        let index = self.index(offset);
+        if let Some(cell) = self.data.get_mut(index) {
+            *cell = data;
+        }
+    }
+
+    pub fn layout(&self, name: &'static str, start: u16) -> Vec<BankWindow> {
+        self.windows
+            .iter()
+            .enumerate()
+            .map(|(window, bank)| BankWindow {
+                name,
+                start: start.wrapping_add((window * self.bank_size) as u16),
+                size: self.bank_size,
+                bank: *bank,
+            })
+            .collect()
+    }
+
+    fn index(&self, offset: usize) -> usize {
+        let window = (offset / self.bank_size) % self.windows.len();
+        self.windows[window] * self.bank_size + offset % self.bank_size
+    }
+}
+
+/* The bus hands out offsets from the start of the slot, the mapper decodes absolute addresses */
+impl Device for Slot {
+    fn read(&mut self, address: &u16) -> u8 {
+        self.mapper
+            .borrow_mut()
+            .cpu_read(self.start.wrapping_add(*address))
+    }
+
+    fn write(&mut self, address: &u16, data: u8) {
+        self.mapper
+            .borrow_mut()
+            .cpu_write(self.start.wrapping_add(*address), data);
+    }
+
+    fn peek(&self, address: &u16) -> u8 {
+        self.mapper
+            .borrow()
+            .cpu_peek(self.start.wrapping_add(*address))
+    }
+
+    fn poke(&mut self, address: &u16, data: u8) {
+        self.mapper
+            .borrow_mut()
+            .cpu_poke(self.start.wrapping_add(*address), data);
+    }
+
+    fn writable(&self, address: &u16) -> bool {
+        self.mapper
+            .borrow()
+            .cpu_writable(self.start.wrapping_add(*address))
+    }
+
+    fn tick(&mut self, cycles: u64) {
+        self.mapper.borrow_mut().tick(cycles);
+    }
+
+    fn irq(&self) -> bool {
+        self.mapper.borrow().irq()
+    }
+}
@@ -25,6 +25,13 @@
 // |_ _ _ _ _ _ _ _| $0100 |               |
 // | Zero Page     |       |               |
 // |_______________| $0000 |_______________|
-pub struct Memory {
-    pub stacks: [u8; 65536],
+
+/* Read / write memory device */
+pub struct Ram {
+    pub cells: Vec<u8>,
+}
+
+/* Read only memory device, writes are ignored */
+pub struct Rom {
+    pub cells: Vec<u8>,
 }
@@ -1,33 +1,54 @@
-use crate::interfaces::bus::IBus;
-use crate::ram::model::Memory;
+use crate::interfaces::device::Device;
+use crate::ram::model::{Ram, Rom};
 
-impl Memory {
-    pub fn new() -> Self {
-        Memory {
-            stacks: [0x00; 65536],
+impl Ram {
+    pub fn new(size: usize) -> Self {
+        Ram {
+            cells: vec![0x00; size],
         }
     }
+}
+
+impl Device for Ram {
+    fn read(&mut self, address: &u16) -> u8 {
+        self.peek(address)
+    }
 
-    fn in_mem(&self, address: &u16) -> bool {
-        if *address >= 0x0000 && *address <= 0xFFFF {
-            return true;
+    fn write(&mut self, address: &u16, data: u8) {
+        if let Some(cell) = self.cells.get_mut(*address as usize) {
+            *cell = data;
         }
-        return false;
+    }
+
+    fn peek(&self, address: &u16) -> u8 {
+        self.cells.get(*address as usize).copied().unwrap_or(0x00)
     }
 }
 
-impl IBus for Memory {
-    fn read(&self, address: &u16) -> u8 {
-        if !self.in_mem(address) {
-            return 0x00;
-        }
+impl Rom {
+    pub fn new(cells: Vec<u8>) -> Self {
+        Rom { cells }
+    }
+}
 
-        return self.stacks[*address as usize];
+impl Device for Rom {
+    fn read(&mut self, address: &u16) -> u8 {
+        self.peek(address)
     }
 
-    fn write(&mut self, address: &u16, data: u8) {
-        if self.in_mem(address) {
-            self.stacks[*address as usize] = data;
+    fn write(&mut self, _address: &u16, _data: u8) {}
+
+    fn writable(&self, _address: &u16) -> bool {
+        false
+    }
+
+    fn poke(&mut self, address: &u16, data: u8) {
+        if let Some(cell) = self.cells.get_mut(*address as usize) {
+            *cell = data;
         }
     }
+
+    fn peek(&self, address: &u16) -> u8 {
+        self.cells.get(*address as usize).copied().unwrap_or(0x00)
+    }
 }
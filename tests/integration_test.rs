@@ -1,7 +1,12 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use emulator_cpu6502::bus::model::Bus;
-use emulator_cpu6502::cpu::model::CPU;
+use emulator_cpu6502::cpu::model::{StopReason, Variant, CPU};
 use emulator_cpu6502::interfaces::bus::IBus;
 use emulator_cpu6502::interfaces::cpu::ICPU;
+use emulator_cpu6502::interfaces::device::Device;
+use emulator_cpu6502::ram::model::{Ram, Rom};
 
 #[test]
 fn test_cpu_initialization() {
@@ -31,3 +36,110 @@ fn test_bus_initialization() {
 
 // Note: More comprehensive integration tests will be added once we have
 // a better understanding of the CPU and Bus implementations
+
+// Register file that counts the cycles it was clocked for and raises IRQ past a limit
+struct Timer {
+    cycles: u64,
+    limit: u64,
+    reads: u8,
+}
+
+impl Device for Timer {
+    fn read(&mut self, address: &u16) -> u8 {
+        // Reading the status register acknowledges the interrupt
+        self.reads += 1;
+        if *address == 0x0001 {
+            self.limit = u64::MAX;
+        }
+        self.cycles as u8
+    }
+
+    fn write(&mut self, _address: &u16, data: u8) {
+        self.limit = data as u64;
+    }
+
+    fn peek(&self, _address: &u16) -> u8 {
+        self.cycles as u8
+    }
+
+    fn tick(&mut self, cycles: u64) {
+        self.cycles += cycles;
+    }
+
+    fn irq(&self) -> bool {
+        self.cycles >= self.limit
+    }
+}
+
+#[test]
+fn test_bus_dispatch_and_mirroring() {
+    let mut bus = Bus::empty();
+    // 2K of RAM mirrored over $0000-$1FFF
+    bus.attach(0x0000, 0x1FFF, 0x07FF, Ram::new(0x0800));
+    bus.attach(0x8000, 0xFFFF, 0x3FFF, Rom::new(vec![0xEA; 0x4000]));
+
+    bus.write(&0x0801, 0x42);
+    assert_eq!(bus.read(&0x0001), 0x42);
+    assert_eq!(bus.read(&0x1801), 0x42);
+
+    // ROM ignores writes and is mirrored twice
+    bus.write(&0x8000, 0x00);
+    assert_eq!(bus.read(&0x8000), 0xEA);
+    assert_eq!(bus.read(&0xC000), 0xEA);
+
+    // Nothing mapped
+    assert_eq!(bus.read(&0x4000), 0x00);
+}
+
+#[test]
+fn test_bus_device_overlays_ram() {
+    let timer = Rc::new(RefCell::new(Timer {
+        cycles: 0,
+        limit: u64::MAX,
+        reads: 0,
+    }));
+    let mut bus = Bus::flat();
+    bus.attach(0x4000, 0x4001, 0x0001, timer.clone());
+
+    bus.tick(5);
+    assert_eq!(bus.peek(&0x4000), 0x05);
+    assert_eq!(timer.borrow().reads, 0);
+    assert_eq!(bus.read(&0x4000), 0x05);
+    assert_eq!(timer.borrow().reads, 1);
+
+    // RAM answers around the device
+    bus.write(&0x4002, 0x33);
+    assert_eq!(bus.read(&0x4002), 0x33);
+}
+
+#[test]
+fn test_device_irq_drives_cpu() {
+    let timer = Rc::new(RefCell::new(Timer {
+        cycles: 0,
+        limit: u64::MAX,
+        reads: 0,
+    }));
+    let mut bus = Bus::flat();
+    bus.attach(0x4000, 0x4001, 0x0001, timer.clone());
+    let mut cpu = CPU::with_bus(bus, Variant::Nmos);
+
+    // LDA #$10 ; STA $4000 ; CLI ; loop: JMP loop
+    let program = [0xA9, 0x10, 0x8D, 0x00, 0x40, 0x58, 0x4C, 0x06, 0x06];
+    for (offset, byte) in program.iter().enumerate() {
+        cpu.write(&(0x0600 + offset as u16), *byte);
+    }
+    // IRQ handler at $0700 : LDA $4001 ; JMP $0700
+    for (offset, byte) in [0xAD, 0x01, 0x40, 0x4C, 0x03, 0x07].iter().enumerate() {
+        cpu.write(&(0x0700 + offset as u16), *byte);
+    }
+    cpu.write(&0xFFFE, 0x00);
+    cpu.write(&0xFFFF, 0x07);
+    cpu.pc = 0x0600;
+    cpu.sp = 0xFD;
+
+    let result = cpu.run_until(|cpu| cpu.pc == 0x0703).unwrap();
+    assert_eq!(result, StopReason::Condition);
+    assert!(timer.borrow().cycles >= 0x10);
+    // The handler acknowledged the timer
+    assert!(!timer.borrow().irq());
+}